@@ -1,22 +1,17 @@
 extern crate core;
 
-mod xna;
-
-use std::cell::{RefCell, RefMut};
-use std::error::Error;
-use std::fmt::Pointer;
+use std::cell::RefCell;
 use std::rc::Rc;
-use crate::xna::csharp::Exception;
-use crate::xna::framework::Color;
-use crate::xna::framework::game::{Game, GameHandler, GameTime, GraphicsDeviceManager};
+use xna::xna::csharp::Exception;
+use xna::xna::framework::Color;
+use xna::xna::framework::game::{Game, GameHandler, GameTime, GraphicsDeviceManager};
 
 fn main() {
-    let mut game = Rc::new(RefCell::new(Game::new()));
-    let mut graphics_device_manager = Rc::new(RefCell::new(GraphicsDeviceManager::new(Some(game.clone()))));
+    let game = Rc::new(RefCell::new(Game::new()));
+    let graphics_device_manager = Rc::new(RefCell::new(GraphicsDeviceManager::new(Some(game.clone()))));
 
-    let mut game1 = Rc::new(RefCell::new(Game1 {
+    let game1 = Rc::new(RefCell::new(Game1 {
         graphics_device_manager: graphics_device_manager.clone(),
-        game: game.clone(),
     }));
 
     create_window(game.clone());
@@ -59,7 +54,6 @@ fn apply_graphics_device_manager(graphics_device_manager: Rc<RefCell<GraphicsDev
 
 struct Game1 {
     pub graphics_device_manager: Rc<RefCell<GraphicsDeviceManager>>,
-    pub game: Rc<RefCell<Game>>,
 }
 
 impl GameHandler for Game1 {
@@ -71,11 +65,11 @@ impl GameHandler for Game1 {
         Ok(())
     }
 
-    fn on_update(&mut self, game_time: &GameTime) -> Result<(), Exception> {
+    fn on_update(&mut self, _game_time: &GameTime) -> Result<(), Exception> {
         Ok(())
     }
 
-    fn on_draw(&mut self, game_time: &GameTime) -> Result<(), Exception> {
+    fn on_draw(&mut self, _game_time: &GameTime) -> Result<(), Exception> {
         let manager = self.graphics_device_manager.borrow_mut();
         let device = manager.graphics_device.as_ref().unwrap().borrow_mut();
         device.clear(Color::cornflower_blue())?;
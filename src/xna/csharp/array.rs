@@ -3,9 +3,36 @@ use crate::xna::csharp::{Array, Exception};
 impl Array {
     pub fn clear<T>(array: &mut [T], index: usize, length: usize)
         -> Result<(), Exception> where T : Default, T : Clone {
-        let mut slice = &mut array[index.. length];
-        slice.fill(T::default());
+        let end = match index.checked_add(length) {
+            Some(end) if end <= array.len() => end,
+            _ => return Err(Exception::out_of_range("index and length must refer to a location within the array.", None)),
+        };
+
+        array[index..end].fill(T::default());
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clear_resets_length_elements_starting_at_index() {
+        let mut array = [1, 2, 3, 4, 5];
+        Array::clear(&mut array, 1, 3).unwrap();
+
+        assert_eq!(array, [1, 0, 0, 0, 5]);
+    }
+
+    #[test]
+    fn clear_rejects_out_of_range_arguments() {
+        let mut array = [1, 2, 3];
+
+        assert!(Array::clear(&mut array, 4, 0).is_err());
+        assert!(Array::clear(&mut array, 1, 3).is_err());
+        assert!(Array::clear(&mut array, 1, usize::MAX).is_err());
+        assert_eq!(array, [1, 2, 3]);
+    }
+}
@@ -3,11 +3,44 @@ use crate::xna::csharp::{Buffer, Exception};
 impl Buffer {
     pub fn block_copy<T>(src: &[T], src_offset: usize, dst: &mut [T], dst_offset: usize, count: usize)
         -> Result<(), Exception> where T: Copy {
-        let source = &src[src_offset..count];
-        let destination = &mut dst[dst_offset..count];
+        let source_end = match src_offset.checked_add(count) {
+            Some(end) if end <= src.len() => end,
+            _ => return Err(Exception::out_of_range("src_offset and count must refer to a location within src.", None)),
+        };
 
-        destination.copy_from_slice(source);
+        let destination_end = match dst_offset.checked_add(count) {
+            Some(end) if end <= dst.len() => end,
+            _ => return Err(Exception::out_of_range("dst_offset and count must refer to a location within dst.", None)),
+        };
+
+        dst[dst_offset..destination_end].copy_from_slice(&src[src_offset..source_end]);
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn block_copy_copies_count_elements_between_offsets() {
+        let source = [1, 2, 3, 4, 5];
+        let mut destination = [0; 5];
+        Buffer::block_copy(&source, 1, &mut destination, 2, 3).unwrap();
+
+        assert_eq!(destination, [0, 0, 2, 3, 4]);
+    }
+
+    #[test]
+    fn block_copy_rejects_out_of_range_arguments() {
+        let source = [1, 2, 3];
+        let mut destination = [0; 3];
+
+        assert!(Buffer::block_copy(&source, 1, &mut destination, 0, 3).is_err());
+        assert!(Buffer::block_copy(&source, 0, &mut destination, 1, 3).is_err());
+        assert!(Buffer::block_copy(&source, usize::MAX, &mut destination, 0, 1).is_err());
+        assert!(Buffer::block_copy(&source, 0, &mut destination, usize::MAX, 1).is_err());
+        assert_eq!(destination, [0; 3]);
+    }
+}
@@ -0,0 +1,23 @@
+use crate::xna::csharp::io::Crc32;
+
+impl Crc32 {
+    const POLYNOMIAL: u32 = 0xEDB88320;
+
+    pub fn compute(data: &[u8]) -> u32 {
+        Self::append(0, data)
+    }
+
+    pub fn append(crc: u32, data: &[u8]) -> u32 {
+        let mut value = !crc;
+
+        for byte in data {
+            value ^= *byte as u32;
+
+            for _ in 0..8 {
+                value = if value & 1 != 0 { (value >> 1) ^ Self::POLYNOMIAL } else { value >> 1 };
+            }
+        }
+
+        !value
+    }
+}
@@ -110,14 +110,13 @@ impl MemoryStream {
 
         if self.exposable && value != self.capacity {
             if value > 0 {
-                let mut new_buffer:Vec<u8> = Vec::with_capacity(value as usize);
-                let slice = new_buffer.as_mut_slice();
+                let mut new_buffer = vec![0u8; value as usize];
 
                 if self.length > 0 {
-                    Buffer::block_copy(&self.buffer, 0, slice, 0, self.length as usize)?;
+                    Buffer::block_copy(&self.buffer, 0, &mut new_buffer, 0, self.length as usize)?;
                 }
 
-                self.buffer.copy_from_slice(&new_buffer);
+                self.buffer = new_buffer;
 
             } else {
                 self.buffer = Vec::new();
@@ -130,7 +129,7 @@ impl MemoryStream {
     }
 
     pub fn get_buffer(&self) -> Result<Vec<u8>, Exception> {
-        if self.exposable {
+        if !self.exposable {
             return Err(Exception::new("Unauthorized access.", None));
         }
 
@@ -149,7 +148,7 @@ impl MemoryStream {
             n = 0
         }
 
-        self.position = self.position + n;
+        self.position += n;
 
         Ok(n)
     }
@@ -259,7 +258,7 @@ impl Stream for MemoryStream {
     }
 
     fn read(&mut self, buffer: &mut [u8], offset: i32, count: i32) -> Result<i32, Exception> {
-        StreamHelper::validate_buffer_arguments(&buffer, offset, count)?;
+        StreamHelper::validate_buffer_arguments(buffer, offset, count)?;
         self.ensure_not_close()?;
 
         let mut n = self.length - self.position;
@@ -273,21 +272,13 @@ impl Stream for MemoryStream {
         }
 
         if n <= 8 {
-            let mut byte_count = n as usize;
-
-            loop {
-                byte_count = byte_count - 1;
-
-                if byte_count >= 0 {
-                    buffer[offset as usize + byte_count] = self.buffer[self.position as usize + byte_count];
-                }else {
-                    break;
-                }
+            for byte_count in (0..n as usize).rev() {
+                buffer[offset as usize + byte_count] = self.buffer[self.position as usize + byte_count];
             }
         }else {
             Buffer::block_copy(&self.buffer, self.position as usize, buffer, offset as usize, n as usize)?;
         }
-        self.position = self.position + n as i32;
+        self.position += n;
 
         Ok(n)
     }
@@ -300,7 +291,7 @@ impl Stream for MemoryStream {
         }
 
         let result = self.buffer[self.position as usize];
-        self.position = self.position + 1;
+        self.position += 1;
 
         Ok(result as i32)
     }
@@ -334,15 +325,9 @@ impl Stream for MemoryStream {
             self.length = i;
         }
 
-        if (count <= 8) {
-            let mut byte_count = count as usize;
-
-            loop {
-                byte_count = byte_count - 1;
-
-                if byte_count >= 0 {
-                    self.buffer[self.position as usize + byte_count] = buffer[offset as usize + byte_count];
-                }
+        if count <= 8 {
+            for byte_count in (0..count as usize).rev() {
+                self.buffer[self.position as usize + byte_count] = buffer[offset as usize + byte_count];
             }
         } else {
             Buffer::block_copy(buffer, offset as usize, &mut self.buffer, self.position as usize, count as usize)?;
@@ -374,7 +359,7 @@ impl Stream for MemoryStream {
         }
 
         let position = self.position;
-        self.position = self.position + 1;
+        self.position += 1;
 
         self.buffer[position as usize] = value;
 
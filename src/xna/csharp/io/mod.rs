@@ -1,5 +1,7 @@
 pub mod stream;
 pub mod memory_stream;
+pub mod zlib;
+pub mod crc32;
 
 use crate::xna::csharp::Exception;
 
@@ -44,4 +46,10 @@ pub struct MemoryStream {
     writable: bool,
     exposable: bool,
     is_open: bool
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub struct ZLib;
+
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub struct Crc32;
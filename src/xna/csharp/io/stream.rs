@@ -9,12 +9,10 @@ impl StreamHelper {
         }
 
         let mut buffer = vec![0u8; buffer_size as usize];
-        let mut slice = buffer.as_mut_slice();
-
-        let mut bytes_read = 0;
+        let slice = buffer.as_mut_slice();
 
         loop {
-            bytes_read = source.read(&mut slice, 0, buffer_size)?;
+            let bytes_read = source.read(slice, 0, buffer_size)?;
 
             if bytes_read != 0 {
                 destination.write(slice, 0, bytes_read)?;
@@ -66,7 +64,7 @@ impl StreamHelper {
     }
 
     pub fn read_exactly(source: &mut dyn Stream, buffer: &mut [u8], offset: i32, count: i32) -> Result<(), Exception> {
-        Self::validate_buffer_arguments(&buffer, offset.clone(), count.clone())?;
+        Self::validate_buffer_arguments(buffer, offset, count)?;
         let slice = &mut buffer[offset as usize..(offset + count) as usize];
 
         _ = Self::read_at_least_core(source, slice, count, true)?;
@@ -74,13 +72,13 @@ impl StreamHelper {
         Ok(())
     }
 
-    pub fn read_at_least(source: &mut dyn Stream, buffer: &mut [u8], minimum_bytes: i32, throw_on_end_of_stream: bool) -> Result<(i32), Exception> {
-        Self::validate_read_at_least_arguments(buffer.len().clone() as i32, minimum_bytes)?;
+    pub fn read_at_least(source: &mut dyn Stream, buffer: &mut [u8], minimum_bytes: i32, throw_on_end_of_stream: bool) -> Result<i32, Exception> {
+        Self::validate_read_at_least_arguments(buffer.len() as i32, minimum_bytes)?;
 
         Self::read_at_least_core(source, buffer, minimum_bytes, throw_on_end_of_stream)
     }
 
-    pub fn read_at_least_core(source: &mut dyn Stream, buffer: &mut [u8], minimum_bytes: i32, throw_on_end_of_stream: bool) -> Result<(i32), Exception> {
+    pub fn read_at_least_core(source: &mut dyn Stream, buffer: &mut [u8], minimum_bytes: i32, throw_on_end_of_stream: bool) -> Result<i32, Exception> {
         let mut total_read = 0;
 
         while total_read < minimum_bytes {
@@ -95,7 +93,7 @@ impl StreamHelper {
                 return Ok(total_read);
             }
 
-            total_read = total_read + read;
+            total_read += read;
         }
 
         Ok(total_read)
@@ -142,13 +140,13 @@ impl StreamHelper {
     }
 
     pub fn write(destination: &mut dyn Stream, buffer: &[u8]) -> Result<(), Exception> {
-        let _ = destination.write(buffer, 0, buffer.len() as i32)?;
+        destination.write(buffer, 0, buffer.len() as i32)?;
         Ok(())
     }
 
     pub fn write_byte(destination: &mut dyn Stream, value: u8) -> Result<(), Exception> {
         let buffer = [value, 1];
-        _ = destination.write(&buffer, 0, 1)?;
+        destination.write(&buffer, 0, 1)?;
 
         Ok(())
     }
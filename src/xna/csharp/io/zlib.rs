@@ -0,0 +1,505 @@
+use crate::xna::csharp::Exception;
+use crate::xna::csharp::io::ZLib;
+
+const LENGTH_BASE: [u16; 29] = [3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258];
+const LENGTH_EXTRA: [u8; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+const DISTANCE_BASE: [u16; 30] = [1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577];
+const DISTANCE_EXTRA: [u8; 30] = [0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13];
+const CODE_LENGTH_ORDER: [usize; 19] = [16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15];
+
+const WINDOW_SIZE: usize = 32768;
+const HASH_SIZE: usize = 1 << 15;
+const MAX_CHAIN: usize = 64;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+    bit_buffer: u32,
+    bit_count: u32,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        BitReader { data, position: 0, bit_buffer: 0, bit_count: 0 }
+    }
+
+    fn bits(&mut self, count: u32) -> Result<u32, Exception> {
+        while self.bit_count < count {
+            if self.position >= self.data.len() {
+                return Err(Exception::new("Unexpected end of compressed data.", None));
+            }
+
+            self.bit_buffer |= (self.data[self.position] as u32) << self.bit_count;
+            self.position += 1;
+            self.bit_count += 8;
+        }
+
+        let value = self.bit_buffer & ((1u64 << count) - 1) as u32;
+        self.bit_buffer >>= count;
+        self.bit_count -= count;
+
+        Ok(value)
+    }
+
+    fn align_to_byte(&mut self) {
+        self.bit_buffer = 0;
+        self.bit_count = 0;
+    }
+}
+
+struct Huffman {
+    counts: [u16; 16],
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Result<Huffman, Exception> {
+        let mut counts = [0u16; 16];
+
+        for length in lengths {
+            counts[*length as usize] += 1;
+        }
+
+        let mut left = 1i32;
+
+        for count in counts.iter().skip(1) {
+            left = (left << 1) - *count as i32;
+
+            if left < 0 {
+                return Err(Exception::new("Invalid Huffman code lengths.", None));
+            }
+        }
+
+        let mut offsets = [0u16; 16];
+
+        for index in 1..15 {
+            offsets[index + 1] = offsets[index] + counts[index];
+        }
+
+        let mut symbols = vec![0u16; lengths.len()];
+
+        for (symbol, length) in lengths.iter().enumerate() {
+            if *length != 0 {
+                symbols[offsets[*length as usize] as usize] = symbol as u16;
+                offsets[*length as usize] += 1;
+            }
+        }
+
+        counts[0] = 0;
+
+        Ok(Huffman { counts, symbols })
+    }
+
+    fn decode(&self, reader: &mut BitReader) -> Result<u16, Exception> {
+        let mut code = 0i32;
+        let mut first = 0i32;
+        let mut index = 0i32;
+
+        for length in 1..16 {
+            code |= reader.bits(1)? as i32;
+            let count = self.counts[length] as i32;
+
+            if code - count < first {
+                return Ok(self.symbols[(index + code - first) as usize]);
+            }
+
+            index += count;
+            first += count;
+            first <<= 1;
+            code <<= 1;
+        }
+
+        Err(Exception::new("Invalid Huffman code.", None))
+    }
+}
+
+struct BitWriter {
+    data: Vec<u8>,
+    bit_buffer: u32,
+    bit_count: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u32, count: u32) {
+        self.bit_buffer |= value << self.bit_count;
+        self.bit_count += count;
+
+        while self.bit_count >= 8 {
+            self.data.push(self.bit_buffer as u8);
+            self.bit_buffer >>= 8;
+            self.bit_count -= 8;
+        }
+    }
+
+    fn write_reversed(&mut self, code: u32, count: u32) {
+        self.write(code.reverse_bits() >> (32 - count), count);
+    }
+
+    fn flush(&mut self) {
+        if self.bit_count > 0 {
+            self.data.push(self.bit_buffer as u8);
+        }
+
+        self.bit_buffer = 0;
+        self.bit_count = 0;
+    }
+}
+
+impl ZLib {
+    pub fn compress(data: &[u8]) -> Vec<u8> {
+        let mut result = vec![0x78, 0x9C];
+        result.extend(Self::deflate(data));
+        result.extend(Self::adler32(data).to_be_bytes());
+
+        result
+    }
+
+    pub fn decompress(data: &[u8], maximum_length: usize) -> Result<Vec<u8>, Exception> {
+        if data.len() < 6 {
+            return Err(Exception::new("The zlib stream is too short.", None));
+        }
+
+        let compression_method = data[0] & 0x0F;
+        let header = ((data[0] as u16) << 8) | data[1] as u16;
+
+        if compression_method != 8 || !header.is_multiple_of(31) {
+            return Err(Exception::new("Invalid zlib header.", None));
+        }
+
+        if data[1] & 0x20 != 0 {
+            return Err(Exception::new("Preset dictionaries are not supported.", None));
+        }
+
+        let (result, used) = Self::inflate_with_length(&data[2..], maximum_length)?;
+        let end = 2 + used;
+
+        if end + 4 > data.len() {
+            return Err(Exception::new("The zlib stream is missing its checksum.", None));
+        }
+
+        let checksum = u32::from_be_bytes([data[end], data[end + 1], data[end + 2], data[end + 3]]);
+
+        if checksum != Self::adler32(&result) {
+            return Err(Exception::new("The zlib checksum does not match.", None));
+        }
+
+        Ok(result)
+    }
+
+    pub fn adler32(data: &[u8]) -> u32 {
+        let mut a = 1u32;
+        let mut b = 0u32;
+
+        for chunk in data.chunks(5552) {
+            for byte in chunk {
+                a += *byte as u32;
+                b += a;
+            }
+
+            a %= 65521;
+            b %= 65521;
+        }
+
+        (b << 16) | a
+    }
+
+    pub fn inflate(data: &[u8], maximum_length: usize) -> Result<Vec<u8>, Exception> {
+        Ok(Self::inflate_with_length(data, maximum_length)?.0)
+    }
+
+    fn inflate_with_length(data: &[u8], maximum_length: usize) -> Result<(Vec<u8>, usize), Exception> {
+        let mut reader = BitReader::new(data);
+        let mut output = Vec::with_capacity(data.len().saturating_mul(4).min(maximum_length));
+
+        loop {
+            let is_final = reader.bits(1)? == 1;
+
+            match reader.bits(2)? {
+                0 => Self::inflate_stored(&mut reader, &mut output, maximum_length)?,
+                1 => {
+                    let (literals, distances) = Self::fixed_tables()?;
+                    Self::inflate_block(&mut reader, &mut output, &literals, &distances, maximum_length)?
+                }
+                2 => {
+                    let (literals, distances) = Self::dynamic_tables(&mut reader)?;
+                    Self::inflate_block(&mut reader, &mut output, &literals, &distances, maximum_length)?
+                }
+                _ => return Err(Exception::new("Invalid deflate block type.", None)),
+            }
+
+            if is_final {
+                break;
+            }
+        }
+
+        Ok((output, reader.position))
+    }
+
+    fn inflate_stored(reader: &mut BitReader, output: &mut Vec<u8>, maximum_length: usize) -> Result<(), Exception> {
+        reader.align_to_byte();
+
+        let position = reader.position;
+
+        if position + 4 > reader.data.len() {
+            return Err(Exception::new("Unexpected end of compressed data.", None));
+        }
+
+        let length = u16::from_le_bytes([reader.data[position], reader.data[position + 1]]) as usize;
+        let complement = u16::from_le_bytes([reader.data[position + 2], reader.data[position + 3]]) as usize;
+
+        if length != !complement & 0xFFFF {
+            return Err(Exception::new("Invalid stored block length.", None));
+        }
+
+        let start = position + 4;
+
+        if start + length > reader.data.len() {
+            return Err(Exception::new("Unexpected end of compressed data.", None));
+        }
+
+        Self::ensure_output_length(output.len() + length, maximum_length)?;
+
+        output.extend_from_slice(&reader.data[start..start + length]);
+        reader.position = start + length;
+
+        Ok(())
+    }
+
+    fn fixed_tables() -> Result<(Huffman, Huffman), Exception> {
+        let mut lengths = [0u8; 288];
+
+        lengths[..144].fill(8);
+        lengths[144..256].fill(9);
+        lengths[256..280].fill(7);
+        lengths[280..].fill(8);
+
+        Ok((Huffman::new(&lengths)?, Huffman::new(&[5u8; 30])?))
+    }
+
+    fn dynamic_tables(reader: &mut BitReader) -> Result<(Huffman, Huffman), Exception> {
+        let literal_count = reader.bits(5)? as usize + 257;
+        let distance_count = reader.bits(5)? as usize + 1;
+        let code_count = reader.bits(4)? as usize + 4;
+
+        if literal_count > 286 || distance_count > 30 {
+            return Err(Exception::new("Invalid dynamic block header.", None));
+        }
+
+        let mut code_lengths = [0u8; 19];
+
+        for index in CODE_LENGTH_ORDER.iter().take(code_count) {
+            code_lengths[*index] = reader.bits(3)? as u8;
+        }
+
+        let code_table = Huffman::new(&code_lengths)?;
+        let mut lengths = vec![0u8; literal_count + distance_count];
+        let mut index = 0;
+
+        while index < lengths.len() {
+            let symbol = code_table.decode(reader)?;
+
+            if symbol < 16 {
+                lengths[index] = symbol as u8;
+                index += 1;
+                continue;
+            }
+
+            let (value, repeat) = match symbol {
+                16 => {
+                    if index == 0 {
+                        return Err(Exception::new("Invalid code length repeat.", None));
+                    }
+
+                    (lengths[index - 1], 3 + reader.bits(2)? as usize)
+                }
+                17 => (0, 3 + reader.bits(3)? as usize),
+                _ => (0, 11 + reader.bits(7)? as usize),
+            };
+
+            if index + repeat > lengths.len() {
+                return Err(Exception::new("Invalid code length repeat.", None));
+            }
+
+            lengths[index..index + repeat].fill(value);
+            index += repeat;
+        }
+
+        if lengths[256] == 0 {
+            return Err(Exception::new("The dynamic block has no end-of-block code.", None));
+        }
+
+        Ok((Huffman::new(&lengths[..literal_count])?, Huffman::new(&lengths[literal_count..])?))
+    }
+
+    fn inflate_block(reader: &mut BitReader, output: &mut Vec<u8>, literals: &Huffman, distances: &Huffman, maximum_length: usize) -> Result<(), Exception> {
+        loop {
+            let symbol = literals.decode(reader)? as usize;
+
+            if symbol < 256 {
+                Self::ensure_output_length(output.len() + 1, maximum_length)?;
+                output.push(symbol as u8);
+                continue;
+            }
+
+            if symbol == 256 {
+                return Ok(());
+            }
+
+            let symbol = symbol - 257;
+
+            if symbol >= LENGTH_BASE.len() {
+                return Err(Exception::new("Invalid length code.", None));
+            }
+
+            let length = LENGTH_BASE[symbol] as usize + reader.bits(LENGTH_EXTRA[symbol] as u32)? as usize;
+            let symbol = distances.decode(reader)? as usize;
+
+            if symbol >= DISTANCE_BASE.len() {
+                return Err(Exception::new("Invalid distance code.", None));
+            }
+
+            let distance = DISTANCE_BASE[symbol] as usize + reader.bits(DISTANCE_EXTRA[symbol] as u32)? as usize;
+
+            if distance > output.len() {
+                return Err(Exception::new("Distance is too far back.", None));
+            }
+
+            Self::ensure_output_length(output.len() + length, maximum_length)?;
+
+            let start = output.len() - distance;
+
+            for index in 0..length {
+                output.push(output[start + index]);
+            }
+        }
+    }
+
+    fn ensure_output_length(length: usize, maximum_length: usize) -> Result<(), Exception> {
+        if length > maximum_length {
+            return Err(Exception::new("The decompressed data is longer than expected.", None));
+        }
+
+        Ok(())
+    }
+
+    pub fn deflate(data: &[u8]) -> Vec<u8> {
+        let mut writer = BitWriter { data: Vec::with_capacity(data.len() / 2 + 16), bit_buffer: 0, bit_count: 0 };
+
+        writer.write(1, 1);
+        writer.write(1, 2);
+
+        let mut head = vec![usize::MAX; HASH_SIZE];
+        let mut previous = vec![usize::MAX; WINDOW_SIZE];
+        let hash = |position: usize| {
+            let value = ((data[position] as u32) << 16) | ((data[position + 1] as u32) << 8) | data[position + 2] as u32;
+            (value.wrapping_mul(2654435761) >> 17) as usize & (HASH_SIZE - 1)
+        };
+
+        let mut position = 0;
+
+        while position < data.len() {
+            let mut best_length = 0;
+            let mut best_distance = 0;
+
+            if position + MIN_MATCH <= data.len() {
+                let key = hash(position);
+                let mut candidate = head[key];
+                let mut chain = 0;
+                let max_length = MAX_MATCH.min(data.len() - position);
+
+                while candidate != usize::MAX && position - candidate <= WINDOW_SIZE && chain < MAX_CHAIN {
+                    let mut length = 0;
+
+                    while length < max_length && data[candidate + length] == data[position + length] {
+                        length += 1;
+                    }
+
+                    if length > best_length {
+                        best_length = length;
+                        best_distance = position - candidate;
+
+                        if length == max_length {
+                            break;
+                        }
+                    }
+
+                    candidate = previous[candidate % WINDOW_SIZE];
+                    chain += 1;
+                }
+            }
+
+            let advance = if best_length >= MIN_MATCH {
+                Self::write_match(&mut writer, best_length, best_distance);
+                best_length
+            } else {
+                Self::write_literal(&mut writer, data[position] as u32);
+                1
+            };
+
+            for index in position..position + advance {
+                if index + MIN_MATCH <= data.len() {
+                    let key = hash(index);
+                    previous[index % WINDOW_SIZE] = head[key];
+                    head[key] = index;
+                }
+            }
+
+            position += advance;
+        }
+
+        Self::write_literal(&mut writer, 256);
+        writer.flush();
+
+        writer.data
+    }
+
+    fn write_literal(writer: &mut BitWriter, symbol: u32) {
+        match symbol {
+            0..=143 => writer.write_reversed(0x30 + symbol, 8),
+            144..=255 => writer.write_reversed(0x190 + symbol - 144, 9),
+            256..=279 => writer.write_reversed(symbol - 256, 7),
+            _ => writer.write_reversed(0xC0 + symbol - 280, 8),
+        }
+    }
+
+    fn write_match(writer: &mut BitWriter, length: usize, distance: usize) {
+        let length_code = LENGTH_BASE.iter().rposition(|base| *base as usize <= length).unwrap();
+        Self::write_literal(writer, 257 + length_code as u32);
+        writer.write((length - LENGTH_BASE[length_code] as usize) as u32, LENGTH_EXTRA[length_code] as u32);
+
+        let distance_code = DISTANCE_BASE.iter().rposition(|base| *base as usize <= distance).unwrap();
+        writer.write_reversed(distance_code as u32, 5);
+        writer.write((distance - DISTANCE_BASE[distance_code] as usize) as u32, DISTANCE_EXTRA[distance_code] as u32);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decompress_round_trips_compressed_data() {
+        let data: Vec<u8> = (0..10000u32).map(|index| (index * index % 251) as u8).collect();
+
+        assert_eq!(ZLib::decompress(&ZLib::compress(&data), data.len()).unwrap(), data);
+    }
+
+    #[test]
+    fn decompress_stops_at_the_maximum_length() {
+        let data = vec![0u8; 1 << 20];
+        let compressed = ZLib::compress(&data);
+
+        assert!(ZLib::decompress(&compressed, data.len() - 1).is_err());
+        assert!(ZLib::inflate(&compressed[2..], 1000).is_err());
+    }
+
+    #[test]
+    fn inflate_limits_stored_blocks() {
+        let stored = [0x01, 0x04, 0x00, 0xFB, 0xFF, 1, 2, 3, 4];
+
+        assert_eq!(ZLib::inflate(&stored, 4).unwrap(), [1, 2, 3, 4]);
+        assert!(ZLib::inflate(&stored, 3).is_err());
+    }
+}
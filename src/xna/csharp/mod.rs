@@ -51,7 +51,7 @@ impl Exception {
     pub fn create(message: &str, h_result: isize, inner: Option<Exception>) -> Self {
         Exception {
             message: message.to_string(),
-            inner: if inner.is_some() { Some(Box::new(inner.unwrap())) } else { None },
+            inner: inner.map(Box::new),
             h_result, //E_POINTER
         }
     }
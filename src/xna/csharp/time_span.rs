@@ -13,7 +13,7 @@ impl TimeSpan {
             + (milliseconds as i64 * Self::MICROSECONDS_PER_MILLISECOND)
             + microseconds as i64;
 
-        if total_micro_seconds > Self::MAX_MICROSECONDS || total_micro_seconds < Self::MIN_MICROSECONDS {
+        if !(Self::MIN_MICROSECONDS..=Self::MAX_MICROSECONDS).contains(&total_micro_seconds) {
             return Err(Exception::out_of_range("TimeSpan overflowed because the duration is too long", None));
         }
 
@@ -66,7 +66,7 @@ impl TimeSpan {
     }
 
     pub fn total_milliseconds(&self) -> f64 {
-        let temp = (self.ticks / Self::TICKS_PER_MILLISECOND);
+        let temp = self.ticks / Self::TICKS_PER_MILLISECOND;
 
         if temp > Self::MAX_MILLISECONDS {
             return Self::MAX_MILLISECONDS as f64;
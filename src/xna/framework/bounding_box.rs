@@ -0,0 +1,173 @@
+use crate::xna::csharp::Exception;
+use crate::xna::framework::{BoundingBox, BoundingFrustum, BoundingSphere, ContainmentType, Plane, PlaneIntersectionType, Ray, Vector3};
+
+impl BoundingBox {
+    pub const CORNER_COUNT: usize = 8;
+
+    pub fn new(min: Vector3, max: Vector3) -> BoundingBox {
+        BoundingBox { min, max }
+    }
+
+    pub fn get_corners(&self) -> [Vector3; Self::CORNER_COUNT] {
+        [
+            Vector3 { x: self.min.x, y: self.max.y, z: self.max.z },
+            Vector3 { x: self.max.x, y: self.max.y, z: self.max.z },
+            Vector3 { x: self.max.x, y: self.min.y, z: self.max.z },
+            Vector3 { x: self.min.x, y: self.min.y, z: self.max.z },
+            Vector3 { x: self.min.x, y: self.max.y, z: self.min.z },
+            Vector3 { x: self.max.x, y: self.max.y, z: self.min.z },
+            Vector3 { x: self.max.x, y: self.min.y, z: self.min.z },
+            Vector3 { x: self.min.x, y: self.min.y, z: self.min.z },
+        ]
+    }
+
+    pub fn create_merged(original: &BoundingBox, additional: &BoundingBox) -> BoundingBox {
+        BoundingBox {
+            min: Vector3::min(&original.min, &additional.min),
+            max: Vector3::max(&original.max, &additional.max),
+        }
+    }
+
+    pub fn create_from_sphere(sphere: &BoundingSphere) -> BoundingBox {
+        BoundingBox {
+            min: sphere.center - Vector3::from_value(sphere.radius),
+            max: sphere.center + Vector3::from_value(sphere.radius),
+        }
+    }
+
+    pub fn create_from_points(points: &[Vector3]) -> Result<BoundingBox, Exception> {
+        if points.is_empty() {
+            return Err(Exception::argument_exception("points cannot be empty.", None));
+        }
+
+        let mut min = Vector3::from_value(f32::MAX);
+        let mut max = Vector3::from_value(f32::MIN);
+
+        for point in points {
+            min = Vector3::min(&min, point);
+            max = Vector3::max(&max, point);
+        }
+
+        Ok(BoundingBox { min, max })
+    }
+
+    pub fn intersects_box(&self, bounding_box: &BoundingBox) -> bool {
+        if self.max.x < bounding_box.min.x || self.min.x > bounding_box.max.x {
+            return false;
+        }
+
+        if self.max.y < bounding_box.min.y || self.min.y > bounding_box.max.y {
+            return false;
+        }
+
+        self.max.z >= bounding_box.min.z && self.min.z <= bounding_box.max.z
+    }
+
+    pub fn intersects_sphere(&self, sphere: &BoundingSphere) -> bool {
+        let vector = Vector3::clamp(&sphere.center, &self.min, &self.max);
+        let num = Vector3::distance_squared(&sphere.center, &vector);
+
+        num <= sphere.radius * sphere.radius
+    }
+
+    pub fn intersects_plane(&self, plane: &Plane) -> PlaneIntersectionType {
+        plane.intersects_box(self)
+    }
+
+    pub fn intersects_ray(&self, ray: &Ray) -> Option<f32> {
+        let mut num1 = 0.0f32;
+        let mut num2 = f32::MAX;
+
+        let axes = [
+            (ray.position.x, ray.direction.x, self.min.x, self.max.x),
+            (ray.position.y, ray.direction.y, self.min.y, self.max.y),
+            (ray.position.z, ray.direction.z, self.min.z, self.max.z),
+        ];
+
+        for (position, direction, min, max) in axes {
+            if direction.abs() < 1E-06 {
+                if position < min || position > max {
+                    return None;
+                }
+
+                continue;
+            }
+
+            let num3 = 1.0 / direction;
+            let mut num4 = (min - position) * num3;
+            let mut num5 = (max - position) * num3;
+
+            if num4 > num5 {
+                std::mem::swap(&mut num4, &mut num5);
+            }
+
+            num1 = num1.max(num4);
+            num2 = num2.min(num5);
+
+            if num1 > num2 {
+                return None;
+            }
+        }
+
+        Some(num1)
+    }
+
+    pub fn contains_box(&self, bounding_box: &BoundingBox) -> ContainmentType {
+        if !self.intersects_box(bounding_box) {
+            return ContainmentType::Disjoint;
+        }
+
+        if self.min.x <= bounding_box.min.x && bounding_box.max.x <= self.max.x
+            && self.min.y <= bounding_box.min.y && bounding_box.max.y <= self.max.y
+            && self.min.z <= bounding_box.min.z && bounding_box.max.z <= self.max.z {
+            return ContainmentType::Contains;
+        }
+
+        ContainmentType::Intersects
+    }
+
+    pub fn contains_sphere(&self, sphere: &BoundingSphere) -> ContainmentType {
+        if !self.intersects_sphere(sphere) {
+            return ContainmentType::Disjoint;
+        }
+
+        let center = sphere.center;
+        let radius = sphere.radius;
+
+        if self.min.x + radius <= center.x && center.x <= self.max.x - radius && self.max.x - self.min.x > radius
+            && self.min.y + radius <= center.y && center.y <= self.max.y - radius && self.max.y - self.min.y > radius
+            && self.min.z + radius <= center.z && center.z <= self.max.z - radius && self.max.z - self.min.z > radius {
+            return ContainmentType::Contains;
+        }
+
+        ContainmentType::Intersects
+    }
+
+    pub fn contains_point(&self, point: &Vector3) -> ContainmentType {
+        if self.min.x <= point.x && point.x <= self.max.x
+            && self.min.y <= point.y && point.y <= self.max.y
+            && self.min.z <= point.z && point.z <= self.max.z {
+            return ContainmentType::Contains;
+        }
+
+        ContainmentType::Disjoint
+    }
+
+    pub fn intersects_frustum(&self, frustum: &BoundingFrustum) -> bool {
+        frustum.intersects_box(self)
+    }
+
+    pub fn contains_frustum(&self, frustum: &BoundingFrustum) -> ContainmentType {
+        if !frustum.intersects_box(self) {
+            return ContainmentType::Disjoint;
+        }
+
+        for corner in frustum.get_corners() {
+            if self.contains_point(&corner) == ContainmentType::Disjoint {
+                return ContainmentType::Intersects;
+            }
+        }
+
+        ContainmentType::Contains
+    }
+}
@@ -0,0 +1,421 @@
+use crate::xna::framework::{BoundingBox, BoundingFrustum, BoundingSphere, ContainmentType, Matrix, Plane, PlaneIntersectionType, Ray, Vector3};
+
+impl BoundingFrustum {
+    pub const CORNER_COUNT: usize = 8;
+
+    const NEAR_PLANE_INDEX: usize = 0;
+    const FAR_PLANE_INDEX: usize = 1;
+    const LEFT_PLANE_INDEX: usize = 2;
+    const RIGHT_PLANE_INDEX: usize = 3;
+    const TOP_PLANE_INDEX: usize = 4;
+    const BOTTOM_PLANE_INDEX: usize = 5;
+
+    const EDGES: [(usize, usize); 12] = [
+        (0, 1), (1, 2), (2, 3), (3, 0),
+        (4, 5), (5, 6), (6, 7), (7, 4),
+        (0, 4), (1, 5), (2, 6), (3, 7),
+    ];
+
+    pub fn new(value: &Matrix) -> BoundingFrustum {
+        let mut frustum = BoundingFrustum::default();
+        frustum.set_matrix(value);
+
+        frustum
+    }
+
+    pub fn matrix(&self) -> Matrix { self.matrix }
+
+    pub fn near(&self) -> Plane { self.planes[Self::NEAR_PLANE_INDEX] }
+
+    pub fn far(&self) -> Plane { self.planes[Self::FAR_PLANE_INDEX] }
+
+    pub fn left(&self) -> Plane { self.planes[Self::LEFT_PLANE_INDEX] }
+
+    pub fn right(&self) -> Plane { self.planes[Self::RIGHT_PLANE_INDEX] }
+
+    pub fn top(&self) -> Plane { self.planes[Self::TOP_PLANE_INDEX] }
+
+    pub fn bottom(&self) -> Plane { self.planes[Self::BOTTOM_PLANE_INDEX] }
+
+    pub fn get_corners(&self) -> [Vector3; Self::CORNER_COUNT] { self.corners }
+
+    pub fn set_matrix(&mut self, value: &Matrix) {
+        self.matrix = *value;
+
+        self.planes[Self::NEAR_PLANE_INDEX] = Plane::new(-value.m13, -value.m23, -value.m33, -value.m43);
+        self.planes[Self::FAR_PLANE_INDEX] = Plane::new(
+            -value.m14 + value.m13, -value.m24 + value.m23, -value.m34 + value.m33, -value.m44 + value.m43);
+        self.planes[Self::LEFT_PLANE_INDEX] = Plane::new(
+            -value.m14 - value.m11, -value.m24 - value.m21, -value.m34 - value.m31, -value.m44 - value.m41);
+        self.planes[Self::RIGHT_PLANE_INDEX] = Plane::new(
+            -value.m14 + value.m11, -value.m24 + value.m21, -value.m34 + value.m31, -value.m44 + value.m41);
+        self.planes[Self::TOP_PLANE_INDEX] = Plane::new(
+            -value.m14 + value.m12, -value.m24 + value.m22, -value.m34 + value.m32, -value.m44 + value.m42);
+        self.planes[Self::BOTTOM_PLANE_INDEX] = Plane::new(
+            -value.m14 - value.m12, -value.m24 - value.m22, -value.m34 - value.m32, -value.m44 - value.m42);
+
+        for plane in self.planes.iter_mut() {
+            let num = 1.0 / plane.normal.length();
+            plane.normal *= num;
+            plane.d *= num;
+        }
+
+        let ray = Self::compute_intersection_line(&self.near(), &self.left());
+        self.corners[0] = Self::compute_intersection(&self.top(), &ray);
+        self.corners[3] = Self::compute_intersection(&self.bottom(), &ray);
+
+        let ray = Self::compute_intersection_line(&self.right(), &self.near());
+        self.corners[1] = Self::compute_intersection(&self.top(), &ray);
+        self.corners[2] = Self::compute_intersection(&self.bottom(), &ray);
+
+        let ray = Self::compute_intersection_line(&self.left(), &self.far());
+        self.corners[4] = Self::compute_intersection(&self.top(), &ray);
+        self.corners[7] = Self::compute_intersection(&self.bottom(), &ray);
+
+        let ray = Self::compute_intersection_line(&self.far(), &self.right());
+        self.corners[5] = Self::compute_intersection(&self.top(), &ray);
+        self.corners[6] = Self::compute_intersection(&self.bottom(), &ray);
+    }
+
+    pub fn contains_point(&self, point: &Vector3) -> ContainmentType {
+        for plane in &self.planes {
+            if plane.dot_coordinate(point) > 1E-05 {
+                return ContainmentType::Disjoint;
+            }
+        }
+
+        ContainmentType::Contains
+    }
+
+    pub fn contains_box(&self, bounding_box: &BoundingBox) -> ContainmentType {
+        if !self.intersects_box(bounding_box) {
+            return ContainmentType::Disjoint;
+        }
+
+        for plane in &self.planes {
+            if plane.intersects_box(bounding_box) != PlaneIntersectionType::Back {
+                return ContainmentType::Intersects;
+            }
+        }
+
+        ContainmentType::Contains
+    }
+
+    pub fn contains_sphere(&self, sphere: &BoundingSphere) -> ContainmentType {
+        if !self.intersects_sphere(sphere) {
+            return ContainmentType::Disjoint;
+        }
+
+        for plane in &self.planes {
+            if plane.dot_coordinate(&sphere.center) > -sphere.radius {
+                return ContainmentType::Intersects;
+            }
+        }
+
+        ContainmentType::Contains
+    }
+
+    pub fn contains_frustum(&self, frustum: &BoundingFrustum) -> ContainmentType {
+        if !self.intersects_frustum(frustum) {
+            return ContainmentType::Disjoint;
+        }
+
+        for corner in &frustum.corners {
+            if self.contains_point(corner) == ContainmentType::Disjoint {
+                return ContainmentType::Intersects;
+            }
+        }
+
+        ContainmentType::Contains
+    }
+
+    pub fn intersects_box(&self, bounding_box: &BoundingBox) -> bool {
+        let axes = [Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z()];
+        let corners = bounding_box.get_corners();
+
+        self.intersects_convex(&corners, &axes, &axes)
+    }
+
+    pub fn intersects_frustum(&self, frustum: &BoundingFrustum) -> bool {
+        let normals = frustum.planes.map(|plane| plane.normal);
+        let edges = frustum.edge_directions();
+
+        self.intersects_convex(&frustum.corners, &normals, &edges)
+    }
+
+    pub fn intersects_sphere(&self, sphere: &BoundingSphere) -> bool {
+        if self.contains_point(&sphere.center) == ContainmentType::Contains {
+            return true;
+        }
+
+        let num1 = sphere.radius * sphere.radius;
+
+        for plane in &self.planes {
+            let num2 = plane.dot_coordinate(&sphere.center);
+
+            if num2 > sphere.radius {
+                return false;
+            }
+
+            let point = sphere.center - plane.normal * num2;
+
+            if num2 * num2 <= num1 && self.contains_point(&point) == ContainmentType::Contains {
+                return true;
+            }
+        }
+
+        for (start, end) in Self::EDGES {
+            let point = Self::closest_point_on_segment(&sphere.center, &self.corners[start], &self.corners[end]);
+
+            if Vector3::distance_squared(&sphere.center, &point) <= num1 {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    pub fn intersects_plane(&self, plane: &Plane) -> PlaneIntersectionType {
+        let mut front = false;
+        let mut back = false;
+
+        for corner in &self.corners {
+            if plane.dot_coordinate(corner) > 0.0 {
+                front = true;
+            } else {
+                back = true;
+            }
+
+            if front && back {
+                return PlaneIntersectionType::Intersecting;
+            }
+        }
+
+        if front { PlaneIntersectionType::Front } else { PlaneIntersectionType::Back }
+    }
+
+    pub fn intersects_ray(&self, ray: &Ray) -> Option<f32> {
+        if self.contains_point(&ray.position) == ContainmentType::Contains {
+            return Some(0.0);
+        }
+
+        let mut num1 = f32::MIN;
+        let mut num2 = f32::MAX;
+
+        for plane in &self.planes {
+            let num3 = Vector3::dot(&plane.normal, &ray.direction);
+            let num4 = plane.dot_coordinate(&ray.position);
+
+            if num3.abs() < 1E-05 {
+                if num4 > 0.0 {
+                    return None;
+                }
+
+                continue;
+            }
+
+            let num5 = -num4 / num3;
+
+            if num3 < 0.0 {
+                num1 = num1.max(num5);
+            } else {
+                num2 = num2.min(num5);
+            }
+
+            if num1 > num2 {
+                return None;
+            }
+        }
+
+        if num2 < 0.0 {
+            return None;
+        }
+
+        Some(num1.max(0.0))
+    }
+
+    fn edge_directions(&self) -> [Vector3; 6] {
+        [
+            self.corners[1] - self.corners[0],
+            self.corners[2] - self.corners[1],
+            self.corners[4] - self.corners[0],
+            self.corners[5] - self.corners[1],
+            self.corners[6] - self.corners[2],
+            self.corners[7] - self.corners[3],
+        ]
+    }
+
+    fn intersects_convex(&self, corners: &[Vector3], normals: &[Vector3], edges: &[Vector3]) -> bool {
+        let own_normals = self.planes.map(|plane| plane.normal);
+        let own_edges = self.edge_directions();
+
+        for axis in own_normals.iter().chain(normals.iter()) {
+            if Self::is_separating_axis(axis, &self.corners, corners) {
+                return false;
+            }
+        }
+
+        for own_edge in &own_edges {
+            for edge in edges {
+                let axis = Vector3::cross(own_edge, edge);
+
+                if axis.length_squared() < 1E-10 {
+                    continue;
+                }
+
+                if Self::is_separating_axis(&axis, &self.corners, corners) {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    fn is_separating_axis(axis: &Vector3, corners1: &[Vector3], corners2: &[Vector3]) -> bool {
+        let (min1, max1) = Self::project(axis, corners1);
+        let (min2, max2) = Self::project(axis, corners2);
+
+        max1 < min2 || max2 < min1
+    }
+
+    fn project(axis: &Vector3, corners: &[Vector3]) -> (f32, f32) {
+        let mut min = f32::MAX;
+        let mut max = f32::MIN;
+
+        for corner in corners {
+            let num = Vector3::dot(axis, corner);
+            min = min.min(num);
+            max = max.max(num);
+        }
+
+        (min, max)
+    }
+
+    fn closest_point_on_segment(point: &Vector3, start: &Vector3, end: &Vector3) -> Vector3 {
+        let vector = *end - *start;
+        let num1 = vector.length_squared();
+
+        if num1 < 1E-10 {
+            return *start;
+        }
+
+        let num2 = (Vector3::dot(&(*point - *start), &vector) / num1).clamp(0.0, 1.0);
+
+        *start + vector * num2
+    }
+
+    fn compute_intersection_line(plane1: &Plane, plane2: &Plane) -> Ray {
+        let direction = Vector3::cross(&plane1.normal, &plane2.normal);
+        let num = direction.length_squared();
+        let vector = plane2.normal * -plane1.d + plane1.normal * plane2.d;
+
+        Ray {
+            position: Vector3::cross(&vector, &direction) / num,
+            direction,
+        }
+    }
+
+    fn compute_intersection(plane: &Plane, ray: &Ray) -> Vector3 {
+        let num = (-plane.d - Vector3::dot(&plane.normal, &ray.position)) / Vector3::dot(&plane.normal, &ray.direction);
+
+        ray.position + ray.direction * num
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vector(x: f32, y: f32, z: f32) -> Vector3 {
+        Vector3 { x, y, z }
+    }
+
+    fn frustum_at(position: Vector3) -> BoundingFrustum {
+        let view = Matrix::create_look_at(&position, &(position - Vector3::unit_z()), &Vector3::up());
+        let projection = Matrix::create_perspective_field_of_view(std::f32::consts::FRAC_PI_2, 1.0, 1.0, 100.0).unwrap();
+
+        BoundingFrustum::new(&(view * projection))
+    }
+
+    fn camera() -> BoundingFrustum {
+        frustum_at(vector(0.0, 0.0, 10.0))
+    }
+
+    #[test]
+    fn corners_lie_on_the_near_and_far_planes() {
+        let corners = camera().get_corners();
+
+        assert!((corners[0] - vector(-1.0, 1.0, 9.0)).length() < 1e-4);
+        assert!((corners[2] - vector(1.0, -1.0, 9.0)).length() < 1e-4);
+        assert!((corners[4] - vector(-100.0, 100.0, -90.0)).length() < 1e-2);
+        assert!((corners[6] - vector(100.0, -100.0, -90.0)).length() < 1e-2);
+    }
+
+    #[test]
+    fn contains_point_checks_every_plane() {
+        let frustum = camera();
+
+        assert_eq!(frustum.contains_point(&Vector3::zero()), ContainmentType::Contains);
+        assert_eq!(frustum.contains_point(&vector(0.0, 0.0, 20.0)), ContainmentType::Disjoint);
+        assert_eq!(frustum.contains_point(&vector(0.0, 0.0, 9.5)), ContainmentType::Disjoint);
+        assert_eq!(frustum.contains_point(&vector(0.0, 0.0, -95.0)), ContainmentType::Disjoint);
+        assert_eq!(frustum.contains_point(&vector(11.0, 0.0, 0.0)), ContainmentType::Disjoint);
+    }
+
+    #[test]
+    fn contains_box_classifies_inside_straddling_and_outside() {
+        let frustum = camera();
+
+        assert_eq!(frustum.contains_box(&BoundingBox::new(vector(-1.0, -1.0, -1.0), vector(1.0, 1.0, 1.0))), ContainmentType::Contains);
+        assert_eq!(frustum.contains_box(&BoundingBox::new(vector(9.0, -1.0, -1.0), vector(11.0, 1.0, 1.0))), ContainmentType::Intersects);
+        assert_eq!(frustum.contains_box(&BoundingBox::new(vector(49.0, -1.0, -1.0), vector(51.0, 1.0, 1.0))), ContainmentType::Disjoint);
+    }
+
+    #[test]
+    fn intersects_box_rejects_a_box_beside_the_apex() {
+        let frustum = camera();
+        let beside = BoundingBox::new(vector(4.5, 4.5, 7.5), vector(5.5, 5.5, 8.5));
+
+        assert!(!frustum.intersects_box(&beside));
+        assert_eq!(frustum.contains_box(&beside), ContainmentType::Disjoint);
+    }
+
+    #[test]
+    fn contains_sphere_classifies_inside_straddling_and_outside() {
+        let frustum = camera();
+
+        assert_eq!(frustum.contains_sphere(&BoundingSphere::new(Vector3::zero(), 1.0)), ContainmentType::Contains);
+        assert_eq!(frustum.contains_sphere(&BoundingSphere::new(vector(10.0, 0.0, 0.0), 1.0)), ContainmentType::Intersects);
+        assert_eq!(frustum.contains_sphere(&BoundingSphere::new(vector(100.0, 0.0, 0.0), 1.0)), ContainmentType::Disjoint);
+        assert_eq!(frustum.contains_sphere(&BoundingSphere::new(vector(5.0, 5.0, 8.0), 0.5)), ContainmentType::Disjoint);
+    }
+
+    #[test]
+    fn frustum_queries_detect_overlap_and_separation() {
+        let frustum = camera();
+        let overlapping = frustum_at(vector(5.0, 0.0, 10.0));
+        let separated = frustum_at(vector(1000.0, 0.0, 10.0));
+
+        assert!(frustum.intersects_frustum(&overlapping));
+        assert!(!frustum.intersects_frustum(&separated));
+        assert_eq!(frustum.contains_frustum(&frustum), ContainmentType::Contains);
+        assert_eq!(frustum.contains_frustum(&overlapping), ContainmentType::Intersects);
+        assert_eq!(frustum.contains_frustum(&separated), ContainmentType::Disjoint);
+    }
+
+    #[test]
+    fn plane_and_ray_queries() {
+        let frustum = camera();
+
+        assert_eq!(frustum.intersects_plane(&Plane::new(0.0, 0.0, 1.0, 0.0)), PlaneIntersectionType::Intersecting);
+        assert_eq!(frustum.intersects_plane(&Plane::new(0.0, 0.0, 1.0, 200.0)), PlaneIntersectionType::Front);
+        assert_eq!(frustum.intersects_plane(&Plane::new(0.0, 0.0, 1.0, -200.0)), PlaneIntersectionType::Back);
+
+        let distance = frustum.intersects_ray(&Ray::new(vector(0.0, 0.0, 20.0), -Vector3::unit_z())).unwrap();
+
+        assert!((distance - 11.0).abs() < 1e-4);
+        assert_eq!(frustum.intersects_ray(&Ray::new(Vector3::zero(), Vector3::unit_x())), Some(0.0));
+        assert_eq!(frustum.intersects_ray(&Ray::new(vector(0.0, 0.0, 20.0), Vector3::unit_z())), None);
+    }
+}
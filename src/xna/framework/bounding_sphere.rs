@@ -0,0 +1,176 @@
+use crate::xna::csharp::Exception;
+use crate::xna::framework::{BoundingBox, BoundingFrustum, BoundingSphere, ContainmentType, Matrix, Plane, PlaneIntersectionType, Ray, Vector3};
+
+impl BoundingSphere {
+    pub fn new(center: Vector3, radius: f32) -> BoundingSphere {
+        BoundingSphere { center, radius }
+    }
+
+    pub fn create_merged(original: &BoundingSphere, additional: &BoundingSphere) -> BoundingSphere {
+        let vector = additional.center - original.center;
+        let num1 = vector.length();
+        let radius1 = original.radius;
+        let radius2 = additional.radius;
+
+        if radius1 + radius2 >= num1 {
+            if radius1 - radius2 >= num1 {
+                return *original;
+            }
+
+            if radius2 - radius1 >= num1 {
+                return *additional;
+            }
+        }
+
+        let vector2 = vector * (1.0 / num1);
+        let num2 = (-radius1).min(num1 - radius2);
+        let num3 = (radius1.max(num1 + radius2) - num2) * 0.5;
+
+        BoundingSphere {
+            center: original.center + vector2 * (num3 + num2),
+            radius: num3,
+        }
+    }
+
+    pub fn create_from_box(bounding_box: &BoundingBox) -> BoundingSphere {
+        let center = Vector3::lerp(&bounding_box.min, &bounding_box.max, 0.5);
+        let radius = Vector3::distance(&bounding_box.min, &bounding_box.max) * 0.5;
+
+        BoundingSphere { center, radius }
+    }
+
+    pub fn create_from_points(points: &[Vector3]) -> Result<BoundingSphere, Exception> {
+        if points.is_empty() {
+            return Err(Exception::argument_exception("points cannot be empty.", None));
+        }
+
+        let mut min_x = points[0];
+        let mut max_x = points[0];
+        let mut min_y = points[0];
+        let mut max_y = points[0];
+        let mut min_z = points[0];
+        let mut max_z = points[0];
+
+        for point in points {
+            if point.x < min_x.x { min_x = *point; }
+            if point.x > max_x.x { max_x = *point; }
+            if point.y < min_y.y { min_y = *point; }
+            if point.y > max_y.y { max_y = *point; }
+            if point.z < min_z.z { min_z = *point; }
+            if point.z > max_z.z { max_z = *point; }
+        }
+
+        let num1 = Vector3::distance_squared(&max_x, &min_x);
+        let num2 = Vector3::distance_squared(&max_y, &min_y);
+        let num3 = Vector3::distance_squared(&max_z, &min_z);
+
+        let (mut center, mut radius) = if num1 > num2 && num1 > num3 {
+            (Vector3::lerp(&max_x, &min_x, 0.5), num1.sqrt() * 0.5)
+        } else if num2 > num3 {
+            (Vector3::lerp(&max_y, &min_y, 0.5), num2.sqrt() * 0.5)
+        } else {
+            (Vector3::lerp(&max_z, &min_z, 0.5), num3.sqrt() * 0.5)
+        };
+
+        for point in points {
+            let vector = *point - center;
+            let num4 = vector.length();
+
+            if num4 > radius {
+                radius = (radius + num4) * 0.5;
+                center += vector * (1.0 - radius / num4);
+            }
+        }
+
+        Ok(BoundingSphere { center, radius })
+    }
+
+    pub fn transform(&self, matrix: &Matrix) -> BoundingSphere {
+        let num1 = matrix.m11 * matrix.m11 + matrix.m12 * matrix.m12 + matrix.m13 * matrix.m13;
+        let num2 = matrix.m21 * matrix.m21 + matrix.m22 * matrix.m22 + matrix.m23 * matrix.m23;
+        let num3 = matrix.m31 * matrix.m31 + matrix.m32 * matrix.m32 + matrix.m33 * matrix.m33;
+        let num4 = num1.max(num2).max(num3);
+
+        BoundingSphere {
+            center: Vector3::transform(&self.center, matrix),
+            radius: self.radius * num4.sqrt(),
+        }
+    }
+
+    pub fn intersects_box(&self, bounding_box: &BoundingBox) -> bool {
+        bounding_box.intersects_sphere(self)
+    }
+
+    pub fn intersects_sphere(&self, sphere: &BoundingSphere) -> bool {
+        let num1 = Vector3::distance_squared(&self.center, &sphere.center);
+        let num2 = self.radius + sphere.radius;
+
+        num1 <= num2 * num2
+    }
+
+    pub fn intersects_plane(&self, plane: &Plane) -> PlaneIntersectionType {
+        plane.intersects_sphere(self)
+    }
+
+    pub fn intersects_ray(&self, ray: &Ray) -> Option<f32> {
+        ray.intersects_sphere(self)
+    }
+
+    pub fn contains_box(&self, bounding_box: &BoundingBox) -> ContainmentType {
+        if !bounding_box.intersects_sphere(self) {
+            return ContainmentType::Disjoint;
+        }
+
+        let num = self.radius * self.radius;
+
+        for corner in bounding_box.get_corners() {
+            if Vector3::distance_squared(&self.center, &corner) > num {
+                return ContainmentType::Intersects;
+            }
+        }
+
+        ContainmentType::Contains
+    }
+
+    pub fn contains_sphere(&self, sphere: &BoundingSphere) -> ContainmentType {
+        let num = Vector3::distance(&self.center, &sphere.center);
+
+        if self.radius + sphere.radius < num {
+            return ContainmentType::Disjoint;
+        }
+
+        if self.radius - sphere.radius < num {
+            return ContainmentType::Intersects;
+        }
+
+        ContainmentType::Contains
+    }
+
+    pub fn contains_point(&self, point: &Vector3) -> ContainmentType {
+        if Vector3::distance_squared(point, &self.center) < self.radius * self.radius {
+            return ContainmentType::Contains;
+        }
+
+        ContainmentType::Disjoint
+    }
+
+    pub fn intersects_frustum(&self, frustum: &BoundingFrustum) -> bool {
+        frustum.intersects_sphere(self)
+    }
+
+    pub fn contains_frustum(&self, frustum: &BoundingFrustum) -> ContainmentType {
+        if !frustum.intersects_sphere(self) {
+            return ContainmentType::Disjoint;
+        }
+
+        let num = self.radius * self.radius;
+
+        for corner in frustum.get_corners() {
+            if Vector3::distance_squared(&self.center, &corner) > num {
+                return ContainmentType::Intersects;
+            }
+        }
+
+        ContainmentType::Contains
+    }
+}
@@ -1,5 +1,7 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+use crate::xna::csharp::Exception;
 use crate::xna::framework::{Color, Vector3, Vector4};
-use crate::xna::framework::graphics::{IPackedVector, PackUtils};
+use crate::xna::framework::graphics::{IPackedVector, PackUtils, PackedVector};
 
 impl IPackedVector for Color {
     fn to_vector4(&self) -> Vector4 {
@@ -10,6 +12,20 @@ impl IPackedVector for Color {
 
         Vector4 {x, y, z, w}
     }
+
+    fn from_vector4(vector: Vector4) -> Self {
+        Color::from_vector4(vector)
+    }
+}
+
+impl PackedVector<u32> for Color {
+    fn packed_value(&self) -> u32 {
+        self.packed_value
+    }
+
+    fn set_packed_value(&mut self, value: u32) {
+        self.packed_value = value;
+    }
 }
 
 impl Color {
@@ -23,9 +39,9 @@ impl Color {
         let b1;
 
         if ((r | g  | b) & -256) != 0 {
-            r1 = Self::clamp_to_byte64(r as u64);
-            g1 = Self::clamp_to_byte64(g as u64);
-            b1 = Self::clamp_to_byte64(b as u64);
+            r1 = Self::clamp_to_byte64(r as i64);
+            g1 = Self::clamp_to_byte64(g as i64);
+            b1 = Self::clamp_to_byte64(b as i64);
         } else {
             r1 = r;
             g1 = g;
@@ -46,10 +62,10 @@ impl Color {
         let a1;
 
         if ((r | g  | b | a) & -256) != 0 {
-            r1 = Self::clamp_to_byte64(r as u64);
-            g1 = Self::clamp_to_byte64(g as u64);
-            b1 = Self::clamp_to_byte64(b as u64);
-            a1 = Self::clamp_to_byte64(a as u64);
+            r1 = Self::clamp_to_byte64(r as i64);
+            g1 = Self::clamp_to_byte64(g as i64);
+            b1 = Self::clamp_to_byte64(b as i64);
+            a1 = Self::clamp_to_byte64(a as i64);
         } else {
             r1 = r;
             g1 = g;
@@ -85,16 +101,16 @@ impl Color {
         Self::from_packed_value(packed_value)
     }
 
-    pub fn from_nom_premultiplied(vector: Vector4) -> Color {
+    pub fn from_non_premultiplied(vector: Vector4) -> Color {
         let packed_value = Self::pack_helper(vector.x * vector.w, vector.y * vector.w, vector.z * vector.w, vector.w);
         Self::from_packed_value(packed_value)
     }
 
-    pub fn from_nom_premultiplied_rgba(r: i32, g: i32, b: i32, a: i32) -> Color {
-        let r1 = Self::clamp_to_byte64((r * a) as u64 / u8::MAX  as u64);
-        let g1 = Self::clamp_to_byte64((g * a) as u64 / u8::MAX as u64);
-        let b1 = Self::clamp_to_byte64((b * a) as u64 / u8::MAX as u64);
-        let a1 = Self::clamp_to_byte64(a as u64);
+    pub fn from_non_premultiplied_rgba(r: i32, g: i32, b: i32, a: i32) -> Color {
+        let r1 = Self::clamp_to_byte64(r as i64 * a as i64 / u8::MAX as i64);
+        let g1 = Self::clamp_to_byte64(g as i64 * a as i64 / u8::MAX as i64);
+        let b1 = Self::clamp_to_byte64(b as i64 * a as i64 / u8::MAX as i64);
+        let a1 = Self::clamp_to_byte64(a as i64);
 
         let g2 = g1 << 8;
         let b2 = b1 << 16;
@@ -104,11 +120,21 @@ impl Color {
         Self::from_packed_value(packed_value)
     }
 
+    #[deprecated(note = "use from_non_premultiplied instead")]
+    pub fn from_nom_premultiplied(vector: Vector4) -> Color {
+        Self::from_non_premultiplied(vector)
+    }
+
+    #[deprecated(note = "use from_non_premultiplied_rgba instead")]
+    pub fn from_nom_premultiplied_rgba(r: i32, g: i32, b: i32, a: i32) -> Color {
+        Self::from_non_premultiplied_rgba(r, g, b, a)
+    }
+
     pub fn r(&self)
              -> u8 { self.packed_value as u8 }
 
     pub fn set_r(&mut self, value: u8) {
-        self.packed_value = self.packed_value & & 4294967040u32 | value as u32;
+        self.packed_value = self.packed_value & 4294967040u32 | value as u32;
     }
 
     pub fn g(&self)
@@ -132,12 +158,12 @@ impl Color {
         self.packed_value = ((self.packed_value as i32 & 16777215) | (value as i32) << 24) as u32;
     }
 
-    pub fn clamp_to_byte64(value: u64) -> i32 {
+    pub fn clamp_to_byte64(value: i64) -> i32 {
         if value < 0{
             return 0;
         }
 
-        if value > u8::MAX as u64{
+        if value > u8::MAX as i64{
             return u8::MAX as i32;
         }
 
@@ -154,59 +180,197 @@ impl Color {
         let num7 = value2.b() as i32;
         let num8 = value2.a() as i32;
         let num9 = PackUtils::pack_unorm(65536.0, amount) as i32;
-        let num10 = num1 + ((num5 - num1) * num9 >> 16);
-        let num11 = num2 + ((num6 - num2) * num9 >> 16);
-        let num12 = num3 + ((num7 - num3) * num9 >> 16);
-        let num13 = num4 + ((num8 - num4) * num9 >> 16);
+        let num10 = num1 + (((num5 - num1) * num9) >> 16);
+        let num11 = num2 + (((num6 - num2) * num9) >> 16);
+        let num12 = num3 + (((num7 - num3) * num9) >> 16);
+        let num13 = num4 + (((num8 - num4) * num9) >> 16);
         let packed_value = (num10 | num11 << 8 | num12 << 16 | num13 << 24) as u32;
         Color{ packed_value }
     }
 
     pub fn multiply(value: &Color, scale: f32) -> Color {
-        let num1 = value.packed_value;
-        let num2 = value.r() as u32;
-        let num3 = value.g() as u32;
+        let num1 = value.r() as u32;
+        let num2 = value.g() as u32;
+        let num3 = value.b() as u32;
         let num4 = value.a() as u32;
         let scale1 = scale * 65536.0;
-        let num5 : u32;
-
-        if scale1 >= 0.0 {
-            if(scale1 <= 16777215.0){
-                num5 = scale as u32;
-            }else {
-                num5 = 16777215u32;
-            }
+        let num5 = if scale1 >= 0.0 {
+            if scale1 <= 16777215.0 { scale1 as u32 } else { 16777215u32 }
         } else {
-            num5 = 0;
-        }
+            0
+        };
+
+        let num6 = (num1 * num5) >> 16;
+        let num7 = (num2 * num5) >> 16;
+        let num8 = (num3 * num5) >> 16;
+        let num9 = (num4 * num5) >> 16;
 
-        let num6 = num1 * num5 >> 16;
-        let num7 = num2 * num5 >> 16;
-        let num8 = num3 * num5 >> 16;
-        let num9 = num4 * num5 >> 16;
-
-        let num10 : u32;
-        let num11 : u32;
-        let num12 : u32;
-        let num13 : u32;
-
-        if (num6 > u8::MAX as u32){
-            num10 = u8::MAX as u32;
-        } else { num10 = num6}
-        if (num7 > u8::MAX as u32){
-            num11 = u8::MAX as u32;
-        } else { num11 = num7}
-        if (num8 > u8::MAX as u32){
-            num12 = u8::MAX as u32;
-        } else { num12 = num8}
-        if (num9 > u8::MAX as u32){
-            num13 = u8::MAX as u32;
-        } else { num13 = num9}
+        let num10 = num6.min(u8::MAX as u32);
+        let num11 = num7.min(u8::MAX as u32);
+        let num12 = num8.min(u8::MAX as u32);
+        let num13 = num9.min(u8::MAX as u32);
 
         let packed_value = ((num10 as i32) | (num11 as i32) << 8 | (num12 as i32) << 16 | (num13 as i32) << 24) as u32;
         Color { packed_value }
     }
 
+    pub fn from_hex(value: &str) -> Result<Color, Exception> {
+        let hex = value.strip_prefix('#').unwrap_or(value);
+
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(Exception::argument_exception("value is not a valid hexadecimal color.", None));
+        }
+
+        let digits: Vec<u8> = match hex.len() {
+            3 | 4 => hex.chars().map(|c| {
+                let digit = c.to_digit(16).unwrap() as u8;
+                digit << 4 | digit
+            }).collect(),
+            6 | 8 => (0..hex.len()).step_by(2)
+                .map(|index| u8::from_str_radix(&hex[index..index + 2], 16).unwrap())
+                .collect(),
+            _ => return Err(Exception::argument_exception("value must have 3, 4, 6 or 8 hexadecimal digits.", None)),
+        };
+
+        let a = if digits.len() == 4 { digits[3] } else { u8::MAX };
+
+        Ok(Color::from_rgba(digits[0] as i32, digits[1] as i32, digits[2] as i32, a as i32))
+    }
+
+    pub fn to_hex(self) -> String {
+        format!("#{:02X}{:02X}{:02X}{:02X}", self.r(), self.g(), self.b(), self.a())
+    }
+
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Color {
+        Self::from_hsva(hue, saturation, value, 1.0)
+    }
+
+    pub fn from_hsva(hue: f32, saturation: f32, value: f32, alpha: f32) -> Color {
+        let saturation = saturation.clamp(0.0, 1.0);
+        let value = value.clamp(0.0, 1.0);
+        let chroma = value * saturation;
+
+        Self::from_chroma(hue, chroma, value - chroma, alpha)
+    }
+
+    pub fn to_hsv(self) -> (f32, f32, f32) {
+        let (hue, max, delta) = self.hue_max_delta();
+        let saturation = if max == 0.0 { 0.0 } else { delta / max };
+
+        (hue, saturation, max)
+    }
+
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Color {
+        Self::from_hsla(hue, saturation, lightness, 1.0)
+    }
+
+    pub fn from_hsla(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Color {
+        let saturation = saturation.clamp(0.0, 1.0);
+        let lightness = lightness.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+
+        Self::from_chroma(hue, chroma, lightness - chroma * 0.5, alpha)
+    }
+
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let (hue, max, delta) = self.hue_max_delta();
+        let lightness = max - delta * 0.5;
+        let num = 1.0 - (2.0 * lightness - 1.0).abs();
+        let saturation = if delta == 0.0 || num <= 0.0 { 0.0 } else { (delta / num).min(1.0) };
+
+        (hue, saturation, lightness)
+    }
+
+    pub fn premultiply(value: &Color) -> Color {
+        let a = value.a() as u32;
+        let num = |channel: u8| ((channel as u32 * a + 127) / 255) as i32;
+
+        Color::from_rgba(num(value.r()), num(value.g()), num(value.b()), a as i32)
+    }
+
+    pub fn unpremultiply(value: &Color) -> Color {
+        let a = value.a() as u32;
+
+        if a == 0 {
+            return Color::transparent();
+        }
+
+        let num = |channel: u8| ((channel as u32 * 255 + a / 2) / a) as i32;
+
+        Color::from_rgba(num(value.r()), num(value.g()), num(value.b()), a as i32)
+    }
+
+    pub fn srgb_to_linear(value: f32) -> f32 {
+        if value <= 0.04045 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    }
+
+    pub fn linear_to_srgb(value: f32) -> f32 {
+        if value <= 0.0031308 {
+            value * 12.92
+        } else {
+            1.055 * value.powf(1.0 / 2.4) - 0.055
+        }
+    }
+
+    pub fn to_linear(value: &Color) -> Color {
+        let vector = value.to_vector4();
+
+        Color::from_float_rgba(
+            Self::srgb_to_linear(vector.x),
+            Self::srgb_to_linear(vector.y),
+            Self::srgb_to_linear(vector.z),
+            vector.w)
+    }
+
+    pub fn to_srgb(value: &Color) -> Color {
+        let vector = value.to_vector4();
+
+        Color::from_float_rgba(
+            Self::linear_to_srgb(vector.x),
+            Self::linear_to_srgb(vector.y),
+            Self::linear_to_srgb(vector.z),
+            vector.w)
+    }
+
+    fn from_chroma(hue: f32, chroma: f32, m: f32, alpha: f32) -> Color {
+        let num1 = hue.rem_euclid(360.0) / 60.0;
+        let num2 = chroma * (1.0 - (num1 % 2.0 - 1.0).abs());
+
+        let (r, g, b) = match num1 as i32 {
+            0 => (chroma, num2, 0.0),
+            1 => (num2, chroma, 0.0),
+            2 => (0.0, chroma, num2),
+            3 => (0.0, num2, chroma),
+            4 => (num2, 0.0, chroma),
+            _ => (chroma, 0.0, num2),
+        };
+
+        Color::from_float_rgba(r + m, g + m, b + m, alpha)
+    }
+
+    fn hue_max_delta(self) -> (f32, f32, f32) {
+        let vector = self.to_vector4();
+        let max = vector.x.max(vector.y).max(vector.z);
+        let min = vector.x.min(vector.y).min(vector.z);
+        let delta = max - min;
+
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == vector.x {
+            60.0 * ((vector.y - vector.z) / delta).rem_euclid(6.0)
+        } else if max == vector.y {
+            60.0 * ((vector.z - vector.x) / delta + 2.0)
+        } else {
+            60.0 * ((vector.x - vector.y) / delta + 4.0)
+        };
+
+        (hue, max, delta)
+    }
+
     fn pack_helper(vector_x: f32, vector_y: f32, vector_z: f32, vector_w: f32) -> u32 {
         PackUtils::pack_unorm(u8::MAX as f32, vector_x)
             | PackUtils::pack_unorm(u8::MAX as f32, vector_y) << 8
@@ -499,4 +663,168 @@ impl Color {
     pub fn yellow() -> Color { Color::from_packed_value(4278255615) }
 
     pub fn yellow_green() -> Color { Color::from_packed_value(4281519514) }
-}
\ No newline at end of file
+}
+
+impl Add for Color {
+    type Output = Color;
+
+    fn add(self, rhs: Color) -> Color {
+        Color::from_rgba(
+            self.r() as i32 + rhs.r() as i32,
+            self.g() as i32 + rhs.g() as i32,
+            self.b() as i32 + rhs.b() as i32,
+            self.a() as i32 + rhs.a() as i32)
+    }
+}
+
+impl Sub for Color {
+    type Output = Color;
+
+    fn sub(self, rhs: Color) -> Color {
+        Color::from_rgba(
+            self.r() as i32 - rhs.r() as i32,
+            self.g() as i32 - rhs.g() as i32,
+            self.b() as i32 - rhs.b() as i32,
+            self.a() as i32 - rhs.a() as i32)
+    }
+}
+
+impl Mul for Color {
+    type Output = Color;
+
+    fn mul(self, rhs: Color) -> Color {
+        let num = |value1: u8, value2: u8| ((value1 as u32 * value2 as u32 + 127) / 255) as i32;
+
+        Color::from_rgba(
+            num(self.r(), rhs.r()),
+            num(self.g(), rhs.g()),
+            num(self.b(), rhs.b()),
+            num(self.a(), rhs.a()))
+    }
+}
+
+impl Mul<f32> for Color {
+    type Output = Color;
+
+    fn mul(self, rhs: f32) -> Color {
+        Color::multiply(&self, rhs)
+    }
+}
+
+impl AddAssign for Color {
+    fn add_assign(&mut self, rhs: Color) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Color {
+    fn sub_assign(&mut self, rhs: Color) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Color {
+    fn mul_assign(&mut self, rhs: Color) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<f32> for Color {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgba(color: Color) -> [u8; 4] {
+        [color.r(), color.g(), color.b(), color.a()]
+    }
+
+    #[test]
+    fn from_hex_parses_every_digit_count() {
+        assert_eq!(rgba(Color::from_hex("#F80").unwrap()), [255, 136, 0, 255]);
+        assert_eq!(rgba(Color::from_hex("F808").unwrap()), [255, 136, 0, 136]);
+        assert_eq!(rgba(Color::from_hex("#12aBcD").unwrap()), [0x12, 0xAB, 0xCD, 255]);
+        assert_eq!(rgba(Color::from_hex("#12ABCD80").unwrap()), [0x12, 0xAB, 0xCD, 0x80]);
+    }
+
+    #[test]
+    fn from_hex_rejects_invalid_input() {
+        for value in ["", "#", "#12", "#12345", "#1234567", "#123456789", "#GG0000", "12 345", "#ÄÖ0"] {
+            assert!(Color::from_hex(value).is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn to_hex_round_trips_through_from_hex() {
+        let color = Color::from_rgba(1, 128, 254, 77);
+
+        assert_eq!(color.to_hex(), "#0180FE4D");
+        assert_eq!(Color::from_hex(&color.to_hex()).unwrap(), color);
+        assert_eq!(Color::cornflower_blue().to_hex(), "#6495EDFF");
+    }
+
+    #[test]
+    fn hsv_round_trips_through_rgb() {
+        assert_eq!(rgba(Color::from_hsv(0.0, 1.0, 1.0)), [255, 0, 0, 255]);
+        assert_eq!(rgba(Color::from_hsv(120.0, 1.0, 1.0)), [0, 255, 0, 255]);
+        assert_eq!(rgba(Color::from_hsv(-120.0, 1.0, 1.0)), [0, 0, 255, 255]);
+        assert_eq!(rgba(Color::from_hsva(60.0, 0.5, 0.5, 0.5)), [128, 128, 64, 128]);
+
+        for color in [Color::cornflower_blue(), Color::crimson(), Color::dark_khaki(), Color::black(), Color::white()] {
+            let (hue, saturation, value) = color.to_hsv();
+            assert_eq!(Color::from_hsv(hue, saturation, value), color);
+        }
+    }
+
+    #[test]
+    fn hsl_round_trips_through_rgb() {
+        assert_eq!(rgba(Color::from_hsl(240.0, 1.0, 0.5)), [0, 0, 255, 255]);
+        assert_eq!(rgba(Color::from_hsl(0.0, 0.0, 1.0)), [255, 255, 255, 255]);
+
+        for color in [Color::cornflower_blue(), Color::crimson(), Color::dark_khaki()] {
+            let (hue, saturation, lightness) = color.to_hsl();
+            assert_eq!(Color::from_hsl(hue, saturation, lightness), color);
+        }
+    }
+
+    #[test]
+    fn premultiply_round_trips_opaque_and_translucent_colors() {
+        let color = Color::from_rgba(200, 100, 50, 128);
+
+        assert_eq!(rgba(Color::premultiply(&color)), [100, 50, 25, 128]);
+        assert_eq!(Color::unpremultiply(&Color::premultiply(&color)), Color::from_rgba(199, 100, 50, 128));
+        assert_eq!(Color::unpremultiply(&Color::premultiply(&Color::crimson())), Color::crimson());
+        assert_eq!(Color::unpremultiply(&Color::from_rgba(10, 20, 30, 0)), Color::transparent());
+    }
+
+    #[test]
+    fn srgb_round_trips_through_linear() {
+        assert_eq!(Color::srgb_to_linear(0.0), 0.0);
+        assert!((Color::srgb_to_linear(1.0) - 1.0).abs() < 1e-6);
+        assert!((Color::srgb_to_linear(0.5) - 0.21404114).abs() < 1e-6);
+        assert!((Color::linear_to_srgb(0.21404114) - 0.5).abs() < 1e-6);
+        assert!((Color::srgb_to_linear(0.04) - 0.04 / 12.92).abs() < 1e-7);
+
+        for index in 0..=255 {
+            let value = index as f32 / 255.0;
+            assert!((Color::linear_to_srgb(Color::srgb_to_linear(value)) - value).abs() < 1e-5);
+        }
+
+        let color = Color::from_rgba(128, 64, 255, 10);
+        assert_eq!(rgba(Color::to_linear(&color)), [55, 13, 255, 10]);
+        assert_eq!(Color::to_srgb(&Color::to_linear(&Color::white())), Color::white());
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecated_non_premultiplied_aliases_forward() {
+        let vector = Vector4 { x: 1.0, y: 0.5, z: 0.0, w: 0.5 };
+
+        assert_eq!(Color::from_nom_premultiplied(vector), Color::from_non_premultiplied(vector));
+        assert_eq!(Color::from_nom_premultiplied_rgba(255, 128, 0, 128), Color::from_non_premultiplied_rgba(255, 128, 0, 128));
+    }
+}
@@ -2,7 +2,7 @@ mod content_manager;
 
 use crate::xna::csharp::Exception;
 
-trait IContentManager {
+pub trait IContentManager {
     fn get_root_directory(&self) -> &str;
     fn set_root_directory(&mut self, value: &str) -> Result<(), Exception>;
     fn unload(&mut self) -> Result<(), Exception>;
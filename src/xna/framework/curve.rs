@@ -0,0 +1,268 @@
+use crate::xna::csharp::Exception;
+use crate::xna::framework::{Curve, CurveContinuity, CurveKey, CurveKeyCollection, CurveLoopType, CurveTangent, MathHelper};
+
+impl Curve {
+    pub fn new() -> Self {
+        Curve::default()
+    }
+
+    pub fn from_keys(keys: CurveKeyCollection) -> Self {
+        Curve { keys, ..Default::default() }
+    }
+
+    pub fn is_constant(&self) -> bool {
+        self.keys.len() <= 1
+    }
+
+    pub fn evaluate(&self, position: f32) -> f32 {
+        let (first, last) = match (self.keys.first(), self.keys.last()) {
+            (Some(first), Some(last)) => (*first, *last),
+            _ => return 0.0,
+        };
+
+        if self.keys.len() == 1 {
+            return first.value;
+        }
+
+        if position < first.position {
+            return self.evaluate_loop(self.pre_loop, position, &first, &last, &first);
+        }
+
+        if position > last.position {
+            return self.evaluate_loop(self.post_loop, position, &first, &last, &last);
+        }
+
+        self.get_curve_position(position)
+    }
+
+    pub fn compute_tangents(&mut self, tangent_type: CurveTangent) {
+        self.compute_tangents_in_out(tangent_type, tangent_type);
+    }
+
+    pub fn compute_tangents_in_out(&mut self, tangent_in_type: CurveTangent, tangent_out_type: CurveTangent) {
+        for index in 0..self.keys.len() {
+            self.compute_tangent_at(index, tangent_in_type, tangent_out_type);
+        }
+    }
+
+    pub fn compute_tangent(&mut self, key_index: usize, tangent_type: CurveTangent) -> Result<(), Exception> {
+        self.compute_tangent_in_out(key_index, tangent_type, tangent_type)
+    }
+
+    pub fn compute_tangent_in_out(&mut self, key_index: usize, tangent_in_type: CurveTangent, tangent_out_type: CurveTangent) -> Result<(), Exception> {
+        if key_index >= self.keys.len() {
+            return Err(Exception::out_of_range("key_index is out of range of the key collection.", None));
+        }
+
+        self.compute_tangent_at(key_index, tangent_in_type, tangent_out_type);
+        Ok(())
+    }
+
+    fn compute_tangent_at(&mut self, key_index: usize, tangent_in_type: CurveTangent, tangent_out_type: CurveTangent) {
+        let key = self.keys.keys[key_index];
+
+        let (position0, value0) = match key_index.checked_sub(1).and_then(|index| self.keys.get(index)) {
+            Some(previous) => (previous.position, previous.value),
+            None => (key.position, key.value),
+        };
+
+        let (position1, value1) = match self.keys.get(key_index + 1) {
+            Some(next) => (next.position, next.value),
+            None => (key.position, key.value),
+        };
+
+        let num = position1 - position0;
+
+        let tangent_in = match tangent_in_type {
+            CurveTangent::Flat => 0.0,
+            CurveTangent::Linear => key.value - value0,
+            CurveTangent::Smooth if num.abs() < f32::EPSILON => 0.0,
+            CurveTangent::Smooth => (value1 - value0) * ((key.position - position0) / num),
+        };
+
+        let tangent_out = match tangent_out_type {
+            CurveTangent::Flat => 0.0,
+            CurveTangent::Linear => value1 - key.value,
+            CurveTangent::Smooth if num.abs() < f32::EPSILON => 0.0,
+            CurveTangent::Smooth => (value1 - value0) * ((position1 - key.position) / num),
+        };
+
+        let key = &mut self.keys.keys[key_index];
+        key.tangent_in = tangent_in;
+        key.tangent_out = tangent_out;
+    }
+
+    fn evaluate_loop(&self, loop_type: CurveLoopType, position: f32, first: &CurveKey, last: &CurveKey, edge: &CurveKey) -> f32 {
+        let num1 = last.position - first.position;
+
+        if num1 <= 0.0 {
+            return edge.value;
+        }
+
+        let cycle = ((position - first.position) / num1).floor();
+
+        match loop_type {
+            CurveLoopType::Constant => edge.value,
+            CurveLoopType::Linear => {
+                if position < first.position {
+                    first.value - first.tangent_in * (first.position - position)
+                } else {
+                    last.value + last.tangent_out * (position - last.position)
+                }
+            }
+            CurveLoopType::Cycle => self.get_curve_position(position - cycle * num1),
+            CurveLoopType::CycleOffset => {
+                self.get_curve_position(position - cycle * num1) + cycle * (last.value - first.value)
+            }
+            CurveLoopType::Oscillate => {
+                let num2 = if cycle % 2.0 == 0.0 {
+                    position - cycle * num1
+                } else {
+                    last.position - position + first.position + cycle * num1
+                };
+
+                self.get_curve_position(num2)
+            }
+        }
+    }
+
+    fn get_curve_position(&self, position: f32) -> f32 {
+        let mut previous = match self.keys.first() {
+            Some(key) => key,
+            None => return 0.0,
+        };
+
+        if position <= previous.position {
+            return previous.value;
+        }
+
+        for next in self.keys.iter().skip(1) {
+            if next.position >= position {
+                let num = next.position - previous.position;
+
+                if num <= 0.0 {
+                    return next.value;
+                }
+
+                let amount = (position - previous.position) / num;
+
+                if previous.continuity == CurveContinuity::Step {
+                    return if amount >= 1.0 { next.value } else { previous.value };
+                }
+
+                return MathHelper::hermite(previous.value, previous.tangent_out, next.value, next.tangent_in, amount);
+            }
+
+            previous = next;
+        }
+
+        previous.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ramp(pre_loop: CurveLoopType, post_loop: CurveLoopType) -> Curve {
+        let mut keys = CurveKeyCollection::new();
+        keys.add(CurveKey::new(0.0, 0.0));
+        keys.add(CurveKey::new(10.0, 10.0));
+
+        let mut curve = Curve { pre_loop, post_loop, keys };
+        curve.compute_tangents(CurveTangent::Linear);
+
+        curve
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!((actual - expected).abs() < 1e-4, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn evaluate_interpolates_between_keys() {
+        let curve = ramp(CurveLoopType::Constant, CurveLoopType::Constant);
+
+        assert_close(curve.evaluate(0.0), 0.0);
+        assert_close(curve.evaluate(2.5), 2.5);
+        assert_close(curve.evaluate(10.0), 10.0);
+    }
+
+    #[test]
+    fn constant_loop_holds_the_edge_keys() {
+        let curve = ramp(CurveLoopType::Constant, CurveLoopType::Constant);
+
+        assert_close(curve.evaluate(-5.0), 0.0);
+        assert_close(curve.evaluate(15.0), 10.0);
+    }
+
+    #[test]
+    fn cycle_loop_repeats_the_curve() {
+        let curve = ramp(CurveLoopType::Cycle, CurveLoopType::Cycle);
+
+        assert_close(curve.evaluate(-3.0), 7.0);
+        assert_close(curve.evaluate(13.0), 3.0);
+        assert_close(curve.evaluate(27.0), 7.0);
+    }
+
+    #[test]
+    fn cycle_offset_loop_accumulates_the_value_range() {
+        let curve = ramp(CurveLoopType::CycleOffset, CurveLoopType::CycleOffset);
+
+        assert_close(curve.evaluate(-3.0), -3.0);
+        assert_close(curve.evaluate(13.0), 13.0);
+        assert_close(curve.evaluate(27.0), 27.0);
+    }
+
+    #[test]
+    fn oscillate_loop_mirrors_alternate_cycles() {
+        let curve = ramp(CurveLoopType::Oscillate, CurveLoopType::Oscillate);
+
+        assert_close(curve.evaluate(-3.0), 3.0);
+        assert_close(curve.evaluate(13.0), 7.0);
+        assert_close(curve.evaluate(23.0), 3.0);
+    }
+
+    #[test]
+    fn linear_loop_extrapolates_along_the_edge_tangents() {
+        let mut keys = CurveKeyCollection::new();
+        keys.add(CurveKey::with_tangents(0.0, 0.0, 2.0, 2.0));
+        keys.add(CurveKey::with_tangents(10.0, 10.0, 3.0, 3.0));
+        let curve = Curve { pre_loop: CurveLoopType::Linear, post_loop: CurveLoopType::Linear, keys };
+
+        assert_close(curve.evaluate(-5.0), -10.0);
+        assert_close(curve.evaluate(15.0), 25.0);
+    }
+
+    #[test]
+    fn step_continuity_holds_the_previous_value() {
+        let mut keys = CurveKeyCollection::new();
+        keys.add(CurveKey::with_continuity(0.0, 0.0, 0.0, 0.0, CurveContinuity::Step));
+        keys.add(CurveKey::new(10.0, 10.0));
+        let curve = Curve::from_keys(keys);
+
+        assert_close(curve.evaluate(9.0), 0.0);
+        assert_close(curve.evaluate(10.0), 10.0);
+    }
+
+    #[test]
+    fn compute_tangent_uses_the_neighbouring_keys() {
+        let mut keys = CurveKeyCollection::new();
+        keys.add(CurveKey::new(0.0, 0.0));
+        keys.add(CurveKey::new(10.0, 10.0));
+        keys.add(CurveKey::new(30.0, 40.0));
+        let mut curve = Curve::from_keys(keys);
+
+        curve.compute_tangent(1, CurveTangent::Smooth).unwrap();
+        let key = curve.keys.get(1).unwrap();
+        assert_close(key.tangent_in, 40.0 / 3.0);
+        assert_close(key.tangent_out, 80.0 / 3.0);
+
+        curve.compute_tangent_in_out(1, CurveTangent::Linear, CurveTangent::Flat).unwrap();
+        let key = curve.keys.get(1).unwrap();
+        assert_close(key.tangent_in, 10.0);
+        assert_close(key.tangent_out, 0.0);
+
+        assert!(curve.compute_tangent(3, CurveTangent::Flat).is_err());
+    }
+}
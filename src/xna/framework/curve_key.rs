@@ -0,0 +1,95 @@
+use crate::xna::csharp::Exception;
+use crate::xna::framework::{CurveContinuity, CurveKey, CurveKeyCollection};
+
+impl CurveKey {
+    pub fn new(position: f32, value: f32) -> CurveKey {
+        CurveKey { position, value, ..Default::default() }
+    }
+
+    pub fn with_tangents(position: f32, value: f32, tangent_in: f32, tangent_out: f32) -> CurveKey {
+        CurveKey { position, value, tangent_in, tangent_out, ..Default::default() }
+    }
+
+    pub fn with_continuity(position: f32, value: f32, tangent_in: f32, tangent_out: f32, continuity: CurveContinuity) -> CurveKey {
+        CurveKey { position, value, tangent_in, tangent_out, continuity }
+    }
+
+    pub fn position(&self) -> f32 {
+        self.position
+    }
+}
+
+impl CurveKeyCollection {
+    pub fn new() -> Self {
+        CurveKeyCollection { keys: Vec::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&CurveKey> {
+        self.keys.get(index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut CurveKey> {
+        self.keys.get_mut(index)
+    }
+
+    pub fn first(&self) -> Option<&CurveKey> {
+        self.keys.first()
+    }
+
+    pub fn last(&self) -> Option<&CurveKey> {
+        self.keys.last()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, CurveKey> {
+        self.keys.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, CurveKey> {
+        self.keys.iter_mut()
+    }
+
+    pub fn add(&mut self, item: CurveKey) -> usize {
+        let index = self.keys.partition_point(|key| key.position <= item.position);
+        self.keys.insert(index, item);
+
+        index
+    }
+
+    pub fn index_of(&self, item: &CurveKey) -> Option<usize> {
+        self.keys.iter().position(|key| key == item)
+    }
+
+    pub fn contains(&self, item: &CurveKey) -> bool {
+        self.index_of(item).is_some()
+    }
+
+    pub fn remove(&mut self, item: &CurveKey) -> bool {
+        match self.index_of(item) {
+            Some(index) => {
+                self.keys.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn remove_at(&mut self, index: usize) -> Result<CurveKey, Exception> {
+        if index >= self.keys.len() {
+            return Err(Exception::out_of_range("index is out of range of the key collection.", None));
+        }
+
+        Ok(self.keys.remove(index))
+    }
+
+    pub fn clear(&mut self) {
+        self.keys.clear();
+    }
+}
@@ -15,8 +15,8 @@ impl Game {
             ..Default::default()
         };
 
-        game.set_is_fixed_time_step(game.is_fixed_time_step.clone());
-        game.set_target_elapsed_time(game.target_elapsed_time.clone());
+        game.set_is_fixed_time_step(game.is_fixed_time_step);
+        game.set_target_elapsed_time(game.target_elapsed_time);
 
         game
     }
@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 pub mod game;
 pub mod game_window;
 pub mod graphics_device_manager;
@@ -6,13 +7,12 @@ use std::cell::RefCell;
 use std::cmp::Ordering;
 use crate::xna::csharp::{Exception, TimeSpan};
 use crate::xna::framework::graphics::{DepthFormat, GraphicsAdapter, GraphicsDevice, PresentationParameters, SurfaceFormat};
-use std::ops::Deref;
 use std::rc::Rc;
-use std::sync::{Arc, Mutex};
-use thiserror::Error;
 
 #[cfg(target_os = "windows")]
 use crate::xna::platform::windows::{WindowsGame, WindowsGameWindow};
+#[cfg(not(target_os = "windows"))]
+use crate::xna::platform::headless::{HeadlessGame, HeadlessGameWindow};
 
 pub enum DisplayOrientation {
     Default,
@@ -40,7 +40,9 @@ pub struct GameWindow {
     pub style: GameWindowStyle,
 
     #[cfg(target_os = "windows")]
-    pub platform: WindowsGameWindow
+    pub platform: WindowsGameWindow,
+    #[cfg(not(target_os = "windows"))]
+    pub platform: HeadlessGameWindow
 }
 
 #[derive(Default, PartialEq, Eq, Copy, Clone)]
@@ -74,7 +76,9 @@ pub struct Game {
     pub is_window_created: bool,
 
     #[cfg(target_os = "windows")]
-    pub platform: WindowsGame
+    pub platform: WindowsGame,
+    #[cfg(not(target_os = "windows"))]
+    pub platform: HeadlessGame
 }
 
 #[derive(Default, Clone)]
@@ -133,28 +137,16 @@ impl Ord for GraphicsDeviceInformation {
 
 impl PartialOrd for GraphicsDeviceInformation {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        //todo: implementar comparator
-
-        if self != other{
-            return if self.profile as i32 <= other.profile as i32 {Some(Ordering::Less)} else {Some(Ordering::Greater)}
-        }
-
-        let presentation_parameters1 = &self.presentation_parameters;
-        let presentation_parameters2 = &other.presentation_parameters;
-
-        if presentation_parameters1 != presentation_parameters2 && presentation_parameters1.multi_sample_count <= presentation_parameters2.multi_sample_count {
-            return Some(Ordering::Less);
-        }
-
-        Some (Ordering::Equal)
+        Some(self.cmp(other))
     }
 }
 
 impl SurfaceFormat {
     pub fn rank(format: &SurfaceFormat) -> i32 {
-        match format {
-            SurfaceFormat::Color => 32,
-            _ => 0
+        if format.is_compressed() {
+            return 0;
         }
+
+        (format.size() * 8) as i32
     }
 }
@@ -1,6 +1,6 @@
-use std::iter::Once;
-use crate::xna::framework::Color;
-use crate::xna::framework::graphics::{Blend, BlendFunction, BlendRenderTarget, BlendState, ColorWriteChannels};
+use crate::xna::csharp::Exception;
+use crate::xna::framework::{Color, Vector4};
+use crate::xna::framework::graphics::{Blend, BlendFunction, BlendRenderTarget, BlendState, ColorWriteChannels, IPackedVector};
 
 impl BlendState {
     pub fn new() -> BlendState {
@@ -17,9 +17,8 @@ impl BlendState {
                 source_alpha: Blend::One,
                 destination_alpha: Blend::One,
                 operation_alpha: BlendFunction::Add,
-                write_mask: ColorWriteChannels::All,
+                write_mask: ColorWriteChannels::ALL,
             };8],
-            ..Default::default()
         }
     }
 
@@ -62,4 +61,160 @@ impl BlendState {
 
         blend
     }
-}
\ No newline at end of file
+
+    pub fn render_target(&self, index: usize) -> &BlendRenderTarget {
+        if self.independent_blend_enable {
+            &self.render_targets[index]
+        } else {
+            &self.render_targets[0]
+        }
+    }
+
+    pub fn blend(&self, source: &Color, destination: &Color) -> Result<Color, Exception> {
+        Ok(Color::from_vector4(self.blend_vector4(&source.to_vector4(), &destination.to_vector4())?))
+    }
+
+    pub fn blend_vector4(&self, source: &Vector4, destination: &Vector4) -> Result<Vector4, Exception> {
+        self.blend_render_target(0, source, destination)
+    }
+
+    pub fn blend_render_target(&self, index: usize, source: &Vector4, destination: &Vector4) -> Result<Vector4, Exception> {
+        let blend_factor = self.blend_factor.to_vector4();
+        self.render_target(index).blend(source, destination, &blend_factor)
+    }
+}
+
+impl BlendRenderTarget {
+    pub fn blend(&self, source: &Vector4, destination: &Vector4, blend_factor: &Vector4) -> Result<Vector4, Exception> {
+        let source = Vector4::clamp(source, &Vector4::zero(), &Vector4::one());
+
+        if !self.enabled {
+            return Ok(self.apply_write_mask(&source, destination));
+        }
+
+        let source_factor = self.source.factor(&source, destination, blend_factor)?;
+        let destination_factor = self.destination.factor(&source, destination, blend_factor)?;
+        let source_alpha_factor = self.source_alpha.factor(&source, destination, blend_factor)?.w;
+        let destination_alpha_factor = self.destination_alpha.factor(&source, destination, blend_factor)?.w;
+
+        let result = Vector4 {
+            x: self.operation.evaluate(source.x, source_factor.x, destination.x, destination_factor.x),
+            y: self.operation.evaluate(source.y, source_factor.y, destination.y, destination_factor.y),
+            z: self.operation.evaluate(source.z, source_factor.z, destination.z, destination_factor.z),
+            w: self.operation_alpha.evaluate(source.w, source_alpha_factor, destination.w, destination_alpha_factor),
+        };
+
+        let result = Vector4::clamp(&result, &Vector4::zero(), &Vector4::one());
+        Ok(self.apply_write_mask(&result, destination))
+    }
+
+    pub fn apply_write_mask(&self, value: &Vector4, destination: &Vector4) -> Vector4 {
+        let mask = self.write_mask;
+
+        Vector4 {
+            x: if mask.contains(ColorWriteChannels::RED) { value.x } else { destination.x },
+            y: if mask.contains(ColorWriteChannels::GREEN) { value.y } else { destination.y },
+            z: if mask.contains(ColorWriteChannels::BLUE) { value.z } else { destination.z },
+            w: if mask.contains(ColorWriteChannels::ALPHA) { value.w } else { destination.w },
+        }
+    }
+}
+
+impl Blend {
+    pub fn factor(&self, source: &Vector4, destination: &Vector4, blend_factor: &Vector4) -> Result<Vector4, Exception> {
+        let factor = match self {
+            Blend::Zero => Vector4::zero(),
+            Blend::One => Vector4::one(),
+            Blend::SourceColor => *source,
+            Blend::InverseSourceColor => Vector4::one() - *source,
+            Blend::SourceAlpha => Vector4::from_value(source.w),
+            Blend::InverseSourceAlpha => Vector4::from_value(1.0 - source.w),
+            Blend::DestinationAlpha => Vector4::from_value(destination.w),
+            Blend::InverseDestinationAlpha => Vector4::from_value(1.0 - destination.w),
+            Blend::DestinationColor => *destination,
+            Blend::InverseDestinationColor => Vector4::one() - *destination,
+            Blend::SourceAlphaSaturation => {
+                let factor = source.w.min(1.0 - destination.w);
+                Vector4 { x: factor, y: factor, z: factor, w: 1.0 }
+            }
+            Blend::BlendFactor => *blend_factor,
+            Blend::InverseBlendFactor => Vector4::one() - *blend_factor,
+            Blend::Source1Color | Blend::InverseSource1Color | Blend::Source1Alpha | Blend::InverseSource1Alpha => {
+                return Err(Exception::invalid_operation("Dual-source blend factors require a second source color.", None));
+            }
+        };
+
+        Ok(factor)
+    }
+}
+
+impl BlendFunction {
+    pub fn evaluate(&self, source: f32, source_factor: f32, destination: f32, destination_factor: f32) -> f32 {
+        match self {
+            BlendFunction::Add => source * source_factor + destination * destination_factor,
+            BlendFunction::Subtract => source * source_factor - destination * destination_factor,
+            BlendFunction::ReverseSubtract => destination * destination_factor - source * source_factor,
+            BlendFunction::Min => source.min(destination),
+            BlendFunction::Max => source.max(destination),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rgba(color: Color) -> [u8; 4] {
+        [color.r(), color.g(), color.b(), color.a()]
+    }
+
+    #[test]
+    fn alpha_blend_composites_premultiplied_source_over_destination() {
+        let source = Color::from_rgba(128, 0, 0, 128);
+        let destination = Color::from_rgba(0, 0, 255, 255);
+
+        assert_eq!(rgba(BlendState::alpha_blend().blend(&source, &destination).unwrap()), [128, 0, 127, 255]);
+        assert_eq!(rgba(BlendState::alpha_blend().blend(&Color::transparent(), &destination).unwrap()), [0, 0, 255, 255]);
+        assert_eq!(rgba(BlendState::alpha_blend().blend(&Color::white(), &destination).unwrap()), [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn additive_scales_source_by_alpha_and_adds_destination() {
+        let source = Color::from_rgba(255, 0, 0, 128);
+        let destination = Color::from_rgba(0, 64, 0, 255);
+
+        assert_eq!(rgba(BlendState::additive().blend(&source, &destination).unwrap()), [128, 64, 0, 255]);
+        assert_eq!(rgba(BlendState::additive().blend(&Color::white(), &Color::white()).unwrap()), [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn opaque_replaces_destination() {
+        let source = Color::from_rgba(10, 20, 30, 40);
+
+        assert_eq!(rgba(BlendState::opaque().blend(&source, &Color::white()).unwrap()), [10, 20, 30, 40]);
+    }
+
+    #[test]
+    fn write_mask_keeps_disabled_channels() {
+        let mut state = BlendState::opaque();
+        state.render_targets[0].write_mask = ColorWriteChannels::RED | ColorWriteChannels::ALPHA;
+
+        let result = state.blend(&Color::from_rgba(10, 20, 30, 40), &Color::from_rgba(50, 60, 70, 80)).unwrap();
+
+        assert_eq!(rgba(result), [10, 60, 70, 40]);
+    }
+
+    #[test]
+    fn dual_source_factors_are_rejected() {
+        let source = Color::from_rgba(10, 20, 30, 40);
+
+        for factor in [Blend::Source1Color, Blend::InverseSource1Color, Blend::Source1Alpha, Blend::InverseSource1Alpha] {
+            let mut state = BlendState::opaque();
+            state.render_targets[0].destination = factor;
+            assert!(state.blend(&source, &Color::white()).is_err());
+
+            state.render_targets[0].enabled = false;
+            assert_eq!(rgba(state.blend(&source, &Color::white()).unwrap()), [10, 20, 30, 40]);
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use crate::xna::csharp::Exception;
+use crate::xna::framework::Color;
+use crate::xna::framework::graphics::{BmpCodec, ImageCodec, Texture2D};
+
+impl BmpCodec {
+    const FILE_HEADER_SIZE: usize = 14;
+    const V4_HEADER_SIZE: usize = 108;
+    const BI_RGB: u32 = 0;
+    const BI_BITFIELDS: u32 = 3;
+    const BI_ALPHABITFIELDS: u32 = 6;
+    const LCS_SRGB: u32 = 0x73524742;
+
+    pub fn decode(data: &[u8]) -> Result<Texture2D, Exception> {
+        if !data.starts_with(b"BM") {
+            return Err(Exception::new("The data is not a BMP image.", None));
+        }
+
+        let pixel_offset = ImageCodec::read_u32_le(data, 10)? as usize;
+        let header_size = ImageCodec::read_u32_le(data, Self::FILE_HEADER_SIZE)? as usize;
+        let header = Self::FILE_HEADER_SIZE;
+
+        let (width, height, bit_count, compression, palette_size, palette_entry_size) = if header_size == 12 {
+            let bit_count = ImageCodec::read_u16_le(data, header + 10)?;
+            (ImageCodec::read_u16_le(data, header + 4)? as i32, ImageCodec::read_u16_le(data, header + 6)? as i16 as i32, bit_count, Self::BI_RGB, 0, 3)
+        } else if header_size >= 40 {
+            (
+                ImageCodec::read_u32_le(data, header + 4)? as i32,
+                ImageCodec::read_u32_le(data, header + 8)? as i32,
+                ImageCodec::read_u16_le(data, header + 14)?,
+                ImageCodec::read_u32_le(data, header + 16)?,
+                ImageCodec::read_u32_le(data, header + 32)? as usize,
+                4,
+            )
+        } else {
+            return Err(Exception::new("The BMP header is not supported.", None));
+        };
+
+        let is_top_down = height < 0;
+        let width = width.unsigned_abs();
+        let height = height.unsigned_abs();
+
+        ImageCodec::validate_dimensions(width, height)?;
+
+        let mut masks = match bit_count {
+            16 => [0x7C00, 0x03E0, 0x001F, 0],
+            32 => [0x00FF0000, 0x0000FF00, 0x000000FF, 0],
+            _ => [0; 4],
+        };
+
+        let mut palette_offset = header + header_size;
+
+        match compression {
+            Self::BI_RGB => {}
+            Self::BI_BITFIELDS | Self::BI_ALPHABITFIELDS if bit_count == 16 || bit_count == 32 => {
+                let mask_count = if compression == Self::BI_ALPHABITFIELDS || header_size >= 56 { 4 } else { 3 };
+
+                for (index, mask) in masks.iter_mut().enumerate().take(mask_count) {
+                    *mask = ImageCodec::read_u32_le(data, header + 40 + index * 4)?;
+                }
+
+                if header_size == 40 {
+                    palette_offset += mask_count * 4;
+                }
+            }
+            _ => return Err(Exception::new("The BMP compression method is not supported.", None)),
+        }
+
+        let palette: Vec<Color> = if bit_count <= 8 {
+            let count = if palette_size == 0 { 1usize << bit_count } else { palette_size };
+            let bytes = ImageCodec::bytes(data, palette_offset, count * palette_entry_size)?;
+
+            bytes.chunks_exact(palette_entry_size).map(|entry| ImageCodec::rgba(entry[2], entry[1], entry[0], 255)).collect()
+        } else {
+            Vec::new()
+        };
+
+        let row_size = (width as usize * bit_count as usize).div_ceil(32) * 4;
+        let pixel_data = ImageCodec::bytes(data, pixel_offset, row_size * height as usize)?;
+        let mut pixels = vec![Color::transparent(); (width * height) as usize];
+        let mut has_alpha = masks[3] != 0;
+
+        for y in 0..height as usize {
+            let source_row = if is_top_down { y } else { height as usize - 1 - y };
+            let row = &pixel_data[source_row * row_size..(source_row + 1) * row_size];
+
+            for x in 0..width as usize {
+                pixels[y * width as usize + x] = match bit_count {
+                    1 | 2 | 4 | 8 => {
+                        let bit = x * bit_count as usize;
+                        let index = (row[bit / 8] >> (8 - bit_count as usize - bit % 8)) & ((1u16 << bit_count) - 1) as u8;
+
+                        palette.get(index as usize).copied().unwrap_or(Color::black())
+                    }
+                    24 => ImageCodec::rgba(row[x * 3 + 2], row[x * 3 + 1], row[x * 3], 255),
+                    16 | 32 => {
+                        let value = if bit_count == 16 {
+                            u16::from_le_bytes([row[x * 2], row[x * 2 + 1]]) as u32
+                        } else {
+                            u32::from_le_bytes([row[x * 4], row[x * 4 + 1], row[x * 4 + 2], row[x * 4 + 3]])
+                        };
+
+                        let channel = |index: usize| ImageCodec::extract_mask(value, masks[index]).unwrap_or(0);
+                        let alpha = if masks[3] != 0 {
+                            channel(3)
+                        } else if bit_count == 32 {
+                            (value >> 24) as u8
+                        } else {
+                            255
+                        };
+
+                        ImageCodec::rgba(channel(0), channel(1), channel(2), alpha)
+                    }
+                    _ => return Err(Exception::new("The BMP bit depth is not supported.", None)),
+                };
+            }
+        }
+
+        if bit_count == 32 && !has_alpha {
+            has_alpha = pixels.iter().any(|pixel| pixel.a() != 0);
+
+            if !has_alpha {
+                pixels.iter_mut().for_each(|pixel| pixel.set_a(255));
+            }
+        }
+
+        let mut texture = Texture2D::new(width, height)?;
+        texture.set_data(None, None, &pixels)?;
+
+        Ok(texture)
+    }
+
+    pub fn encode(width: u32, height: u32, pixels: &[Color]) -> Result<Vec<u8>, Exception> {
+        ImageCodec::validate_dimensions(width, height)?;
+
+        let pixel_count = (width * height) as usize;
+
+        if pixels.len() < pixel_count {
+            return Err(Exception::argument_exception("pixels is smaller than the image dimensions.", None));
+        }
+
+        let pixel_offset = Self::FILE_HEADER_SIZE + Self::V4_HEADER_SIZE;
+        let image_size = pixel_count * 4;
+        let mut result = Vec::with_capacity(pixel_offset + image_size);
+
+        result.extend_from_slice(b"BM");
+        result.extend_from_slice(&((pixel_offset + image_size) as u32).to_le_bytes());
+        result.extend_from_slice(&0u32.to_le_bytes());
+        result.extend_from_slice(&(pixel_offset as u32).to_le_bytes());
+
+        result.extend_from_slice(&(Self::V4_HEADER_SIZE as u32).to_le_bytes());
+        result.extend_from_slice(&(width as i32).to_le_bytes());
+        result.extend_from_slice(&(height as i32).to_le_bytes());
+        result.extend_from_slice(&1u16.to_le_bytes());
+        result.extend_from_slice(&32u16.to_le_bytes());
+        result.extend_from_slice(&Self::BI_BITFIELDS.to_le_bytes());
+        result.extend_from_slice(&(image_size as u32).to_le_bytes());
+        result.extend_from_slice(&2835i32.to_le_bytes());
+        result.extend_from_slice(&2835i32.to_le_bytes());
+        result.extend_from_slice(&0u32.to_le_bytes());
+        result.extend_from_slice(&0u32.to_le_bytes());
+
+        for mask in [0x00FF0000u32, 0x0000FF00, 0x000000FF, 0xFF000000] {
+            result.extend_from_slice(&mask.to_le_bytes());
+        }
+
+        result.extend_from_slice(&Self::LCS_SRGB.to_le_bytes());
+        result.resize(pixel_offset, 0);
+
+        for y in (0..height as usize).rev() {
+            for pixel in &pixels[y * width as usize..(y + 1) * width as usize] {
+                result.extend_from_slice(&[pixel.b(), pixel.g(), pixel.r(), pixel.a()]);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_and_decode_round_trip() {
+        for (width, height) in [(1, 1), (7, 5), (64, 3)] {
+            let pixels: Vec<Color> = (0..width * height)
+                .map(|index| ImageCodec::rgba((index * 7) as u8, (index * 13 + 5) as u8, (index * 31) as u8, 255u8.wrapping_sub((index * 3) as u8)))
+                .collect();
+            let texture = BmpCodec::decode(&BmpCodec::encode(width, height, &pixels).unwrap()).unwrap();
+            let mut decoded = vec![Color::transparent(); pixels.len()];
+            texture.get_data(None, None, &mut decoded).unwrap();
+
+            assert_eq!((texture.width, texture.height), (width, height));
+            assert_eq!(decoded, pixels);
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not};
+use crate::xna::framework::graphics::ColorWriteChannels;
+
+impl ColorWriteChannels {
+    pub const NONE: ColorWriteChannels = ColorWriteChannels { bits: 0 };
+    pub const RED: ColorWriteChannels = ColorWriteChannels { bits: 1 };
+    pub const GREEN: ColorWriteChannels = ColorWriteChannels { bits: 2 };
+    pub const BLUE: ColorWriteChannels = ColorWriteChannels { bits: 4 };
+    pub const ALPHA: ColorWriteChannels = ColorWriteChannels { bits: 8 };
+    pub const ALL: ColorWriteChannels = ColorWriteChannels { bits: 15 };
+
+    pub const fn from_bits(bits: u8) -> ColorWriteChannels {
+        ColorWriteChannels { bits: bits & Self::ALL.bits }
+    }
+
+    pub const fn bits(&self) -> u8 {
+        self.bits
+    }
+
+    pub const fn contains(&self, other: ColorWriteChannels) -> bool {
+        self.bits & other.bits == other.bits
+    }
+
+    pub const fn intersects(&self, other: ColorWriteChannels) -> bool {
+        self.bits & other.bits != 0
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.bits == 0
+    }
+
+    pub fn insert(&mut self, other: ColorWriteChannels) {
+        self.bits |= other.bits;
+    }
+
+    pub fn remove(&mut self, other: ColorWriteChannels) {
+        self.bits &= !other.bits;
+    }
+}
+
+impl Default for ColorWriteChannels {
+    fn default() -> Self {
+        ColorWriteChannels::ALL
+    }
+}
+
+impl BitOr for ColorWriteChannels {
+    type Output = ColorWriteChannels;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        ColorWriteChannels { bits: self.bits | rhs.bits }
+    }
+}
+
+impl BitOrAssign for ColorWriteChannels {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.bits |= rhs.bits;
+    }
+}
+
+impl BitAnd for ColorWriteChannels {
+    type Output = ColorWriteChannels;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        ColorWriteChannels { bits: self.bits & rhs.bits }
+    }
+}
+
+impl BitAndAssign for ColorWriteChannels {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.bits &= rhs.bits;
+    }
+}
+
+impl Not for ColorWriteChannels {
+    type Output = ColorWriteChannels;
+
+    fn not(self) -> Self::Output {
+        ColorWriteChannels { bits: !self.bits & Self::ALL.bits }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operators_combine_and_mask_channels() {
+        let red_green = ColorWriteChannels::RED | ColorWriteChannels::GREEN;
+
+        assert_eq!(red_green.bits(), 3);
+        assert_eq!(red_green & ColorWriteChannels::GREEN, ColorWriteChannels::GREEN);
+        assert_eq!(red_green & ColorWriteChannels::BLUE, ColorWriteChannels::NONE);
+        assert_eq!(!red_green, ColorWriteChannels::BLUE | ColorWriteChannels::ALPHA);
+        assert_eq!(!ColorWriteChannels::ALL, ColorWriteChannels::NONE);
+        assert_eq!(!ColorWriteChannels::NONE, ColorWriteChannels::ALL);
+
+        let mut channels = ColorWriteChannels::NONE;
+        channels |= ColorWriteChannels::ALPHA;
+        channels |= ColorWriteChannels::RED;
+        channels &= !ColorWriteChannels::RED;
+        assert_eq!(channels, ColorWriteChannels::ALPHA);
+    }
+
+    #[test]
+    fn contains_requires_every_channel() {
+        let red_alpha = ColorWriteChannels::RED | ColorWriteChannels::ALPHA;
+
+        assert!(red_alpha.contains(ColorWriteChannels::RED));
+        assert!(red_alpha.contains(red_alpha));
+        assert!(red_alpha.contains(ColorWriteChannels::NONE));
+        assert!(!red_alpha.contains(ColorWriteChannels::RED | ColorWriteChannels::BLUE));
+        assert!(red_alpha.intersects(ColorWriteChannels::RED | ColorWriteChannels::BLUE));
+        assert!(!red_alpha.intersects(ColorWriteChannels::GREEN));
+        assert!(ColorWriteChannels::ALL.contains(red_alpha));
+    }
+
+    #[test]
+    fn from_bits_drops_undefined_bits() {
+        assert_eq!(ColorWriteChannels::from_bits(0xFF), ColorWriteChannels::ALL);
+        assert_eq!(ColorWriteChannels::from_bits(0x35).bits(), 5);
+        assert!(ColorWriteChannels::from_bits(0xF0).is_empty());
+    }
+
+    #[test]
+    fn insert_and_remove_update_the_set() {
+        let mut channels = ColorWriteChannels::default();
+        channels.remove(ColorWriteChannels::GREEN | ColorWriteChannels::BLUE);
+        assert_eq!(channels, ColorWriteChannels::RED | ColorWriteChannels::ALPHA);
+
+        channels.insert(ColorWriteChannels::BLUE);
+        assert_eq!(channels.bits(), 13);
+    }
+}
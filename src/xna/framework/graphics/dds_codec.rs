@@ -0,0 +1,206 @@
+use crate::xna::csharp::Exception;
+use crate::xna::framework::Color;
+use crate::xna::framework::graphics::{DdsCodec, ImageCodec, SurfaceFormat, Texture2D};
+
+struct DdsPixelFormat {
+    flags: u32,
+    bit_count: u32,
+    masks: [u32; 4],
+}
+
+impl DdsCodec {
+    const HEADER_SIZE: usize = 128;
+    const DX10_HEADER_SIZE: usize = 20;
+    const DDSD_MIPMAPCOUNT: u32 = 0x20000;
+    const DDPF_ALPHAPIXELS: u32 = 0x1;
+    const DDPF_ALPHA: u32 = 0x2;
+    const DDPF_FOURCC: u32 = 0x4;
+    const DDSCAPS2_CUBEMAP: u32 = 0x200;
+    const DDSCAPS2_VOLUME: u32 = 0x200000;
+    const D3D10_RESOURCE_DIMENSION_TEXTURE2D: u32 = 3;
+
+    pub fn decode(data: &[u8]) -> Result<Texture2D, Exception> {
+        if !data.starts_with(b"DDS ") || ImageCodec::read_u32_le(data, 4)? != 124 {
+            return Err(Exception::new("The data is not a DDS image.", None));
+        }
+
+        let flags = ImageCodec::read_u32_le(data, 8)?;
+        let height = ImageCodec::read_u32_le(data, 12)?;
+        let width = ImageCodec::read_u32_le(data, 16)?;
+        let mip_map_count = ImageCodec::read_u32_le(data, 28)?;
+        let four_cc = ImageCodec::bytes(data, 84, 4)?;
+        let pixel_format = DdsPixelFormat {
+            flags: ImageCodec::read_u32_le(data, 80)?,
+            bit_count: ImageCodec::read_u32_le(data, 88)?,
+            masks: [
+                ImageCodec::read_u32_le(data, 92)?,
+                ImageCodec::read_u32_le(data, 96)?,
+                ImageCodec::read_u32_le(data, 100)?,
+                ImageCodec::read_u32_le(data, 104)?,
+            ],
+        };
+        let caps2 = ImageCodec::read_u32_le(data, 112)?;
+
+        ImageCodec::validate_dimensions(width, height)?;
+
+        if caps2 & (Self::DDSCAPS2_CUBEMAP | Self::DDSCAPS2_VOLUME) != 0 {
+            return Err(Exception::new("Cube map and volume DDS files are not supported.", None));
+        }
+
+        let mut offset = Self::HEADER_SIZE;
+
+        let format = if pixel_format.flags & Self::DDPF_FOURCC != 0 {
+            if four_cc == b"DX10" {
+                let dxgi_format = ImageCodec::read_u32_le(data, offset)?;
+                let dimension = ImageCodec::read_u32_le(data, offset + 4)?;
+                let array_size = ImageCodec::read_u32_le(data, offset + 12)?;
+                offset += Self::DX10_HEADER_SIZE;
+
+                if dimension != Self::D3D10_RESOURCE_DIMENSION_TEXTURE2D || array_size > 1 {
+                    return Err(Exception::new("Only single 2D DDS textures are supported.", None));
+                }
+
+                Self::dxgi_format(dxgi_format)
+            } else {
+                Self::four_cc_format(four_cc)
+            }
+        } else {
+            Self::mask_format(&pixel_format)
+        };
+
+        let level_count = if flags & Self::DDSD_MIPMAPCOUNT != 0 { mip_map_count.max(1) } else { 1 };
+        let level_count = level_count.min(Texture2D::full_level_count(width, height));
+
+        match format {
+            Some(format) => {
+                let mut texture = Texture2D::new_with_format(width, height, level_count > 1, format)?;
+                texture.levels.truncate(level_count as usize);
+
+                for level in 0..level_count {
+                    let size = texture.levels[level as usize].len();
+                    texture.levels[level as usize].copy_from_slice(ImageCodec::bytes(data, offset, size)?);
+                    offset += size;
+                }
+
+                Ok(texture)
+            }
+            None if matches!(pixel_format.bit_count, 8 | 16 | 24 | 32) && pixel_format.flags & Self::DDPF_FOURCC == 0 => {
+                let mut texture = Texture2D::new_with_format(width, height, level_count > 1, SurfaceFormat::Color)?;
+                texture.levels.truncate(level_count as usize);
+
+                Self::decode_masked(data, offset, &mut texture, &pixel_format)?;
+                Ok(texture)
+            }
+            None => Err(Exception::new("The DDS pixel format is not supported.", None)),
+        }
+    }
+
+    fn decode_masked(data: &[u8], mut offset: usize, texture: &mut Texture2D, pixel_format: &DdsPixelFormat) -> Result<(), Exception> {
+        let masks = &pixel_format.masks;
+        let pixel_flags = pixel_format.flags;
+        let pixel_size = (pixel_format.bit_count / 8) as usize;
+        let is_luminance = masks[1] == 0 && masks[2] == 0 && pixel_flags & Self::DDPF_ALPHA == 0;
+
+        for level in 0..texture.level_count() {
+            let level_width = texture.level_width(level);
+            let level_height = texture.level_height(level);
+            let count = (level_width * level_height) as usize;
+            let bytes = ImageCodec::bytes(data, offset, count * pixel_size)?;
+            offset += count * pixel_size;
+
+            let pixels: Vec<Color> = bytes
+                .chunks_exact(pixel_size)
+                .map(|chunk| {
+                    let mut value = 0u32;
+
+                    for (index, byte) in chunk.iter().enumerate() {
+                        value |= (*byte as u32) << (index * 8);
+                    }
+
+                    let channel = |index: usize, default: u8| ImageCodec::extract_mask(value, masks[index]).unwrap_or(default);
+                    let alpha = if pixel_flags & (Self::DDPF_ALPHAPIXELS | Self::DDPF_ALPHA) != 0 { channel(3, 255) } else { 255 };
+
+                    if is_luminance {
+                        let luminance = channel(0, 0);
+                        ImageCodec::rgba(luminance, luminance, luminance, alpha)
+                    } else {
+                        ImageCodec::rgba(channel(0, 0), channel(1, 0), channel(2, 0), alpha)
+                    }
+                })
+                .collect();
+
+            texture.set_data(Some(level), None, &pixels)?;
+        }
+
+        Ok(())
+    }
+
+    fn four_cc_format(four_cc: &[u8]) -> Option<SurfaceFormat> {
+        match four_cc {
+            b"DXT1" => Some(SurfaceFormat::Dxt1),
+            b"DXT2" | b"DXT3" => Some(SurfaceFormat::Dxt3),
+            b"DXT4" | b"DXT5" => Some(SurfaceFormat::Dxt5),
+            _ => match u32::from_le_bytes([four_cc[0], four_cc[1], four_cc[2], four_cc[3]]) {
+                36 => Some(SurfaceFormat::Rgba64),
+                111 => Some(SurfaceFormat::HalfSingle),
+                112 => Some(SurfaceFormat::HalfVector2),
+                113 => Some(SurfaceFormat::HalfVector4),
+                114 => Some(SurfaceFormat::Single),
+                115 => Some(SurfaceFormat::Vector2),
+                116 => Some(SurfaceFormat::Vector4),
+                _ => None,
+            },
+        }
+    }
+
+    fn dxgi_format(format: u32) -> Option<SurfaceFormat> {
+        match format {
+            2 => Some(SurfaceFormat::Vector4),
+            10 => Some(SurfaceFormat::HalfVector4),
+            11 => Some(SurfaceFormat::Rgba64),
+            16 => Some(SurfaceFormat::Vector2),
+            24 => Some(SurfaceFormat::Rgba1010102),
+            28 => Some(SurfaceFormat::Color),
+            29 => Some(SurfaceFormat::ColorSRgb),
+            31 => Some(SurfaceFormat::NormalizedByte4),
+            34 => Some(SurfaceFormat::HalfVector2),
+            35 => Some(SurfaceFormat::Rg32),
+            41 => Some(SurfaceFormat::Single),
+            49 => Some(SurfaceFormat::NormalizedByte2),
+            54 => Some(SurfaceFormat::HalfSingle),
+            65 => Some(SurfaceFormat::Alpha8),
+            71 => Some(SurfaceFormat::Dxt1),
+            72 => Some(SurfaceFormat::Dxt1SRgb),
+            74 => Some(SurfaceFormat::Dxt3),
+            75 => Some(SurfaceFormat::Dxt3SRgb),
+            77 => Some(SurfaceFormat::Dxt5),
+            78 => Some(SurfaceFormat::Dxt5SRgb),
+            85 => Some(SurfaceFormat::Bgr565),
+            86 => Some(SurfaceFormat::Bgra5551),
+            87 => Some(SurfaceFormat::Bgra32),
+            88 => Some(SurfaceFormat::Bgr32),
+            91 => Some(SurfaceFormat::Bgra32SRgb),
+            93 => Some(SurfaceFormat::Bgr32SRgb),
+            115 => Some(SurfaceFormat::Bgra4444),
+            _ => None,
+        }
+    }
+
+    fn mask_format(pixel_format: &DdsPixelFormat) -> Option<SurfaceFormat> {
+        let masks = &pixel_format.masks;
+        let alpha = if pixel_format.flags & (Self::DDPF_ALPHAPIXELS | Self::DDPF_ALPHA) != 0 { masks[3] } else { 0 };
+
+        match (pixel_format.bit_count, masks[0], masks[1], masks[2], alpha) {
+            (32, 0x000000FF, 0x0000FF00, 0x00FF0000, 0xFF000000) => Some(SurfaceFormat::Color),
+            (32, 0x00FF0000, 0x0000FF00, 0x000000FF, 0xFF000000) => Some(SurfaceFormat::Bgra32),
+            (32, 0x00FF0000, 0x0000FF00, 0x000000FF, 0) => Some(SurfaceFormat::Bgr32),
+            (32, 0x000003FF, 0x000FFC00, 0x3FF00000, 0xC0000000) => Some(SurfaceFormat::Rgba1010102),
+            (32, 0x0000FFFF, 0xFFFF0000, 0, 0) => Some(SurfaceFormat::Rg32),
+            (16, 0xF800, 0x07E0, 0x001F, 0) => Some(SurfaceFormat::Bgr565),
+            (16, 0x7C00, 0x03E0, 0x001F, 0x8000) => Some(SurfaceFormat::Bgra5551),
+            (16, 0x0F00, 0x00F0, 0x000F, 0xF000) => Some(SurfaceFormat::Bgra4444),
+            (8, 0, 0, 0, 0xFF) => Some(SurfaceFormat::Alpha8),
+            _ => None,
+        }
+    }
+}
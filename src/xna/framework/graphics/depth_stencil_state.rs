@@ -1,10 +1,11 @@
+use crate::xna::csharp::Exception;
 use crate::xna::framework::graphics::{ComparisonFunction, DepthFace, DepthStencilState, StencilOperation};
 
 impl DepthStencilState {
     pub fn new() -> Self {
         DepthStencilState {
             depth_enable: true,
-            stencil_enable: true,
+            stencil_enable: false,
             depth_function: ComparisonFunction::LessEquals,
             front_face: DepthFace {
                 stencil_function: ComparisonFunction::Always,
@@ -21,8 +22,8 @@ impl DepthStencilState {
             stencil_read_mask: u8::MAX,
             stencil_write_mask: u8::MAX,
             depth_write_mask: true,
+            reference_stencil: 0,
         }
-
     }
 
     pub fn none() -> DepthStencilState {
@@ -33,12 +34,266 @@ impl DepthStencilState {
         state
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn default() -> DepthStencilState {
+        Self::new()
+    }
+
+    pub fn depth_read() -> DepthStencilState {
         let mut state = Self::new();
+        state.depth_write_mask = false;
+
         state
     }
 
-    pub fn depth_read() -> DepthStencilState {
-        Self::default()
+    pub fn face(&self, is_front_face: bool) -> &DepthFace {
+        if is_front_face { &self.front_face } else { &self.back_face }
+    }
+
+    pub fn test(&self, depth: f32, stored_depth: &mut f32, stored_stencil: &mut u8, is_front_face: bool) -> bool {
+        let face = self.face(is_front_face);
+        let reference = self.reference_stencil as u8;
+
+        if self.stencil_enable {
+            let stencil = *stored_stencil & self.stencil_read_mask;
+
+            if !face.stencil_function.compare(reference & self.stencil_read_mask, stencil) {
+                *stored_stencil = self.update_stencil(*stored_stencil, face.stencil_fail_operation);
+                return false;
+            }
+        }
+
+        if self.depth_enable && !self.depth_function.compare(depth, *stored_depth) {
+            if self.stencil_enable {
+                *stored_stencil = self.update_stencil(*stored_stencil, face.stencil_depth_fail_operation);
+            }
+
+            return false;
+        }
+
+        if self.stencil_enable {
+            *stored_stencil = self.update_stencil(*stored_stencil, face.stencil_pass_operation);
+        }
+
+        if self.depth_enable && self.depth_write_mask {
+            *stored_depth = depth;
+        }
+
+        true
+    }
+
+    pub fn test_buffers(&self, depth_buffer: &mut [f32], stencil_buffer: &mut [u8], index: usize, depth: f32, is_front_face: bool) -> Result<bool, Exception> {
+        if index >= depth_buffer.len() || index >= stencil_buffer.len() {
+            return Err(Exception::out_of_range("index is outside the depth or stencil buffer.", None));
+        }
+
+        Ok(self.test(depth, &mut depth_buffer[index], &mut stencil_buffer[index], is_front_face))
+    }
+
+    pub fn update_stencil(&self, stencil: u8, operation: StencilOperation) -> u8 {
+        let value = operation.apply(stencil, self.reference_stencil as u8);
+        let mask = self.stencil_write_mask;
+
+        (value & mask) | (stencil & !mask)
+    }
+}
+
+impl ComparisonFunction {
+    pub fn compare<T: PartialOrd>(&self, source: T, destination: T) -> bool {
+        match self {
+            ComparisonFunction::Never => false,
+            ComparisonFunction::Less => source < destination,
+            ComparisonFunction::Equal => source == destination,
+            ComparisonFunction::LessEquals => source <= destination,
+            ComparisonFunction::Greater => source > destination,
+            ComparisonFunction::NotEqual => source != destination,
+            ComparisonFunction::GreaterEqual => source >= destination,
+            ComparisonFunction::Always => true,
+        }
+    }
+}
+
+impl StencilOperation {
+    pub fn apply(&self, stencil: u8, reference: u8) -> u8 {
+        match self {
+            StencilOperation::Keep => stencil,
+            StencilOperation::Zero => 0,
+            StencilOperation::Replace => reference,
+            StencilOperation::IncrementSaturation => stencil.saturating_add(1),
+            StencilOperation::DecrementSaturation => stencil.saturating_sub(1),
+            StencilOperation::Invert => !stencil,
+            StencilOperation::Increment => stencil.wrapping_add(1),
+            StencilOperation::Decrement => stencil.wrapping_sub(1),
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OPERATIONS: [(StencilOperation, u8); 8] = [
+        (StencilOperation::Keep, 5),
+        (StencilOperation::Zero, 0),
+        (StencilOperation::Replace, 3),
+        (StencilOperation::IncrementSaturation, 6),
+        (StencilOperation::DecrementSaturation, 4),
+        (StencilOperation::Invert, 250),
+        (StencilOperation::Increment, 6),
+        (StencilOperation::Decrement, 4),
+    ];
+
+    fn stencil_state(face: DepthFace) -> DepthStencilState {
+        DepthStencilState {
+            stencil_enable: true,
+            depth_function: ComparisonFunction::Less,
+            front_face: face,
+            back_face: face,
+            reference_stencil: 3,
+            ..DepthStencilState::new()
+        }
+    }
+
+    #[test]
+    fn presets_control_depth_testing_and_writes() {
+        let mut stencil = 0;
+
+        let mut depth = 0.1;
+        assert!(DepthStencilState::none().test(0.9, &mut depth, &mut stencil, true));
+        assert_eq!(depth, 0.1);
+
+        let mut depth = 0.1;
+        assert!(!DepthStencilState::default().test(0.9, &mut depth, &mut stencil, true));
+        assert!(DepthStencilState::default().test(0.1, &mut depth, &mut stencil, true));
+        assert!(DepthStencilState::default().test(0.05, &mut depth, &mut stencil, true));
+        assert_eq!(depth, 0.05);
+
+        let mut depth = 0.5;
+        assert!(!DepthStencilState::depth_read().test(0.9, &mut depth, &mut stencil, true));
+        assert!(DepthStencilState::depth_read().test(0.2, &mut depth, &mut stencil, true));
+        assert_eq!(depth, 0.5);
+        assert_eq!(stencil, 0);
+    }
+
+    #[test]
+    fn stencil_fail_operation_runs_when_the_stencil_test_fails() {
+        for (operation, expected) in OPERATIONS {
+            let state = stencil_state(DepthFace {
+                stencil_function: ComparisonFunction::Never,
+                stencil_fail_operation: operation,
+                ..DepthFace::default()
+            });
+            let mut depth = 1.0;
+            let mut stencil = 5;
+
+            assert!(!state.test(0.5, &mut depth, &mut stencil, true));
+            assert_eq!(stencil, expected);
+            assert_eq!(depth, 1.0);
+        }
+    }
+
+    #[test]
+    fn stencil_depth_fail_operation_runs_when_the_depth_test_fails() {
+        for (operation, expected) in OPERATIONS {
+            let state = stencil_state(DepthFace {
+                stencil_function: ComparisonFunction::Always,
+                stencil_depth_fail_operation: operation,
+                ..DepthFace::default()
+            });
+            let mut depth = 0.1;
+            let mut stencil = 5;
+
+            assert!(!state.test(0.5, &mut depth, &mut stencil, true));
+            assert_eq!(stencil, expected);
+            assert_eq!(depth, 0.1);
+        }
+    }
+
+    #[test]
+    fn stencil_pass_operation_runs_when_both_tests_pass() {
+        for (operation, expected) in OPERATIONS {
+            let state = stencil_state(DepthFace {
+                stencil_function: ComparisonFunction::Always,
+                stencil_pass_operation: operation,
+                ..DepthFace::default()
+            });
+            let mut depth = 1.0;
+            let mut stencil = 5;
+
+            assert!(state.test(0.5, &mut depth, &mut stencil, true));
+            assert_eq!(stencil, expected);
+            assert_eq!(depth, 0.5);
+        }
+    }
+
+    #[test]
+    fn stencil_operations_saturate_or_wrap_at_the_limits() {
+        assert_eq!(StencilOperation::IncrementSaturation.apply(255, 0), 255);
+        assert_eq!(StencilOperation::Increment.apply(255, 0), 0);
+        assert_eq!(StencilOperation::DecrementSaturation.apply(0, 0), 0);
+        assert_eq!(StencilOperation::Decrement.apply(0, 0), 255);
+    }
+
+    #[test]
+    fn read_and_write_masks_limit_the_stencil_bits() {
+        let mut state = stencil_state(DepthFace {
+            stencil_function: ComparisonFunction::Equal,
+            stencil_pass_operation: StencilOperation::Replace,
+            ..DepthFace::default()
+        });
+        state.depth_enable = false;
+        state.reference_stencil = 0xF3;
+
+        let mut depth = 1.0;
+        let mut stencil = 0xA3;
+        assert!(!state.test(0.5, &mut depth, &mut stencil, true));
+
+        state.stencil_read_mask = 0x0F;
+        state.stencil_write_mask = 0x0F;
+        assert!(state.test(0.5, &mut depth, &mut stencil, true));
+        assert_eq!(stencil, 0xA3);
+
+        state.reference_stencil = 0x3C;
+        state.front_face.stencil_function = ComparisonFunction::Always;
+        assert!(state.test(0.5, &mut depth, &mut stencil, true));
+        assert_eq!(stencil, 0xAC);
+
+        state.stencil_write_mask = 0xF0;
+        assert_eq!(state.update_stencil(0xFF, StencilOperation::Zero), 0x0F);
+    }
+
+    #[test]
+    fn front_and_back_faces_use_their_own_operations() {
+        let mut state = stencil_state(DepthFace {
+            stencil_function: ComparisonFunction::Always,
+            stencil_pass_operation: StencilOperation::Replace,
+            ..DepthFace::default()
+        });
+        state.back_face = DepthFace {
+            stencil_function: ComparisonFunction::Never,
+            stencil_fail_operation: StencilOperation::Zero,
+            ..DepthFace::default()
+        };
+
+        let mut depth = 1.0;
+        let mut stencil = 5;
+        assert!(state.test(0.5, &mut depth, &mut stencil, true));
+        assert_eq!(stencil, 3);
+
+        let mut depth = 1.0;
+        let mut stencil = 5;
+        assert!(!state.test(0.5, &mut depth, &mut stencil, false));
+        assert_eq!(stencil, 0);
+        assert_eq!(depth, 1.0);
+    }
+
+    #[test]
+    fn test_buffers_rejects_out_of_range_indices() {
+        let state = DepthStencilState::default();
+        let mut depth = [1.0; 2];
+        let mut stencil = [0; 2];
+
+        assert!(state.test_buffers(&mut depth, &mut stencil, 1, 0.5, true).unwrap());
+        assert_eq!(depth[1], 0.5);
+        assert!(state.test_buffers(&mut depth, &mut stencil, 2, 0.5, true).is_err());
     }
-}
\ No newline at end of file
+}
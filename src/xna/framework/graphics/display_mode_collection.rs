@@ -7,7 +7,7 @@ impl DisplayModeCollection {
 
         for mode in &self.display_modes {
             if mode.format == *format {
-                modes.push(mode.clone());
+                modes.push(*mode);
                 count += 1;
             }
         }
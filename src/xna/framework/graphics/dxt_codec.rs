@@ -0,0 +1,608 @@
+use crate::xna::csharp::Exception;
+use crate::xna::framework::Color;
+use crate::xna::framework::graphics::{DxtCodec, DxtCompressionQuality, ImageCodec, SurfaceFormat};
+
+type Rgb = [f32; 3];
+
+impl DxtCodec {
+    const REFINEMENT_PASSES: usize = 4;
+
+    pub fn decode(format: SurfaceFormat, width: u32, height: u32, data: &[u8]) -> Result<Vec<Color>, Exception> {
+        let block_size = Self::block_size(format)?;
+        let blocks_wide = width.div_ceil(4) as usize;
+        let blocks_high = height.div_ceil(4) as usize;
+
+        if data.len() < blocks_wide * blocks_high * block_size {
+            return Err(Exception::argument_exception("data is smaller than the compressed surface.", None));
+        }
+
+        let mut pixels = vec![Color::transparent(); (width * height) as usize];
+
+        for block_y in 0..blocks_high {
+            for block_x in 0..blocks_wide {
+                let offset = (block_y * blocks_wide + block_x) * block_size;
+                let block = Self::decode_block(format, &data[offset..offset + block_size])?;
+
+                for (index, color) in block.iter().enumerate() {
+                    let x = block_x * 4 + index % 4;
+                    let y = block_y * 4 + index / 4;
+
+                    if x < width as usize && y < height as usize {
+                        pixels[y * width as usize + x] = *color;
+                    }
+                }
+            }
+        }
+
+        Ok(pixels)
+    }
+
+    pub fn encode(format: SurfaceFormat, width: u32, height: u32, pixels: &[Color], quality: DxtCompressionQuality) -> Result<Vec<u8>, Exception> {
+        let block_size = Self::block_size(format)?;
+
+        if pixels.len() < (width * height) as usize {
+            return Err(Exception::argument_exception("pixels is smaller than the surface dimensions.", None));
+        }
+
+        let blocks_wide = width.div_ceil(4) as usize;
+        let blocks_high = height.div_ceil(4) as usize;
+        let mut result = Vec::with_capacity(blocks_wide * blocks_high * block_size);
+
+        for block_y in 0..blocks_high {
+            for block_x in 0..blocks_wide {
+                let mut block = [Color::transparent(); 16];
+
+                for (index, color) in block.iter_mut().enumerate() {
+                    let x = (block_x * 4 + index % 4).min(width as usize - 1);
+                    let y = (block_y * 4 + index / 4).min(height as usize - 1);
+                    *color = pixels[y * width as usize + x];
+                }
+
+                Self::encode_block(format, &block, quality, &mut result)?;
+            }
+        }
+
+        Ok(result)
+    }
+
+    pub fn decode_block(format: SurfaceFormat, block: &[u8]) -> Result<[Color; 16], Exception> {
+        if block.len() < Self::block_size(format)? {
+            return Err(Exception::argument_exception("block is smaller than a compressed block of the surface format.", None));
+        }
+
+        let colors = match format.to_linear() {
+            SurfaceFormat::Dxt1 => Self::decode_color_block(&block[..8], true),
+            SurfaceFormat::Dxt3 => {
+                let mut colors = Self::decode_color_block(&block[8..16], false);
+                let alpha = u64::from_le_bytes(block[..8].try_into().unwrap());
+
+                for (index, color) in colors.iter_mut().enumerate() {
+                    color.set_a((((alpha >> (index * 4)) & 0xF) * 17) as u8);
+                }
+
+                colors
+            }
+            _ => {
+                let mut colors = Self::decode_color_block(&block[8..16], false);
+                let palette = Self::alpha_palette(block[0], block[1]);
+                let indices = u64::from_le_bytes([block[2], block[3], block[4], block[5], block[6], block[7], 0, 0]);
+
+                for (index, color) in colors.iter_mut().enumerate() {
+                    color.set_a(palette[((indices >> (index * 3)) & 0x7) as usize]);
+                }
+
+                colors
+            }
+        };
+
+        Ok(colors)
+    }
+
+    pub fn encode_block(format: SurfaceFormat, block: &[Color; 16], quality: DxtCompressionQuality, output: &mut Vec<u8>) -> Result<(), Exception> {
+        Self::block_size(format)?;
+
+        match format.to_linear() {
+            SurfaceFormat::Dxt1 => Self::encode_color_block(block, true, quality, output),
+            SurfaceFormat::Dxt3 => {
+                let mut alpha = 0u64;
+
+                for (index, color) in block.iter().enumerate() {
+                    alpha |= ((color.a() as u64 * 15 + 127) / 255) << (index * 4);
+                }
+
+                output.extend_from_slice(&alpha.to_le_bytes());
+                Self::encode_color_block(block, false, quality, output);
+            }
+            _ => {
+                Self::encode_alpha_block(block, quality, output);
+                Self::encode_color_block(block, false, quality, output);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn block_size(format: SurfaceFormat) -> Result<usize, Exception> {
+        match format.to_linear() {
+            SurfaceFormat::Dxt1 => Ok(8),
+            SurfaceFormat::Dxt3 | SurfaceFormat::Dxt5 => Ok(16),
+            _ => Err(Exception::argument_exception("The surface format is not a DXT format.", None)),
+        }
+    }
+
+    fn decode_color_block(block: &[u8], is_dxt1: bool) -> [Color; 16] {
+        let color0 = u16::from_le_bytes([block[0], block[1]]);
+        let color1 = u16::from_le_bytes([block[2], block[3]]);
+        let palette = Self::color_palette(color0, color1, is_dxt1 && color0 <= color1);
+        let indices = u32::from_le_bytes([block[4], block[5], block[6], block[7]]);
+
+        let mut colors = [Color::transparent(); 16];
+
+        for (index, color) in colors.iter_mut().enumerate() {
+            *color = palette[((indices >> (index * 2)) & 0x3) as usize];
+        }
+
+        colors
+    }
+
+    fn color_palette(color0: u16, color1: u16, is_three_color: bool) -> [Color; 4] {
+        let first = Self::unpack_565(color0);
+        let second = Self::unpack_565(color1);
+        let mix = |weight0: u32, weight1: u32, divisor: u32| {
+            let channel = |index: usize| ((first[index] as u32 * weight0 + second[index] as u32 * weight1) / divisor) as u8;
+            ImageCodec::rgba(channel(0), channel(1), channel(2), 255)
+        };
+
+        let start = ImageCodec::rgba(first[0], first[1], first[2], 255);
+        let end = ImageCodec::rgba(second[0], second[1], second[2], 255);
+
+        if is_three_color {
+            [start, end, mix(1, 1, 2), Color::transparent()]
+        } else {
+            [start, end, mix(2, 1, 3), mix(1, 2, 3)]
+        }
+    }
+
+    fn alpha_palette(alpha0: u8, alpha1: u8) -> [u8; 8] {
+        let first = alpha0 as u32;
+        let second = alpha1 as u32;
+        let mut palette = [alpha0, alpha1, 0, 0, 0, 0, 0, 255];
+
+        if alpha0 > alpha1 {
+            for (index, value) in palette.iter_mut().enumerate().skip(2) {
+                *value = ((first * (8 - index as u32) + second * (index as u32 - 1)) / 7) as u8;
+            }
+        } else {
+            for (index, value) in palette.iter_mut().enumerate().take(6).skip(2) {
+                *value = ((first * (6 - index as u32) + second * (index as u32 - 1)) / 5) as u8;
+            }
+        }
+
+        palette
+    }
+
+    fn unpack_565(value: u16) -> [u8; 3] {
+        let red = ((value >> 11) & 0x1F) as u8;
+        let green = ((value >> 5) & 0x3F) as u8;
+        let blue = (value & 0x1F) as u8;
+
+        [(red << 3) | (red >> 2), (green << 2) | (green >> 4), (blue << 3) | (blue >> 2)]
+    }
+
+    fn pack_565(color: &Rgb) -> u16 {
+        let quantize = |value: f32, maximum: f32| (value.clamp(0.0, 255.0) * maximum / 255.0).round() as u16;
+
+        (quantize(color[0], 31.0) << 11) | (quantize(color[1], 63.0) << 5) | quantize(color[2], 31.0)
+    }
+
+    fn encode_color_block(block: &[Color; 16], is_dxt1: bool, quality: DxtCompressionQuality, output: &mut Vec<u8>) {
+        let has_transparency = is_dxt1 && block.iter().any(|color| color.a() < 128);
+        let points: Vec<Rgb> = block
+            .iter()
+            .filter(|color| !has_transparency || color.a() >= 128)
+            .map(|color| [color.r() as f32, color.g() as f32, color.b() as f32])
+            .collect();
+
+        if points.is_empty() {
+            output.extend_from_slice(&[0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF]);
+            return;
+        }
+
+        let (start, end) = match quality {
+            DxtCompressionQuality::Fast => Self::bounding_box_endpoints(&points),
+            DxtCompressionQuality::HighQuality => Self::principal_axis_endpoints(&points),
+        };
+
+        let mut best = Self::fit_endpoints(block, &start, &end, has_transparency);
+
+        if quality == DxtCompressionQuality::HighQuality {
+            for _ in 0..Self::REFINEMENT_PASSES {
+                let Some((start, end)) = Self::refine_endpoints(block, &best, has_transparency) else {
+                    break;
+                };
+
+                let candidate = Self::fit_endpoints(block, &start, &end, has_transparency);
+
+                if candidate.3 >= best.3 {
+                    break;
+                }
+
+                best = candidate;
+            }
+
+            if is_dxt1 && !has_transparency {
+                let color0 = best.0.min(best.1);
+                let color1 = best.0.max(best.1);
+                let candidate = Self::select_indices(block, color0, color1, true);
+
+                if candidate.3 < best.3 {
+                    best = candidate;
+                }
+            }
+        }
+
+        output.extend_from_slice(&best.0.to_le_bytes());
+        output.extend_from_slice(&best.1.to_le_bytes());
+        output.extend_from_slice(&best.2.to_le_bytes());
+    }
+
+    fn fit_endpoints(block: &[Color; 16], start: &Rgb, end: &Rgb, has_transparency: bool) -> (u16, u16, u32, f32) {
+        let mut color0 = Self::pack_565(start);
+        let mut color1 = Self::pack_565(end);
+
+        if has_transparency {
+            if color0 > color1 {
+                std::mem::swap(&mut color0, &mut color1);
+            }
+
+            return Self::select_indices(block, color0, color1, true);
+        }
+
+        if color0 < color1 {
+            std::mem::swap(&mut color0, &mut color1);
+        }
+
+        Self::select_indices(block, color0, color1, color0 == color1)
+    }
+
+    fn select_indices(block: &[Color; 16], color0: u16, color1: u16, is_three_color: bool) -> (u16, u16, u32, f32) {
+        let palette = Self::color_palette(color0, color1, is_three_color);
+        let mut indices = 0u32;
+        let mut total_error = 0.0;
+
+        for (index, color) in block.iter().enumerate() {
+            let (selected, error) = if is_three_color && color.a() < 128 {
+                (3, 0.0)
+            } else {
+                let candidates = if is_three_color { 3 } else { 4 };
+
+                (0..candidates)
+                    .map(|candidate| (candidate, Self::color_distance(color, &palette[candidate])))
+                    .min_by(|first, second| first.1.total_cmp(&second.1))
+                    .unwrap()
+            };
+
+            indices |= (selected as u32) << (index * 2);
+            total_error += error;
+        }
+
+        (color0, color1, indices, total_error)
+    }
+
+    fn refine_endpoints(block: &[Color; 16], fit: &(u16, u16, u32, f32), has_transparency: bool) -> Option<(Rgb, Rgb)> {
+        let is_three_color = has_transparency || fit.0 <= fit.1;
+        let weights: [f32; 4] = if is_three_color { [1.0, 0.0, 0.5, 0.0] } else { [1.0, 0.0, 2.0 / 3.0, 1.0 / 3.0] };
+
+        let mut alpha_alpha = 0.0;
+        let mut alpha_beta = 0.0;
+        let mut beta_beta = 0.0;
+        let mut alpha_x = [0.0f32; 3];
+        let mut beta_x = [0.0f32; 3];
+
+        for (index, color) in block.iter().enumerate() {
+            let selected = ((fit.2 >> (index * 2)) & 0x3) as usize;
+
+            if is_three_color && selected == 3 {
+                continue;
+            }
+
+            let alpha = weights[selected];
+            let beta = 1.0 - alpha;
+            let point = [color.r() as f32, color.g() as f32, color.b() as f32];
+
+            alpha_alpha += alpha * alpha;
+            alpha_beta += alpha * beta;
+            beta_beta += beta * beta;
+
+            for channel in 0..3 {
+                alpha_x[channel] += alpha * point[channel];
+                beta_x[channel] += beta * point[channel];
+            }
+        }
+
+        let determinant = alpha_alpha * beta_beta - alpha_beta * alpha_beta;
+
+        if determinant.abs() < 1e-6 {
+            return None;
+        }
+
+        let mut start = [0.0f32; 3];
+        let mut end = [0.0f32; 3];
+
+        for channel in 0..3 {
+            start[channel] = (alpha_x[channel] * beta_beta - beta_x[channel] * alpha_beta) / determinant;
+            end[channel] = (beta_x[channel] * alpha_alpha - alpha_x[channel] * alpha_beta) / determinant;
+        }
+
+        Some((start, end))
+    }
+
+    fn bounding_box_endpoints(points: &[Rgb]) -> (Rgb, Rgb) {
+        let mut minimum = [255.0f32; 3];
+        let mut maximum = [0.0f32; 3];
+
+        for point in points {
+            for channel in 0..3 {
+                minimum[channel] = minimum[channel].min(point[channel]);
+                maximum[channel] = maximum[channel].max(point[channel]);
+            }
+        }
+
+        let mean = Self::mean(points);
+        let mut covariance = [0.0f32; 2];
+
+        for point in points {
+            covariance[0] += (point[0] - mean[0]) * (point[1] - mean[1]);
+            covariance[1] += (point[0] - mean[0]) * (point[2] - mean[2]);
+        }
+
+        for (index, value) in covariance.iter().enumerate() {
+            if *value < 0.0 {
+                std::mem::swap(&mut minimum[index + 1], &mut maximum[index + 1]);
+            }
+        }
+
+        for channel in 0..3 {
+            let inset = (maximum[channel] - minimum[channel]) / 16.0;
+            maximum[channel] -= inset;
+            minimum[channel] += inset;
+        }
+
+        (maximum, minimum)
+    }
+
+    fn principal_axis_endpoints(points: &[Rgb]) -> (Rgb, Rgb) {
+        let mean = Self::mean(points);
+        let mut covariance = [[0.0f32; 3]; 3];
+
+        for point in points {
+            let delta = [point[0] - mean[0], point[1] - mean[1], point[2] - mean[2]];
+
+            for row in 0..3 {
+                for column in 0..3 {
+                    covariance[row][column] += delta[row] * delta[column];
+                }
+            }
+        }
+
+        let largest = (0..3).max_by(|first, second| covariance[*first][*first].total_cmp(&covariance[*second][*second])).unwrap();
+        let seed = covariance[largest];
+        let seed_length = (seed[0] * seed[0] + seed[1] * seed[1] + seed[2] * seed[2]).sqrt();
+
+        if seed_length < 1e-6 {
+            return (mean, mean);
+        }
+
+        let mut axis = [seed[0] / seed_length, seed[1] / seed_length, seed[2] / seed_length];
+
+        for _ in 0..8 {
+            let next = [
+                covariance[0][0] * axis[0] + covariance[0][1] * axis[1] + covariance[0][2] * axis[2],
+                covariance[1][0] * axis[0] + covariance[1][1] * axis[1] + covariance[1][2] * axis[2],
+                covariance[2][0] * axis[0] + covariance[2][1] * axis[1] + covariance[2][2] * axis[2],
+            ];
+
+            let length = (next[0] * next[0] + next[1] * next[1] + next[2] * next[2]).sqrt();
+
+            if length < 1e-6 {
+                break;
+            }
+
+            axis = [next[0] / length, next[1] / length, next[2] / length];
+        }
+
+        let project = |point: &Rgb| (point[0] - mean[0]) * axis[0] + (point[1] - mean[1]) * axis[1] + (point[2] - mean[2]) * axis[2];
+        let minimum = points.iter().map(project).fold(f32::MAX, f32::min);
+        let maximum = points.iter().map(project).fold(f32::MIN, f32::max);
+        let along = |distance: f32| [mean[0] + axis[0] * distance, mean[1] + axis[1] * distance, mean[2] + axis[2] * distance];
+
+        (along(maximum), along(minimum))
+    }
+
+    fn mean(points: &[Rgb]) -> Rgb {
+        let mut mean = [0.0f32; 3];
+
+        for point in points {
+            for channel in 0..3 {
+                mean[channel] += point[channel] / points.len() as f32;
+            }
+        }
+
+        mean
+    }
+
+    fn color_distance(first: &Color, second: &Color) -> f32 {
+        let red = first.r() as f32 - second.r() as f32;
+        let green = first.g() as f32 - second.g() as f32;
+        let blue = first.b() as f32 - second.b() as f32;
+
+        red * red + green * green + blue * blue
+    }
+
+    fn encode_alpha_block(block: &[Color; 16], quality: DxtCompressionQuality, output: &mut Vec<u8>) {
+        let alphas: Vec<u8> = block.iter().map(|color| color.a()).collect();
+        let maximum = *alphas.iter().max().unwrap();
+        let minimum = *alphas.iter().min().unwrap();
+
+        let mut best = Self::select_alpha_indices(&alphas, maximum, minimum);
+
+        if quality == DxtCompressionQuality::HighQuality {
+            let interior: Vec<u8> = alphas.iter().copied().filter(|alpha| *alpha != 0 && *alpha != 255).collect();
+
+            if let (Some(low), Some(high)) = (interior.iter().min(), interior.iter().max()) {
+                let candidate = Self::select_alpha_indices(&alphas, *low, *high);
+
+                if candidate.3 < best.3 {
+                    best = candidate;
+                }
+            }
+        }
+
+        output.push(best.0);
+        output.push(best.1);
+        output.extend_from_slice(&best.2.to_le_bytes()[..6]);
+    }
+
+    fn select_alpha_indices(alphas: &[u8], alpha0: u8, alpha1: u8) -> (u8, u8, u64, u32) {
+        let palette = Self::alpha_palette(alpha0, alpha1);
+        let mut indices = 0u64;
+        let mut total_error = 0u32;
+
+        for (index, alpha) in alphas.iter().enumerate() {
+            let (selected, error) = palette
+                .iter()
+                .enumerate()
+                .map(|(candidate, value)| (candidate, (*alpha as i32 - *value as i32).unsigned_abs().pow(2)))
+                .min_by_key(|(_, error)| *error)
+                .unwrap();
+
+            indices |= (selected as u64) << (index * 3);
+            total_error += error;
+        }
+
+        (alpha0, alpha1, indices, total_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FORMATS: [SurfaceFormat; 3] = [SurfaceFormat::Dxt1, SurfaceFormat::Dxt3, SurfaceFormat::Dxt5];
+    const QUALITIES: [DxtCompressionQuality; 2] = [DxtCompressionQuality::Fast, DxtCompressionQuality::HighQuality];
+
+    fn round_trip(format: SurfaceFormat, width: u32, height: u32, pixels: &[Color], quality: DxtCompressionQuality) -> Vec<Color> {
+        DxtCodec::decode(format, width, height, &DxtCodec::encode(format, width, height, pixels, quality).unwrap()).unwrap()
+    }
+
+    fn mean_squared_error(expected: &[Color], actual: &[Color]) -> (f64, f64) {
+        let mut error = (0.0, 0.0);
+
+        for (first, second) in expected.iter().zip(actual.iter()) {
+            for (a, b) in [(first.r(), second.r()), (first.g(), second.g()), (first.b(), second.b())] {
+                error.0 += (a as f64 - b as f64).powi(2);
+            }
+
+            error.1 += (first.a() as f64 - second.a() as f64).powi(2);
+        }
+
+        (error.0 / (3 * expected.len()) as f64, error.1 / expected.len() as f64)
+    }
+
+    #[test]
+    fn solid_blocks_round_trip_exactly() {
+        let colors = [Color::red(), Color::white(), Color::black(), ImageCodec::rgba(0, 255, 255, 255)];
+
+        for format in FORMATS {
+            for quality in QUALITIES {
+                for color in colors {
+                    assert_eq!(round_trip(format, 4, 4, &[color; 16], quality), vec![color; 16]);
+                }
+            }
+        }
+
+        let translucent = ImageCodec::rgba(255, 0, 0, 136);
+
+        for quality in QUALITIES {
+            assert_eq!(round_trip(SurfaceFormat::Dxt3, 4, 4, &[translucent; 16], quality), vec![translucent; 16]);
+            assert_eq!(round_trip(SurfaceFormat::Dxt5, 4, 4, &[translucent; 16], quality), vec![translucent; 16]);
+        }
+    }
+
+    #[test]
+    fn gradient_blocks_stay_close_to_the_source() {
+        let pixels: Vec<Color> = (0..16u32)
+            .map(|index| {
+                let value = (index * 4) as u8;
+                ImageCodec::rgba(100 + value, 160 - value, 128, 40 + value)
+            })
+            .collect();
+
+        for format in FORMATS {
+            let source: Vec<Color> = pixels
+                .iter()
+                .map(|pixel| if format == SurfaceFormat::Dxt1 { ImageCodec::rgba(pixel.r(), pixel.g(), pixel.b(), 255) } else { *pixel })
+                .collect();
+
+            let fast = mean_squared_error(&source, &round_trip(format, 4, 4, &source, DxtCompressionQuality::Fast));
+            let high = mean_squared_error(&source, &round_trip(format, 4, 4, &source, DxtCompressionQuality::HighQuality));
+
+            assert!(fast.0 < 30.0 && high.0 <= fast.0, "{} {:?} {:?}", format as u32, fast, high);
+
+            match format {
+                SurfaceFormat::Dxt1 => assert_eq!(high.1, 0.0),
+                SurfaceFormat::Dxt3 => assert!(high.1 < 30.0),
+                _ => assert!(high.1 < 10.0),
+            }
+        }
+    }
+
+    #[test]
+    fn dxt1_keeps_punch_through_alpha() {
+        let pixels: Vec<Color> = (0..16u32)
+            .map(|index| if index % 3 == 0 { Color::transparent() } else { ImageCodec::rgba((index * 16) as u8, 64, 200, 255) })
+            .collect();
+
+        for quality in QUALITIES {
+            let decoded = round_trip(SurfaceFormat::Dxt1, 4, 4, &pixels, quality);
+
+            assert!(pixels.iter().zip(decoded.iter()).all(|(first, second)| (first.a() == 0) == (second.a() == 0)));
+        }
+    }
+
+    #[test]
+    fn decode_handles_partial_blocks() {
+        let pixels: Vec<Color> = (0..35u32)
+            .map(|index| ImageCodec::rgba(if index % 7 < 4 { 0 } else { 255 }, if index / 7 < 4 { 0 } else { 255 }, 255, 255))
+            .collect();
+
+        for format in FORMATS {
+            for quality in QUALITIES {
+                assert_eq!(round_trip(format, 7, 5, &pixels, quality), pixels);
+            }
+        }
+    }
+
+    #[test]
+    fn decode_block_rejects_short_blocks() {
+        assert!(DxtCodec::decode_block(SurfaceFormat::Dxt1, &[0; 7]).is_err());
+        assert!(DxtCodec::decode_block(SurfaceFormat::Dxt3, &[0; 15]).is_err());
+        assert!(DxtCodec::decode_block(SurfaceFormat::Dxt5, &[0; 8]).is_err());
+    }
+
+    #[test]
+    fn block_functions_reject_uncompressed_formats() {
+        let mut output = Vec::new();
+
+        assert!(DxtCodec::decode_block(SurfaceFormat::Color, &[0; 16]).is_err());
+        assert!(DxtCodec::encode_block(SurfaceFormat::Color, &[Color::white(); 16], DxtCompressionQuality::Fast, &mut output).is_err());
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn decode_block_accepts_srgb_formats() {
+        let block = [0x00, 0xF8, 0x1F, 0x00, 0, 0, 0, 0];
+
+        assert_eq!(DxtCodec::decode_block(SurfaceFormat::Dxt1SRgb, &block).unwrap()[0], Color::red());
+    }
+}
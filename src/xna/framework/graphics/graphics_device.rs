@@ -1,7 +1,9 @@
 use crate::xna::framework::game::GraphicsProfile;
+use crate::xna::framework::Rectangle;
 use crate::xna::framework::graphics::{BlendState, DepthFormat, DepthStencilState, GraphicsAdapter, GraphicsDevice, PresentInterval, PresentationParameters, RasterizerState, RenderTarget2D, SurfaceFormat, SwapChain, SwapEffect, Texture2D, Viewport};
 
 impl GraphicsDevice {
+    #[cfg_attr(not(target_os = "windows"), allow(clippy::needless_update))]
     pub fn new() -> Self {
         GraphicsDevice {
             presentation_parameters: PresentationParameters {
@@ -31,6 +33,7 @@ impl GraphicsDevice {
                 max_depth: 1.0,
                 min_depth: 0.0
             },
+            scissor_rectangle: Rectangle::new(0, 0, 800, 600),
             ..Default::default()
         }
     }
@@ -38,10 +41,14 @@ impl GraphicsDevice {
     pub fn new_from_profile(adapter: &GraphicsAdapter, profile: &GraphicsProfile, presentation_parameters: &PresentationParameters) -> Self {
         let mut device = Self::new();
         device.adapter = Some(adapter.clone());
-        device.graphics_profile = profile.clone();
-        device.presentation_parameters = presentation_parameters.clone();
+        device.graphics_profile = *profile;
+        device.presentation_parameters = *presentation_parameters;
 
         device
     }
+
+    pub fn present_count(&self) -> u64 {
+        self.platform.present_count.get()
+    }
 }
 
@@ -0,0 +1,181 @@
+use crate::xna::csharp::Exception;
+use crate::xna::framework::graphics::HalfTypeHelper;
+
+impl HalfTypeHelper {
+    pub fn convert(value: f32) -> u16 {
+        let num1 = value.to_bits();
+        let num2 = (num1 & 0x80000000) >> 16;
+        let num3 = num1 & 0x7FFFFFFF;
+
+        if num3 > 0x47FFEFFF {
+            return (num2 | 0x7FFF) as u16;
+        }
+
+        if num3 < 0x38800000 {
+            let num4 = (num3 & 0x7FFFFF) | 0x800000;
+            let num5 = 113 - (num3 >> 23) as i32;
+            let num6 = if num5 > 31 { 0 } else { num4 >> num5 };
+
+            return (num2 | (num6 + 0xFFF + ((num6 >> 13) & 1)) >> 13) as u16;
+        }
+
+        (num2 | (num3 - 0x38000000 + 0xFFF + ((num3 >> 13) & 1)) >> 13) as u16
+    }
+
+    pub fn convert_to_single(value: u16) -> f32 {
+        let value = value as u32;
+        let num1 = (value & 0x8000) << 16;
+
+        let num2 = if value & 0x7C00 == 0 {
+            if value & 0x3FF == 0 {
+                0
+            } else {
+                let mut num3 = -14i32;
+                let mut num4 = value & 0x3FF;
+
+                while num4 & 0x400 == 0 {
+                    num3 -= 1;
+                    num4 <<= 1;
+                }
+
+                (((num3 + 127) as u32) << 23) | ((num4 & 0x3FF) << 13)
+            }
+        } else {
+            ((((value >> 10) & 0x1F) + 112) << 23) | ((value & 0x3FF) << 13)
+        };
+
+        f32::from_bits(num1 | num2)
+    }
+
+    pub fn convert_ieee(value: f32) -> u16 {
+        let num1 = value.to_bits();
+        let num2 = (num1 >> 16) & 0x8000;
+        let num3 = num1 & 0x7FFFFFFF;
+
+        if num3 > 0x7F800000 {
+            return (num2 | 0x7E00 | ((num3 >> 13) & 0x3FF)) as u16;
+        }
+
+        if num3 > 0x477FEFFF {
+            return (num2 | 0x7C00) as u16;
+        }
+
+        if num3 < 0x38800000 {
+            if num3 <= 0x33000000 {
+                return num2 as u16;
+            }
+
+            let num4 = (num3 & 0x7FFFFF) | 0x800000;
+            let num5 = 126 - (num3 >> 23);
+            let num6 = num4 >> num5;
+            let num7 = num4 & ((1 << num5) - 1);
+            let num8 = 1 << (num5 - 1);
+
+            let num9 = if num7 > num8 || (num7 == num8 && (num6 & 1) != 0) { num6 + 1 } else { num6 };
+
+            return (num2 | num9) as u16;
+        }
+
+        (num2 | (num3 - 0x38000000 + 0xFFF + ((num3 >> 13) & 1)) >> 13) as u16
+    }
+
+    pub fn convert_ieee_to_single(value: u16) -> f32 {
+        let value = value as u32;
+        let num1 = (value & 0x8000) << 16;
+        let num2 = (value >> 10) & 0x1F;
+        let num3 = value & 0x3FF;
+
+        let num4 = match num2 {
+            0x1F => 0x7F800000 | (num3 << 13),
+            0 if num3 == 0 => 0,
+            0 => {
+                let mut num5 = 113u32;
+                let mut num6 = num3;
+
+                while num6 & 0x400 == 0 {
+                    num5 -= 1;
+                    num6 <<= 1;
+                }
+
+                (num5 << 23) | ((num6 & 0x3FF) << 13)
+            }
+            _ => ((num2 + 112) << 23) | (num3 << 13),
+        };
+
+        f32::from_bits(num1 | num4)
+    }
+
+    pub fn convert_array(source_array: &[f32], destination_array: &mut [u16]) -> Result<(), Exception> {
+        if destination_array.len() < source_array.len() {
+            return Err(Exception::argument_exception("destination_array is shorter than source_array.", None));
+        }
+
+        for (source, destination) in source_array.iter().zip(destination_array.iter_mut()) {
+            *destination = Self::convert(*source);
+        }
+
+        Ok(())
+    }
+
+    pub fn convert_to_single_array(source_array: &[u16], destination_array: &mut [f32]) -> Result<(), Exception> {
+        if destination_array.len() < source_array.len() {
+            return Err(Exception::argument_exception("destination_array is shorter than source_array.", None));
+        }
+
+        for (source, destination) in source_array.iter().zip(destination_array.iter_mut()) {
+            *destination = Self::convert_to_single(*source);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(value: f32) -> (u16, f32) {
+        let half = HalfTypeHelper::convert(value);
+        (half, HalfTypeHelper::convert_to_single(half))
+    }
+
+    #[test]
+    fn convert_matches_xna_at_the_range_boundaries() {
+        assert_eq!(round_trip(65504.0), (0x7BFF, 65504.0));
+        assert_eq!(round_trip(65520.0), (0x7C00, 65536.0));
+        assert_eq!(round_trip(131008.0), (0x7FFF, 131008.0));
+        assert_eq!(round_trip(1.0e6), (0x7FFF, 131008.0));
+        assert_eq!(round_trip(-1.0e6), (0xFFFF, -131008.0));
+        assert_eq!(round_trip(f32::INFINITY), (0x7FFF, 131008.0));
+    }
+
+    #[test]
+    fn convert_handles_denormals() {
+        let smallest = f32::from_bits(0x33800000);
+
+        assert_eq!(round_trip(smallest), (0x0001, smallest));
+        assert_eq!(HalfTypeHelper::convert_to_single(0x8001), -smallest);
+        assert_eq!(round_trip(smallest / 4.0), (0x0000, 0.0));
+    }
+
+    #[test]
+    fn convert_to_single_decodes_exponent_31_as_finite() {
+        assert_eq!(HalfTypeHelper::convert_to_single(0x7C00), 65536.0);
+        assert_eq!(HalfTypeHelper::convert(65536.0), 0x7C00);
+
+        for bits in 0x7C00..=0x7FFFu16 {
+            let value = HalfTypeHelper::convert_to_single(bits);
+
+            assert!(value.is_finite());
+            assert_eq!(HalfTypeHelper::convert(value), bits);
+        }
+    }
+
+    #[test]
+    fn convert_ieee_produces_infinity_and_nan() {
+        assert_eq!(HalfTypeHelper::convert_ieee(65520.0), 0x7C00);
+        assert_eq!(HalfTypeHelper::convert_ieee_to_single(0x7C00), f32::INFINITY);
+        assert!(HalfTypeHelper::convert_ieee_to_single(HalfTypeHelper::convert_ieee(f32::NAN)).is_nan());
+        assert_eq!(HalfTypeHelper::convert_ieee(65504.0), 0x7BFF);
+    }
+}
@@ -0,0 +1,77 @@
+use crate::xna::csharp::Exception;
+use crate::xna::framework::Color;
+use crate::xna::framework::graphics::{ImageCodec, ImageFileFormat};
+
+impl ImageCodec {
+    pub const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+    pub fn detect_format(data: &[u8]) -> ImageFileFormat {
+        if data.starts_with(&Self::PNG_SIGNATURE) {
+            ImageFileFormat::Png
+        } else if data.starts_with(b"BM") {
+            ImageFileFormat::Bmp
+        } else if data.starts_with(b"DDS ") {
+            ImageFileFormat::Dds
+        } else {
+            ImageFileFormat::Tga
+        }
+    }
+
+    pub fn bytes(data: &[u8], offset: usize, count: usize) -> Result<&[u8], Exception> {
+        match offset.checked_add(count) {
+            Some(end) if end <= data.len() => Ok(&data[offset..end]),
+            _ => Err(Exception::new("Unexpected end of image data.", None)),
+        }
+    }
+
+    pub fn read_u8(data: &[u8], offset: usize) -> Result<u8, Exception> {
+        Ok(Self::bytes(data, offset, 1)?[0])
+    }
+
+    pub fn read_u16_le(data: &[u8], offset: usize) -> Result<u16, Exception> {
+        let bytes = Self::bytes(data, offset, 2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    pub fn read_u32_le(data: &[u8], offset: usize) -> Result<u32, Exception> {
+        let bytes = Self::bytes(data, offset, 4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    pub fn read_u32_be(data: &[u8], offset: usize) -> Result<u32, Exception> {
+        let bytes = Self::bytes(data, offset, 4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    pub fn rgba(r: u8, g: u8, b: u8, a: u8) -> Color {
+        Color::from_packed_value(r as u32 | (g as u32) << 8 | (b as u32) << 16 | (a as u32) << 24)
+    }
+
+    pub fn scale_bits(value: u32, bits: u32) -> u8 {
+        match bits {
+            0 => 0,
+            8 => value as u8,
+            bits if bits > 8 => (value >> (bits - 8)) as u8,
+            bits => ((value * 255 + ((1 << bits) - 1) / 2) / ((1 << bits) - 1)) as u8,
+        }
+    }
+
+    pub fn extract_mask(value: u32, mask: u32) -> Option<u8> {
+        if mask == 0 {
+            return None;
+        }
+
+        let shift = mask.trailing_zeros();
+        let bits = (mask >> shift).count_ones();
+
+        Some(Self::scale_bits((value & mask) >> shift, bits))
+    }
+
+    pub fn validate_dimensions(width: u32, height: u32) -> Result<(), Exception> {
+        if width == 0 || height == 0 || width > 16384 || height > 16384 {
+            return Err(Exception::new("The image dimensions are not supported.", None));
+        }
+
+        Ok(())
+    }
+}
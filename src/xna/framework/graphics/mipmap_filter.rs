@@ -0,0 +1,59 @@
+use std::f32::consts::PI;
+use crate::xna::framework::graphics::MipmapFilter;
+
+impl MipmapFilter {
+    const KAISER_WIDTH: f32 = 3.0;
+    const KAISER_ALPHA: f32 = 4.0;
+
+    pub fn support(&self) -> f32 {
+        match self {
+            MipmapFilter::Box => 0.5,
+            MipmapFilter::Triangle => 1.0,
+            MipmapFilter::Kaiser => Self::KAISER_WIDTH,
+        }
+    }
+
+    pub fn weight(&self, distance: f32) -> f32 {
+        let distance = distance.abs();
+
+        match self {
+            MipmapFilter::Box => if distance <= 0.5 { 1.0 } else { 0.0 },
+            MipmapFilter::Triangle => (1.0 - distance).max(0.0),
+            MipmapFilter::Kaiser => {
+                if distance >= Self::KAISER_WIDTH {
+                    return 0.0;
+                }
+
+                let ratio = distance / Self::KAISER_WIDTH;
+                let window = Self::bessel_i0(Self::KAISER_ALPHA * (1.0 - ratio * ratio).sqrt()) / Self::bessel_i0(Self::KAISER_ALPHA);
+
+                Self::sinc(distance) * window
+            }
+        }
+    }
+
+    fn sinc(value: f32) -> f32 {
+        if value.abs() < 1e-4 {
+            1.0
+        } else {
+            (PI * value).sin() / (PI * value)
+        }
+    }
+
+    fn bessel_i0(value: f32) -> f32 {
+        let mut sum = 1.0;
+        let mut term = 1.0;
+        let half = value / 2.0;
+
+        for index in 1..32 {
+            term *= half / index as f32;
+            sum += term * term;
+
+            if term * term < sum * 1e-8 {
+                break;
+            }
+        }
+
+        sum
+    }
+}
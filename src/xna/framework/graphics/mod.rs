@@ -1,14 +1,28 @@
 pub mod packed_vector;
+pub mod half_type_helper;
 pub mod graphics_adapter;
 pub mod blend_state;
+pub mod color_write_channels;
 pub mod depth_stencil_state;
 pub mod rasterizer_state;
 pub mod sampler_state;
+pub mod texel_buffer;
+pub mod mipmap_filter;
+pub mod texture_2d;
+pub mod texture_data;
+pub mod image_codec;
+pub mod png_codec;
+pub mod bmp_codec;
+pub mod tga_codec;
+pub mod dds_codec;
+pub mod dxt_codec;
 pub mod swap_chain;
+pub mod surface_format;
 pub mod graphics_device;
 pub mod display_mode_collection;
+pub mod software_renderer;
 
-use crate::xna::framework::{Color, Rectangle, Vector4};
+use crate::xna::framework::{Color, Rectangle, Vector3, Vector4};
 use crate::xna::framework::game::GraphicsProfile;
 #[cfg(target_os = "windows")]
 use crate::xna::platform::windows::WindowsGraphicsAdapter;
@@ -20,23 +34,122 @@ use crate::xna::platform::windows::WindowsGraphicsDevice;
 use crate::xna::platform::windows::WindowsPresentationParameters;
 #[cfg(target_os = "windows")]
 use crate::xna::platform::windows::WindowsRenderTarget2D;
+#[cfg(not(target_os = "windows"))]
+use crate::xna::platform::headless::HeadlessGraphicsDevice;
 
 pub trait IPackedVector {
     fn to_vector4(&self) -> Vector4;
+
+    fn from_vector4(vector: Vector4) -> Self where Self: Sized;
+
+    fn pack_from_vector4(&mut self, vector: Vector4) where Self: Sized {
+        *self = Self::from_vector4(vector);
+    }
+}
+
+pub trait ITextureData: Copy {
+    const SIZE: usize;
+
+    fn read_bytes(bytes: &[u8]) -> Self;
+
+    fn write_bytes(&self, bytes: &mut [u8]);
+}
+
+pub trait PackedVector<T>: IPackedVector {
+    fn packed_value(&self) -> T;
+
+    fn set_packed_value(&mut self, value: T);
 }
 
 pub struct PackUtils {}
 
-#[derive(Default)]
+pub struct HalfTypeHelper;
+
+#[derive(Default, Eq, PartialEq, Clone, Copy)]
 pub struct Alpha8 {
     packed_value: u8,
 }
 
-#[derive(Default)]
+#[derive(Default, Eq, PartialEq, Clone, Copy)]
 pub struct Bgr565 {
     packed_value: u16,
 }
 
+#[derive(Default, Eq, PartialEq, Clone, Copy)]
+pub struct Bgra4444 {
+    packed_value: u16,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy)]
+pub struct Bgra5551 {
+    packed_value: u16,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy)]
+pub struct Byte4 {
+    packed_value: u32,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy)]
+pub struct HalfSingle {
+    packed_value: u16,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy)]
+pub struct HalfVector2 {
+    packed_value: u32,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy)]
+pub struct HalfVector4 {
+    packed_value: u64,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy)]
+pub struct NormalizedByte2 {
+    packed_value: u16,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy)]
+pub struct NormalizedByte4 {
+    packed_value: u32,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy)]
+pub struct NormalizedShort2 {
+    packed_value: u32,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy)]
+pub struct NormalizedShort4 {
+    packed_value: u64,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy)]
+pub struct Rg32 {
+    packed_value: u32,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy)]
+pub struct Rgba1010102 {
+    packed_value: u32,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy)]
+pub struct Rgba64 {
+    packed_value: u64,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy)]
+pub struct Short2 {
+    packed_value: u32,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy)]
+pub struct Short4 {
+    packed_value: u64,
+}
+
 #[derive(Default, Eq, PartialEq, Clone)]
 pub struct GraphicsAdapterOutput {
     pub device_name: String,
@@ -97,14 +210,9 @@ pub enum BlendFunction {
     Max,
 }
 
-#[derive(Default, Eq, PartialEq, Copy, Clone)]
-pub enum ColorWriteChannels {
-    #[default]
-    Red,
-    Green,
-    Blue,
-    Alpha,
-    All,
+#[derive(Eq, PartialEq, Copy, Clone, Debug)]
+pub struct ColorWriteChannels {
+    bits: u8,
 }
 
 #[derive(Default, Eq, PartialEq, Copy, Clone)]
@@ -173,6 +281,7 @@ pub struct DepthStencilState {
     pub depth_write_mask: bool,
     pub front_face: DepthFace,
     pub back_face: DepthFace,
+    pub reference_stencil: i32,
 }
 
 #[derive(Default, Eq, PartialEq, Copy, Clone)]
@@ -242,6 +351,28 @@ pub struct SamplerState {
     pub comparison_function: ComparisonFunction
 }
 
+#[derive(Default, Eq, PartialEq, Copy, Clone, Debug)]
+pub enum MipmapFilter {
+    #[default]
+    Box,
+    Triangle,
+    Kaiser,
+}
+
+#[derive(Default, PartialEq, Copy, Clone, Debug)]
+pub struct MipmapOptions {
+    pub filter: MipmapFilter,
+    pub gamma_correct: bool,
+    pub alpha_coverage_reference: Option<f32>,
+}
+
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct TexelBuffer {
+    pub width: u32,
+    pub height: u32,
+    pub texels: Vec<Vector4>,
+}
+
 #[derive(Default, PartialEq, Clone)]
 pub struct SamplerStateCollection {
     pub samplers: Vec<SamplerState>
@@ -251,9 +382,56 @@ pub struct SamplerStateCollection {
 pub enum SurfaceFormat {
     #[default]
     Color,
+    Bgr565,
+    Bgra5551,
+    Bgra4444,
+    Dxt1,
+    Dxt3,
+    Dxt5,
+    NormalizedByte2,
+    NormalizedByte4,
+    Rgba1010102,
+    Rg32,
+    Rgba64,
+    Alpha8,
+    Single,
+    Vector2,
+    Vector4,
+    HalfSingle,
+    HalfVector2,
+    HalfVector4,
+    HdrBlendable,
+    Bgr32,
+    Bgra32,
+    ColorSRgb,
+    Bgr32SRgb,
+    Bgra32SRgb,
+    Dxt1SRgb,
+    Dxt3SRgb,
+    Dxt5SRgb,
     Unknown,
 }
 
+#[derive(Eq, PartialEq, Copy, Clone)]
+pub enum PackedVectorType {
+    Color,
+    Bgr565,
+    Bgra5551,
+    Bgra4444,
+    NormalizedByte2,
+    NormalizedByte4,
+    Rgba1010102,
+    Rg32,
+    Rgba64,
+    Alpha8,
+    Single,
+    Vector2,
+    Vector4,
+    HalfSingle,
+    HalfVector2,
+    HalfVector4,
+}
+
 #[derive(Default, Eq, PartialEq, Copy, Clone)]
 pub enum PresentInterval {
     #[default]
@@ -308,9 +486,38 @@ pub struct Viewport {
 
 #[derive(Default, PartialEq, Clone)]
 pub struct Texture2D {
-    pub width: u32,
-    pub height: u32,
-    pub format: SurfaceFormat,
+    width: u32,
+    height: u32,
+    format: SurfaceFormat,
+    levels: Vec<Vec<u8>>,
+}
+
+#[derive(Default, Eq, PartialEq, Copy, Clone, Debug)]
+pub enum ImageFileFormat {
+    #[default]
+    Png,
+    Bmp,
+    Tga,
+    Dds,
+}
+
+pub struct ImageCodec;
+
+pub struct PngCodec;
+
+pub struct BmpCodec;
+
+pub struct TgaCodec;
+
+pub struct DdsCodec;
+
+pub struct DxtCodec;
+
+#[derive(Default, Eq, PartialEq, Copy, Clone, Debug)]
+pub enum DxtCompressionQuality {
+    #[default]
+    Fast,
+    HighQuality,
 }
 
 #[derive(Default, PartialEq, Clone)]
@@ -405,10 +612,42 @@ pub struct GraphicsDevice {
     pub sampler_state_collection: SamplerStateCollection,
     pub presentation_parameters: PresentationParameters,
     pub viewport: Viewport,
+    pub scissor_rectangle: Rectangle,
     pub render_target: RenderTarget2D,
     pub swap_chain: SwapChain,
     pub graphics_profile: GraphicsProfile,
 
     #[cfg(target_os = "windows")]
-    pub platform: WindowsGraphicsDevice
+    pub platform: WindowsGraphicsDevice,
+    #[cfg(not(target_os = "windows"))]
+    pub platform: HeadlessGraphicsDevice
+}
+
+#[derive(Default, Eq, PartialEq, Copy, Clone)]
+pub enum PrimitiveType {
+    #[default]
+    TriangleList,
+    TriangleStrip,
+    LineList,
+    LineStrip,
+}
+
+#[derive(Default, PartialEq, Copy, Clone, Debug)]
+pub struct VertexPositionColor {
+    pub position: Vector3,
+    pub color: Color,
+}
+
+#[derive(Default, PartialEq, Clone)]
+pub struct SoftwareRenderer {
+    pub width: u32,
+    pub height: u32,
+    pub color_buffer: Vec<Color>,
+    pub depth_buffer: Vec<f32>,
+    pub stencil_buffer: Vec<u8>,
+    pub blend_state: BlendState,
+    pub depth_stencil_state: DepthStencilState,
+    pub rasterizer_state: RasterizerState,
+    pub viewport: Viewport,
+    pub scissor_rectangle: Rectangle,
 }
@@ -1,8 +1,28 @@
-use crate::xna::framework::{Vector3, Vector4};
-use crate::xna::framework::graphics::Alpha8;
-use crate::xna::framework::graphics::PackUtils;
-use crate::xna::framework::graphics::IPackedVector;
-use crate::xna::framework::graphics::Bgr565;
+use crate::xna::framework::{Vector2, Vector3, Vector4};
+use crate::xna::framework::graphics::{Alpha8, Bgr565, Bgra4444, Bgra5551, Byte4, HalfSingle, HalfVector2, HalfVector4, NormalizedByte2, NormalizedByte4, NormalizedShort2, NormalizedShort4, Rg32, Rgba1010102, Rgba64, Short2, Short4};
+use crate::xna::csharp::Exception;
+use crate::xna::framework::graphics::{HalfTypeHelper, PackUtils};
+use crate::xna::framework::graphics::{IPackedVector, PackedVector};
+
+macro_rules! impl_packed_vector {
+    ($vector:ident, $packed:ty) => {
+        impl PackedVector<$packed> for $vector {
+            fn packed_value(&self) -> $packed {
+                self.packed_value
+            }
+
+            fn set_packed_value(&mut self, value: $packed) {
+                self.packed_value = value;
+            }
+        }
+
+        impl $vector {
+            pub fn from_packed_value(packed_value: $packed) -> $vector {
+                $vector { packed_value }
+            }
+        }
+    };
+}
 
 impl PackUtils {
     pub fn clamp_and_round(value: f32, min: f32, max: f32) -> f64 {
@@ -26,33 +46,32 @@ impl PackUtils {
             return max as f64
         }
 
-        value.round() as f64
+        value.round_ties_even() as f64
     }
 
     pub fn unpack_snorm(bitmask: u32, value: u32) -> f32 {
-        let num1 = bitmask + 1u32 >> 1;
-        let value2: u32;
+        let num1 = (bitmask + 1u32) >> 1;
 
-        if((value as i32) & (num1 as i32)) != 0 {
+        let value2 = if ((value as i32) & (num1 as i32)) != 0 {
             if (value as i32) & (bitmask as i32) == num1 as i32 {
                 return -1.0;
             }
 
-            value2 = value | !bitmask;
+            value | !bitmask
         } else {
-            value2 = value & bitmask;
-        }
+            value & bitmask
+        };
 
         let num2 = (bitmask >> 1) as f32;
 
-        (value2 as f32) / num2
+        (value2 as i32 as f32) / num2
     }
 
     pub fn pack_snorm(bitmask: u32, value: f32) -> u32 {
         let max = (bitmask >> 1) as f32;
         let value2 = value * max;
 
-        (Self::clamp_and_round(value2, -max, max) as u32) & bitmask
+        (Self::clamp_and_round(value2, -max, max) as i32 as u32) & bitmask
     }
 
     pub fn unpack_unorm(bitmask: u32, value: u32) -> f32 {
@@ -69,12 +88,47 @@ impl PackUtils {
         let max = (bitmask >> 1) as f32;
         let min = -max - 1.0;
 
-        (Self::clamp_and_round(value, min, max) as u32) & bitmask
+        (Self::clamp_and_round(value, min, max) as i32 as u32) & bitmask
+    }
+
+    pub fn unpack_signed(bitmask: u32, value: u32) -> f32 {
+        let num = (bitmask >> 1) + 1;
+        let value2 = value & bitmask;
+
+        if value2 & num != 0 {
+            return (value2 | !bitmask) as i32 as f32;
+        }
+
+        value2 as f32
     }
 
     pub fn pack_unsigned(bitmask: f32, value: f32) -> u32 {
         Self::clamp_and_round(value, 0f32, bitmask) as u32
     }
+
+    pub fn convert<S: IPackedVector, D: IPackedVector>(source: &[S]) -> Vec<D> {
+        source.iter().map(|value| D::from_vector4(value.to_vector4())).collect()
+    }
+
+    pub fn convert_into<S: IPackedVector, D: IPackedVector>(source: &[S], destination: &mut [D]) -> Result<(), Exception> {
+        if destination.len() < source.len() {
+            return Err(Exception::argument_exception("destination is shorter than source.", None));
+        }
+
+        for (value, result) in source.iter().zip(destination.iter_mut()) {
+            *result = D::from_vector4(value.to_vector4());
+        }
+
+        Ok(())
+    }
+
+    pub fn pack_half(value: f32) -> u16 {
+        HalfTypeHelper::convert(value)
+    }
+
+    pub fn unpack_half(value: u16) -> f32 {
+        HalfTypeHelper::convert_to_single(value)
+    }
 }
 
 impl Alpha8 {
@@ -82,6 +136,10 @@ impl Alpha8 {
         let packed_value = PackUtils::pack_unorm(u8::MAX as f32, alpha) as u8;
         Alpha8 { packed_value }
     }
+
+    pub fn to_alpha(self) -> f32 {
+        PackUtils::unpack_unorm(u8::MAX as u32, self.packed_value as u32)
+    }
 }
 
 impl IPackedVector for Alpha8 {
@@ -95,8 +153,14 @@ impl IPackedVector for Alpha8 {
             w: alpha,
         }
     }
+
+    fn from_vector4(vector: Vector4) -> Self {
+        Self::from_alpha(vector.w)
+    }
 }
 
+impl_packed_vector!(Alpha8, u8);
+
 impl Bgr565 {
     pub fn from_xyz(x: f32, y: f32, z: f32) -> Bgr565 {
         let packed_value = (PackUtils::pack_unorm(31.0, x) << 11
@@ -114,7 +178,7 @@ impl Bgr565 {
         Bgr565{ packed_value }
     }
 
-    pub fn to_vector3(&self) -> Vector3 {
+    pub fn to_vector3(self) -> Vector3 {
         let x = PackUtils::unpack_unorm(31, self.packed_value as u32 >> 11);
         let y= PackUtils::unpack_unorm(63, self.packed_value as u32 >> 5);
         let z= PackUtils::unpack_unorm(31, self.packed_value as u32);
@@ -134,4 +198,467 @@ impl IPackedVector for Bgr565 {
             w: 1.0,
         }
     }
+
+    fn from_vector4(vector: Vector4) -> Self {
+        Self::from_xyz(vector.x, vector.y, vector.z)
+    }
+}
+
+impl_packed_vector!(Bgr565, u16);
+
+impl Bgra4444 {
+    pub fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> Bgra4444 {
+        let packed_value = (PackUtils::pack_unorm(15.0, x) << 8
+            | PackUtils::pack_unorm(15.0, y) << 4
+            | PackUtils::pack_unorm(15.0, z)
+            | PackUtils::pack_unorm(15.0, w) << 12) as u16;
+
+        Bgra4444 { packed_value }
+    }
+}
+
+impl IPackedVector for Bgra4444 {
+    fn to_vector4(&self) -> Vector4 {
+        let value = self.packed_value as u32;
+
+        Vector4 {
+            x: PackUtils::unpack_unorm(15, value >> 8),
+            y: PackUtils::unpack_unorm(15, value >> 4),
+            z: PackUtils::unpack_unorm(15, value),
+            w: PackUtils::unpack_unorm(15, value >> 12),
+        }
+    }
+
+    fn from_vector4(vector: Vector4) -> Self {
+        Self::from_xyzw(vector.x, vector.y, vector.z, vector.w)
+    }
 }
+
+impl_packed_vector!(Bgra4444, u16);
+
+impl Bgra5551 {
+    pub fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> Bgra5551 {
+        let packed_value = (PackUtils::pack_unorm(31.0, x) << 10
+            | PackUtils::pack_unorm(31.0, y) << 5
+            | PackUtils::pack_unorm(31.0, z)
+            | PackUtils::pack_unorm(1.0, w) << 15) as u16;
+
+        Bgra5551 { packed_value }
+    }
+}
+
+impl IPackedVector for Bgra5551 {
+    fn to_vector4(&self) -> Vector4 {
+        let value = self.packed_value as u32;
+
+        Vector4 {
+            x: PackUtils::unpack_unorm(31, value >> 10),
+            y: PackUtils::unpack_unorm(31, value >> 5),
+            z: PackUtils::unpack_unorm(31, value),
+            w: PackUtils::unpack_unorm(1, value >> 15),
+        }
+    }
+
+    fn from_vector4(vector: Vector4) -> Self {
+        Self::from_xyzw(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl_packed_vector!(Bgra5551, u16);
+
+impl Byte4 {
+    pub fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> Byte4 {
+        let packed_value = PackUtils::pack_unsigned(255.0, x)
+            | PackUtils::pack_unsigned(255.0, y) << 8
+            | PackUtils::pack_unsigned(255.0, z) << 16
+            | PackUtils::pack_unsigned(255.0, w) << 24;
+
+        Byte4 { packed_value }
+    }
+}
+
+impl IPackedVector for Byte4 {
+    fn to_vector4(&self) -> Vector4 {
+        let value = self.packed_value;
+
+        Vector4 {
+            x: (value & 0xFF) as f32,
+            y: (value >> 8 & 0xFF) as f32,
+            z: (value >> 16 & 0xFF) as f32,
+            w: (value >> 24 & 0xFF) as f32,
+        }
+    }
+
+    fn from_vector4(vector: Vector4) -> Self {
+        Self::from_xyzw(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl_packed_vector!(Byte4, u32);
+
+impl HalfSingle {
+    pub fn from_single(value: f32) -> HalfSingle {
+        HalfSingle { packed_value: PackUtils::pack_half(value) }
+    }
+
+    pub fn to_single(self) -> f32 {
+        PackUtils::unpack_half(self.packed_value)
+    }
+}
+
+impl IPackedVector for HalfSingle {
+    fn to_vector4(&self) -> Vector4 {
+        Vector4 { x: self.to_single(), y: 0.0, z: 0.0, w: 1.0 }
+    }
+
+    fn from_vector4(vector: Vector4) -> Self {
+        Self::from_single(vector.x)
+    }
+}
+
+impl_packed_vector!(HalfSingle, u16);
+
+impl HalfVector2 {
+    pub fn from_xy(x: f32, y: f32) -> HalfVector2 {
+        let packed_value = PackUtils::pack_half(x) as u32
+            | (PackUtils::pack_half(y) as u32) << 16;
+
+        HalfVector2 { packed_value }
+    }
+
+    pub fn from_vector2(vector: Vector2) -> HalfVector2 {
+        Self::from_xy(vector.x, vector.y)
+    }
+
+    pub fn to_vector2(self) -> Vector2 {
+        Vector2 {
+            x: PackUtils::unpack_half(self.packed_value as u16),
+            y: PackUtils::unpack_half((self.packed_value >> 16) as u16),
+        }
+    }
+}
+
+impl IPackedVector for HalfVector2 {
+    fn to_vector4(&self) -> Vector4 {
+        let vector = self.to_vector2();
+        Vector4 { x: vector.x, y: vector.y, z: 0.0, w: 1.0 }
+    }
+
+    fn from_vector4(vector: Vector4) -> Self {
+        Self::from_xy(vector.x, vector.y)
+    }
+}
+
+impl_packed_vector!(HalfVector2, u32);
+
+impl HalfVector4 {
+    pub fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> HalfVector4 {
+        let packed_value = PackUtils::pack_half(x) as u64
+            | (PackUtils::pack_half(y) as u64) << 16
+            | (PackUtils::pack_half(z) as u64) << 32
+            | (PackUtils::pack_half(w) as u64) << 48;
+
+        HalfVector4 { packed_value }
+    }
+}
+
+impl IPackedVector for HalfVector4 {
+    fn to_vector4(&self) -> Vector4 {
+        Vector4 {
+            x: PackUtils::unpack_half(self.packed_value as u16),
+            y: PackUtils::unpack_half((self.packed_value >> 16) as u16),
+            z: PackUtils::unpack_half((self.packed_value >> 32) as u16),
+            w: PackUtils::unpack_half((self.packed_value >> 48) as u16),
+        }
+    }
+
+    fn from_vector4(vector: Vector4) -> Self {
+        Self::from_xyzw(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl_packed_vector!(HalfVector4, u64);
+
+impl NormalizedByte2 {
+    pub fn from_xy(x: f32, y: f32) -> NormalizedByte2 {
+        let packed_value = (PackUtils::pack_snorm(0xFF, x)
+            | PackUtils::pack_snorm(0xFF, y) << 8) as u16;
+
+        NormalizedByte2 { packed_value }
+    }
+
+    pub fn from_vector2(vector: Vector2) -> NormalizedByte2 {
+        Self::from_xy(vector.x, vector.y)
+    }
+
+    pub fn to_vector2(self) -> Vector2 {
+        let value = self.packed_value as u32;
+
+        Vector2 {
+            x: PackUtils::unpack_snorm(0xFF, value),
+            y: PackUtils::unpack_snorm(0xFF, value >> 8),
+        }
+    }
+}
+
+impl IPackedVector for NormalizedByte2 {
+    fn to_vector4(&self) -> Vector4 {
+        let vector = self.to_vector2();
+        Vector4 { x: vector.x, y: vector.y, z: 0.0, w: 1.0 }
+    }
+
+    fn from_vector4(vector: Vector4) -> Self {
+        Self::from_xy(vector.x, vector.y)
+    }
+}
+
+impl_packed_vector!(NormalizedByte2, u16);
+
+impl NormalizedByte4 {
+    pub fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> NormalizedByte4 {
+        let packed_value = PackUtils::pack_snorm(0xFF, x)
+            | PackUtils::pack_snorm(0xFF, y) << 8
+            | PackUtils::pack_snorm(0xFF, z) << 16
+            | PackUtils::pack_snorm(0xFF, w) << 24;
+
+        NormalizedByte4 { packed_value }
+    }
+}
+
+impl IPackedVector for NormalizedByte4 {
+    fn to_vector4(&self) -> Vector4 {
+        let value = self.packed_value;
+
+        Vector4 {
+            x: PackUtils::unpack_snorm(0xFF, value),
+            y: PackUtils::unpack_snorm(0xFF, value >> 8),
+            z: PackUtils::unpack_snorm(0xFF, value >> 16),
+            w: PackUtils::unpack_snorm(0xFF, value >> 24),
+        }
+    }
+
+    fn from_vector4(vector: Vector4) -> Self {
+        Self::from_xyzw(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl_packed_vector!(NormalizedByte4, u32);
+
+impl NormalizedShort2 {
+    pub fn from_xy(x: f32, y: f32) -> NormalizedShort2 {
+        let packed_value = PackUtils::pack_snorm(0xFFFF, x)
+            | PackUtils::pack_snorm(0xFFFF, y) << 16;
+
+        NormalizedShort2 { packed_value }
+    }
+
+    pub fn from_vector2(vector: Vector2) -> NormalizedShort2 {
+        Self::from_xy(vector.x, vector.y)
+    }
+
+    pub fn to_vector2(self) -> Vector2 {
+        Vector2 {
+            x: PackUtils::unpack_snorm(0xFFFF, self.packed_value),
+            y: PackUtils::unpack_snorm(0xFFFF, self.packed_value >> 16),
+        }
+    }
+}
+
+impl IPackedVector for NormalizedShort2 {
+    fn to_vector4(&self) -> Vector4 {
+        let vector = self.to_vector2();
+        Vector4 { x: vector.x, y: vector.y, z: 0.0, w: 1.0 }
+    }
+
+    fn from_vector4(vector: Vector4) -> Self {
+        Self::from_xy(vector.x, vector.y)
+    }
+}
+
+impl_packed_vector!(NormalizedShort2, u32);
+
+impl NormalizedShort4 {
+    pub fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> NormalizedShort4 {
+        let packed_value = PackUtils::pack_snorm(0xFFFF, x) as u64
+            | (PackUtils::pack_snorm(0xFFFF, y) as u64) << 16
+            | (PackUtils::pack_snorm(0xFFFF, z) as u64) << 32
+            | (PackUtils::pack_snorm(0xFFFF, w) as u64) << 48;
+
+        NormalizedShort4 { packed_value }
+    }
+}
+
+impl IPackedVector for NormalizedShort4 {
+    fn to_vector4(&self) -> Vector4 {
+        let value = self.packed_value;
+
+        Vector4 {
+            x: PackUtils::unpack_snorm(0xFFFF, value as u32),
+            y: PackUtils::unpack_snorm(0xFFFF, (value >> 16) as u32),
+            z: PackUtils::unpack_snorm(0xFFFF, (value >> 32) as u32),
+            w: PackUtils::unpack_snorm(0xFFFF, (value >> 48) as u32),
+        }
+    }
+
+    fn from_vector4(vector: Vector4) -> Self {
+        Self::from_xyzw(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl_packed_vector!(NormalizedShort4, u64);
+
+impl Rg32 {
+    pub fn from_xy(x: f32, y: f32) -> Rg32 {
+        let packed_value = PackUtils::pack_unorm(65535.0, x)
+            | PackUtils::pack_unorm(65535.0, y) << 16;
+
+        Rg32 { packed_value }
+    }
+
+    pub fn from_vector2(vector: Vector2) -> Rg32 {
+        Self::from_xy(vector.x, vector.y)
+    }
+
+    pub fn to_vector2(self) -> Vector2 {
+        Vector2 {
+            x: PackUtils::unpack_unorm(0xFFFF, self.packed_value),
+            y: PackUtils::unpack_unorm(0xFFFF, self.packed_value >> 16),
+        }
+    }
+}
+
+impl IPackedVector for Rg32 {
+    fn to_vector4(&self) -> Vector4 {
+        let vector = self.to_vector2();
+        Vector4 { x: vector.x, y: vector.y, z: 0.0, w: 1.0 }
+    }
+
+    fn from_vector4(vector: Vector4) -> Self {
+        Self::from_xy(vector.x, vector.y)
+    }
+}
+
+impl_packed_vector!(Rg32, u32);
+
+impl Rgba1010102 {
+    pub fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> Rgba1010102 {
+        let packed_value = PackUtils::pack_unorm(1023.0, x)
+            | PackUtils::pack_unorm(1023.0, y) << 10
+            | PackUtils::pack_unorm(1023.0, z) << 20
+            | PackUtils::pack_unorm(3.0, w) << 30;
+
+        Rgba1010102 { packed_value }
+    }
+}
+
+impl IPackedVector for Rgba1010102 {
+    fn to_vector4(&self) -> Vector4 {
+        let value = self.packed_value;
+
+        Vector4 {
+            x: PackUtils::unpack_unorm(0x3FF, value),
+            y: PackUtils::unpack_unorm(0x3FF, value >> 10),
+            z: PackUtils::unpack_unorm(0x3FF, value >> 20),
+            w: PackUtils::unpack_unorm(0x3, value >> 30),
+        }
+    }
+
+    fn from_vector4(vector: Vector4) -> Self {
+        Self::from_xyzw(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl_packed_vector!(Rgba1010102, u32);
+
+impl Rgba64 {
+    pub fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> Rgba64 {
+        let packed_value = PackUtils::pack_unorm(65535.0, x) as u64
+            | (PackUtils::pack_unorm(65535.0, y) as u64) << 16
+            | (PackUtils::pack_unorm(65535.0, z) as u64) << 32
+            | (PackUtils::pack_unorm(65535.0, w) as u64) << 48;
+
+        Rgba64 { packed_value }
+    }
+}
+
+impl IPackedVector for Rgba64 {
+    fn to_vector4(&self) -> Vector4 {
+        let value = self.packed_value;
+
+        Vector4 {
+            x: PackUtils::unpack_unorm(0xFFFF, value as u32),
+            y: PackUtils::unpack_unorm(0xFFFF, (value >> 16) as u32),
+            z: PackUtils::unpack_unorm(0xFFFF, (value >> 32) as u32),
+            w: PackUtils::unpack_unorm(0xFFFF, (value >> 48) as u32),
+        }
+    }
+
+    fn from_vector4(vector: Vector4) -> Self {
+        Self::from_xyzw(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl_packed_vector!(Rgba64, u64);
+
+impl Short2 {
+    pub fn from_xy(x: f32, y: f32) -> Short2 {
+        let packed_value = PackUtils::pack_signed(0xFFFF, x)
+            | PackUtils::pack_signed(0xFFFF, y) << 16;
+
+        Short2 { packed_value }
+    }
+
+    pub fn from_vector2(vector: Vector2) -> Short2 {
+        Self::from_xy(vector.x, vector.y)
+    }
+
+    pub fn to_vector2(self) -> Vector2 {
+        Vector2 {
+            x: PackUtils::unpack_signed(0xFFFF, self.packed_value),
+            y: PackUtils::unpack_signed(0xFFFF, self.packed_value >> 16),
+        }
+    }
+}
+
+impl IPackedVector for Short2 {
+    fn to_vector4(&self) -> Vector4 {
+        let vector = self.to_vector2();
+        Vector4 { x: vector.x, y: vector.y, z: 0.0, w: 1.0 }
+    }
+
+    fn from_vector4(vector: Vector4) -> Self {
+        Self::from_xy(vector.x, vector.y)
+    }
+}
+
+impl_packed_vector!(Short2, u32);
+
+impl Short4 {
+    pub fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> Short4 {
+        let packed_value = PackUtils::pack_signed(0xFFFF, x) as u64
+            | (PackUtils::pack_signed(0xFFFF, y) as u64) << 16
+            | (PackUtils::pack_signed(0xFFFF, z) as u64) << 32
+            | (PackUtils::pack_signed(0xFFFF, w) as u64) << 48;
+
+        Short4 { packed_value }
+    }
+}
+
+impl IPackedVector for Short4 {
+    fn to_vector4(&self) -> Vector4 {
+        let value = self.packed_value;
+
+        Vector4 {
+            x: PackUtils::unpack_signed(0xFFFF, value as u32),
+            y: PackUtils::unpack_signed(0xFFFF, (value >> 16) as u32),
+            z: PackUtils::unpack_signed(0xFFFF, (value >> 32) as u32),
+            w: PackUtils::unpack_signed(0xFFFF, (value >> 48) as u32),
+        }
+    }
+
+    fn from_vector4(vector: Vector4) -> Self {
+        Self::from_xyzw(vector.x, vector.y, vector.z, vector.w)
+    }
+}
+
+impl_packed_vector!(Short4, u64);
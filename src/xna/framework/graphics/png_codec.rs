@@ -0,0 +1,371 @@
+use crate::xna::csharp::Exception;
+use crate::xna::csharp::io::{Crc32, ZLib};
+use crate::xna::framework::Color;
+use crate::xna::framework::graphics::{ImageCodec, PngCodec, Texture2D};
+
+const ADAM7: [(usize, usize, usize, usize); 7] = [(0, 0, 8, 8), (4, 0, 8, 8), (0, 4, 4, 8), (2, 0, 4, 4), (0, 2, 2, 4), (1, 0, 2, 2), (0, 1, 1, 2)];
+
+struct PngHeader {
+    width: usize,
+    height: usize,
+    bit_depth: u8,
+    color_type: u8,
+    interlace: bool,
+}
+
+impl PngHeader {
+    fn channels(&self) -> usize {
+        match self.color_type {
+            2 => 3,
+            4 => 2,
+            6 => 4,
+            _ => 1,
+        }
+    }
+
+    fn bits_per_pixel(&self) -> usize {
+        self.channels() * self.bit_depth as usize
+    }
+
+    fn row_size(&self, width: usize) -> usize {
+        (width * self.bits_per_pixel()).div_ceil(8)
+    }
+}
+
+impl PngCodec {
+    pub fn decode(data: &[u8]) -> Result<Texture2D, Exception> {
+        if !data.starts_with(&ImageCodec::PNG_SIGNATURE) {
+            return Err(Exception::new("The data is not a PNG image.", None));
+        }
+
+        let mut offset = ImageCodec::PNG_SIGNATURE.len();
+        let mut header = None;
+        let mut palette: Vec<[u8; 3]> = Vec::new();
+        let mut transparency: Vec<u8> = Vec::new();
+        let mut compressed = Vec::new();
+
+        loop {
+            let length = ImageCodec::read_u32_be(data, offset)? as usize;
+            let chunk_type = ImageCodec::bytes(data, offset + 4, 4)?;
+            let chunk = ImageCodec::bytes(data, offset + 8, length)?;
+            let crc = ImageCodec::read_u32_be(data, offset + 8 + length)?;
+
+            if Crc32::compute(ImageCodec::bytes(data, offset + 4, length + 4)?) != crc {
+                return Err(Exception::new("PNG chunk checksum mismatch.", None));
+            }
+
+            match chunk_type {
+                b"IHDR" => header = Some(Self::read_header(chunk)?),
+                b"PLTE" => palette = chunk.chunks_exact(3).map(|entry| [entry[0], entry[1], entry[2]]).collect(),
+                b"tRNS" => transparency = chunk.to_vec(),
+                b"IDAT" => compressed.extend_from_slice(chunk),
+                b"IEND" => break,
+                _ => {
+                    if chunk_type[0] & 0x20 == 0 {
+                        return Err(Exception::new("The PNG image contains an unsupported critical chunk.", None));
+                    }
+                }
+            }
+
+            offset += 12 + length;
+        }
+
+        let header = header.ok_or_else(|| Exception::new("The PNG image has no IHDR chunk.", None))?;
+
+        if header.color_type == 3 && palette.is_empty() {
+            return Err(Exception::new("The PNG image has no palette.", None));
+        }
+
+        let passes: Vec<(usize, usize, usize, usize)> = if header.interlace { ADAM7.to_vec() } else { vec![(0, 0, 1, 1)] };
+        let pass_size = |start_x: usize, start_y: usize, step_x: usize, step_y: usize| {
+            let pass_width = (header.width + step_x - 1 - start_x.min(header.width)) / step_x;
+            let pass_height = (header.height + step_y - 1 - start_y.min(header.height)) / step_y;
+
+            (pass_width, pass_height)
+        };
+
+        let expected_length = passes
+            .iter()
+            .map(|&(start_x, start_y, step_x, step_y)| match pass_size(start_x, start_y, step_x, step_y) {
+                (0, _) | (_, 0) => 0,
+                (pass_width, pass_height) => (header.row_size(pass_width) + 1) * pass_height,
+            })
+            .sum();
+
+        let raw = ZLib::decompress(&compressed, expected_length)?;
+        let mut pixels = vec![Color::transparent(); header.width * header.height];
+        let mut position = 0;
+
+        for (start_x, start_y, step_x, step_y) in passes {
+            let (pass_width, pass_height) = pass_size(start_x, start_y, step_x, step_y);
+
+            if pass_width == 0 || pass_height == 0 {
+                continue;
+            }
+
+            let row_size = header.row_size(pass_width);
+            let length = (row_size + 1) * pass_height;
+            let rows = Self::unfilter(&header, ImageCodec::bytes(&raw, position, length)?, row_size, pass_height)?;
+            position += length;
+
+            for y in 0..pass_height {
+                let row = &rows[y * row_size..(y + 1) * row_size];
+
+                for x in 0..pass_width {
+                    let target = (start_y + y * step_y) * header.width + start_x + x * step_x;
+                    pixels[target] = Self::read_pixel(&header, row, x, &palette, &transparency);
+                }
+            }
+        }
+
+        let mut texture = Texture2D::new(header.width as u32, header.height as u32)?;
+        texture.set_data(None, None, &pixels)?;
+
+        Ok(texture)
+    }
+
+    pub fn encode(width: u32, height: u32, pixels: &[Color]) -> Result<Vec<u8>, Exception> {
+        ImageCodec::validate_dimensions(width, height)?;
+
+        let width = width as usize;
+        let height = height as usize;
+
+        if pixels.len() < width * height {
+            return Err(Exception::argument_exception("pixels is smaller than the image dimensions.", None));
+        }
+
+        let row_size = width * 4;
+        let mut raw = Vec::with_capacity((row_size + 1) * height);
+        let mut previous = vec![0u8; row_size];
+        let mut current = vec![0u8; row_size];
+
+        for y in 0..height {
+            for x in 0..width {
+                let color = pixels[y * width + x];
+                current[x * 4..x * 4 + 4].copy_from_slice(&[color.r(), color.g(), color.b(), color.a()]);
+            }
+
+            let (filter, filtered) = Self::filter_row(&current, &previous, 4);
+            raw.push(filter);
+            raw.extend_from_slice(&filtered);
+
+            std::mem::swap(&mut previous, &mut current);
+        }
+
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&(width as u32).to_be_bytes());
+        header.extend_from_slice(&(height as u32).to_be_bytes());
+        header.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+        let mut result = ImageCodec::PNG_SIGNATURE.to_vec();
+        Self::write_chunk(&mut result, b"IHDR", &header);
+        Self::write_chunk(&mut result, b"IDAT", &ZLib::compress(&raw));
+        Self::write_chunk(&mut result, b"IEND", &[]);
+
+        Ok(result)
+    }
+
+    fn read_header(chunk: &[u8]) -> Result<PngHeader, Exception> {
+        let header = PngHeader {
+            width: ImageCodec::read_u32_be(chunk, 0)? as usize,
+            height: ImageCodec::read_u32_be(chunk, 4)? as usize,
+            bit_depth: ImageCodec::read_u8(chunk, 8)?,
+            color_type: ImageCodec::read_u8(chunk, 9)?,
+            interlace: ImageCodec::read_u8(chunk, 12)? == 1,
+        };
+
+        ImageCodec::validate_dimensions(header.width as u32, header.height as u32)?;
+
+        let is_valid = match header.color_type {
+            0 => matches!(header.bit_depth, 1 | 2 | 4 | 8 | 16),
+            3 => matches!(header.bit_depth, 1 | 2 | 4 | 8),
+            2 | 4 | 6 => matches!(header.bit_depth, 8 | 16),
+            _ => false,
+        };
+
+        if !is_valid || chunk[10] != 0 || chunk[11] != 0 {
+            return Err(Exception::new("The PNG image uses an unsupported format.", None));
+        }
+
+        Ok(header)
+    }
+
+    fn unfilter(header: &PngHeader, data: &[u8], row_size: usize, row_count: usize) -> Result<Vec<u8>, Exception> {
+        let pixel_size = header.bits_per_pixel().div_ceil(8);
+        let mut result = vec![0u8; row_size * row_count];
+
+        for y in 0..row_count {
+            let filter = data[y * (row_size + 1)];
+            let source = &data[y * (row_size + 1) + 1..(y + 1) * (row_size + 1)];
+            let (previous_rows, current_rows) = result.split_at_mut(y * row_size);
+            let previous = if y > 0 { &previous_rows[(y - 1) * row_size..] } else { &[][..] };
+            let current = &mut current_rows[..row_size];
+
+            for x in 0..row_size {
+                let left = if x >= pixel_size { current[x - pixel_size] } else { 0 };
+                let up = if y > 0 { previous[x] } else { 0 };
+                let up_left = if y > 0 && x >= pixel_size { previous[x - pixel_size] } else { 0 };
+
+                let predictor = match filter {
+                    0 => 0,
+                    1 => left,
+                    2 => up,
+                    3 => ((left as u16 + up as u16) / 2) as u8,
+                    4 => Self::paeth(left, up, up_left),
+                    _ => return Err(Exception::new("Invalid PNG filter type.", None)),
+                };
+
+                current[x] = source[x].wrapping_add(predictor);
+            }
+        }
+
+        Ok(result)
+    }
+
+    fn filter_row(current: &[u8], previous: &[u8], pixel_size: usize) -> (u8, Vec<u8>) {
+        let mut best = (0u8, Vec::new());
+        let mut best_score = u64::MAX;
+
+        for filter in 0..5u8 {
+            let filtered: Vec<u8> = (0..current.len())
+                .map(|x| {
+                    let left = if x >= pixel_size { current[x - pixel_size] } else { 0 };
+                    let up = previous[x];
+                    let up_left = if x >= pixel_size { previous[x - pixel_size] } else { 0 };
+
+                    let predictor = match filter {
+                        0 => 0,
+                        1 => left,
+                        2 => up,
+                        3 => ((left as u16 + up as u16) / 2) as u8,
+                        _ => Self::paeth(left, up, up_left),
+                    };
+
+                    current[x].wrapping_sub(predictor)
+                })
+                .collect();
+
+            let score = filtered.iter().map(|value| (*value as i8).unsigned_abs() as u64).sum();
+
+            if score < best_score {
+                best_score = score;
+                best = (filter, filtered);
+            }
+        }
+
+        best
+    }
+
+    fn paeth(left: u8, up: u8, up_left: u8) -> u8 {
+        let estimate = left as i16 + up as i16 - up_left as i16;
+        let distance_left = (estimate - left as i16).abs();
+        let distance_up = (estimate - up as i16).abs();
+        let distance_up_left = (estimate - up_left as i16).abs();
+
+        if distance_left <= distance_up && distance_left <= distance_up_left {
+            left
+        } else if distance_up <= distance_up_left {
+            up
+        } else {
+            up_left
+        }
+    }
+
+    fn read_pixel(header: &PngHeader, row: &[u8], x: usize, palette: &[[u8; 3]], transparency: &[u8]) -> Color {
+        let depth = header.bit_depth as usize;
+        let channels = header.channels();
+
+        let sample = |channel: usize| -> u32 {
+            let bit = (x * channels + channel) * depth;
+
+            match depth {
+                16 => u16::from_be_bytes([row[bit / 8], row[bit / 8 + 1]]) as u32,
+                8 => row[bit / 8] as u32,
+                _ => ((row[bit / 8] >> (8 - depth - bit % 8)) & ((1 << depth) - 1) as u8) as u32,
+            }
+        };
+
+        let scale = |value: u32| ImageCodec::scale_bits(value, depth as u32);
+        let transparent_key = |channel: usize| -> Option<u32> {
+            ImageCodec::bytes(transparency, channel * 2, 2).ok().map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]) as u32)
+        };
+
+        match header.color_type {
+            0 => {
+                let value = sample(0);
+                let gray = scale(value);
+                let alpha = if transparent_key(0) == Some(value) { 0 } else { 255 };
+
+                ImageCodec::rgba(gray, gray, gray, alpha)
+            }
+            2 => {
+                let (red, green, blue) = (sample(0), sample(1), sample(2));
+                let is_transparent = transparent_key(0) == Some(red) && transparent_key(1) == Some(green) && transparent_key(2) == Some(blue);
+
+                ImageCodec::rgba(scale(red), scale(green), scale(blue), if is_transparent { 0 } else { 255 })
+            }
+            3 => {
+                let index = sample(0) as usize;
+                let entry = palette.get(index).copied().unwrap_or([0, 0, 0]);
+                let alpha = transparency.get(index).copied().unwrap_or(255);
+
+                ImageCodec::rgba(entry[0], entry[1], entry[2], alpha)
+            }
+            4 => {
+                let gray = scale(sample(0));
+                ImageCodec::rgba(gray, gray, gray, scale(sample(1)))
+            }
+            _ => ImageCodec::rgba(scale(sample(0)), scale(sample(1)), scale(sample(2)), scale(sample(3))),
+        }
+    }
+
+    fn write_chunk(output: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+        output.extend_from_slice(&(data.len() as u32).to_be_bytes());
+
+        let start = output.len();
+        output.extend_from_slice(chunk_type);
+        output.extend_from_slice(data);
+
+        let crc = Crc32::compute(&output[start..]);
+        output.extend_from_slice(&crc.to_be_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(width: u32, height: u32) -> Vec<Color> {
+        (0..width * height)
+            .map(|index| ImageCodec::rgba((index * 7) as u8, (index * 13 + 5) as u8, (index * 31) as u8, 255u8.wrapping_sub((index * 3) as u8)))
+            .collect()
+    }
+
+    #[test]
+    fn encode_and_decode_round_trip() {
+        for (width, height) in [(1, 1), (7, 5), (64, 3)] {
+            let pixels = pattern(width, height);
+            let texture = PngCodec::decode(&PngCodec::encode(width, height, &pixels).unwrap()).unwrap();
+            let mut decoded = vec![Color::transparent(); pixels.len()];
+            texture.get_data(None, None, &mut decoded).unwrap();
+
+            assert_eq!((texture.width, texture.height), (width, height));
+            assert_eq!(decoded, pixels);
+        }
+    }
+
+    #[test]
+    fn decode_rejects_image_data_longer_than_the_image() {
+        let mut header = Vec::new();
+        header.extend_from_slice(&1u32.to_be_bytes());
+        header.extend_from_slice(&1u32.to_be_bytes());
+        header.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+        let mut data = ImageCodec::PNG_SIGNATURE.to_vec();
+        PngCodec::write_chunk(&mut data, b"IHDR", &header);
+        PngCodec::write_chunk(&mut data, b"IDAT", &ZLib::compress(&vec![0u8; 1 << 20]));
+        PngCodec::write_chunk(&mut data, b"IEND", &[]);
+
+        assert!(PngCodec::decode(&data).is_err());
+    }
+}
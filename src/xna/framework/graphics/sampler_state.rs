@@ -1,14 +1,29 @@
-use std::iter::FilterMap;
-use crate::xna::framework::graphics::{SamplerState, TextureAddressMode, TextureFilter};
+use crate::xna::framework::{Color, Vector2, Vector4};
+use crate::xna::framework::graphics::{ComparisonFunction, IPackedVector, SamplerState, TexelBuffer, TextureAddressMode, TextureFilter};
 
 impl SamplerState {
+    pub fn new() -> Self {
+        SamplerState {
+            max_anisotropy: 4,
+            filter: TextureFilter::Linear,
+            address_u: TextureAddressMode::Wrap,
+            address_v: TextureAddressMode::Wrap,
+            address_w: TextureAddressMode::Wrap,
+            mip_map_level_of_detail_bias: 0.0,
+            max_mip_level: f32::MAX,
+            min_mip_level: 0.0,
+            border_color: Color::transparent(),
+            comparison_function: ComparisonFunction::Never,
+        }
+    }
+
     pub fn point_wrap() -> SamplerState {
         SamplerState {
             filter: TextureFilter::Point,
             address_u: TextureAddressMode::Wrap,
             address_v: TextureAddressMode::Wrap,
             address_w: TextureAddressMode::Wrap,
-            ..Default::default()
+            ..Self::new()
         }
     }
 
@@ -18,47 +33,277 @@ impl SamplerState {
             address_u: TextureAddressMode::Clamp,
             address_v: TextureAddressMode::Clamp,
             address_w: TextureAddressMode::Clamp,
-            ..Default::default()
+            ..Self::new()
         }
     }
 
     pub fn linear_wrap() -> SamplerState {
+        SamplerState {
+            filter: TextureFilter::Linear,
+            address_u: TextureAddressMode::Wrap,
+            address_v: TextureAddressMode::Wrap,
+            address_w: TextureAddressMode::Wrap,
+            ..Self::new()
+        }
+    }
+
+    pub fn linear_clamp() -> SamplerState {
         SamplerState {
             filter: TextureFilter::Linear,
             address_u: TextureAddressMode::Clamp,
             address_v: TextureAddressMode::Clamp,
             address_w: TextureAddressMode::Clamp,
-            ..Default::default()
+            ..Self::new()
         }
     }
 
-    pub fn linear_clamp() -> SamplerState {
+    pub fn anisotropic_wrap() -> SamplerState {
         SamplerState {
-            filter: TextureFilter::Linear,
+            filter: TextureFilter::Anisotropic,
             address_u: TextureAddressMode::Wrap,
             address_v: TextureAddressMode::Wrap,
             address_w: TextureAddressMode::Wrap,
-            ..Default::default()
+            ..Self::new()
         }
     }
 
-    pub fn anisotropic_wrap() -> SamplerState {
+    pub fn anisotropic_clamp() -> SamplerState {
         SamplerState {
             filter: TextureFilter::Anisotropic,
             address_u: TextureAddressMode::Clamp,
             address_v: TextureAddressMode::Clamp,
             address_w: TextureAddressMode::Clamp,
-            ..Default::default()
+            ..Self::new()
         }
     }
 
-    pub fn anisotropic_clamp() -> SamplerState {
-        SamplerState {
-            filter: TextureFilter::Anisotropic,
-            address_u: TextureAddressMode::Wrap,
-            address_v: TextureAddressMode::Wrap,
-            address_w: TextureAddressMode::Wrap,
-            ..Default::default()
+    pub fn sample(&self, levels: &[TexelBuffer], texture_coordinate: &Vector2, level_of_detail: f32) -> Vector4 {
+        if levels.is_empty() {
+            return self.border_color.to_vector4();
+        }
+
+        let (min_linear, mag_linear, mip_linear) = self.filter.modes();
+        let max_level = (levels.len() - 1) as f32;
+        let lod = (level_of_detail + self.mip_map_level_of_detail_bias)
+            .max(self.min_mip_level)
+            .min(self.max_mip_level)
+            .clamp(0.0, max_level);
+        let is_linear = if level_of_detail + self.mip_map_level_of_detail_bias > 0.0 { min_linear } else { mag_linear };
+
+        if mip_linear {
+            let level1 = lod.floor();
+            let level2 = (level1 + 1.0).min(max_level);
+            let amount = lod - level1;
+
+            let value1 = self.sample_level(&levels[level1 as usize], texture_coordinate, is_linear);
+
+            if amount <= 0.0 || level1 == level2 {
+                return value1;
+            }
+
+            let value2 = self.sample_level(&levels[level2 as usize], texture_coordinate, is_linear);
+            Vector4::lerp(&value1, &value2, amount)
+        } else {
+            let level = (lod + 0.5).floor().min(max_level);
+            self.sample_level(&levels[level as usize], texture_coordinate, is_linear)
+        }
+    }
+
+    pub fn sample_grad(&self, levels: &[TexelBuffer], texture_coordinate: &Vector2, ddx: &Vector2, ddy: &Vector2) -> Vector4 {
+        if levels.is_empty() {
+            return self.border_color.to_vector4();
+        }
+
+        let width = levels[0].width as f32;
+        let height = levels[0].height as f32;
+        let length_x = Vector2 { x: ddx.x * width, y: ddx.y * height }.length();
+        let length_y = Vector2 { x: ddy.x * width, y: ddy.y * height }.length();
+        let major = length_x.max(length_y);
+        let minor = length_x.min(length_y);
+
+        if self.filter != TextureFilter::Anisotropic || self.max_anisotropy <= 1 || minor <= 0.0 {
+            return self.sample(levels, texture_coordinate, major.log2());
+        }
+
+        let ratio = (major / minor).min(self.max_anisotropy as f32);
+        let count = ratio.ceil() as usize;
+        let lod = (major / ratio).log2();
+        let axis = if length_x >= length_y { *ddx } else { *ddy };
+
+        let mut result = Vector4::zero();
+
+        for index in 0..count {
+            let offset = (index as f32 + 0.5) / count as f32 - 0.5;
+            let coordinate = Vector2 {
+                x: texture_coordinate.x + axis.x * offset,
+                y: texture_coordinate.y + axis.y * offset,
+            };
+
+            result += self.sample(levels, &coordinate, lod);
+        }
+
+        result * (1.0 / count as f32)
+    }
+
+    pub fn sample_level(&self, level: &TexelBuffer, texture_coordinate: &Vector2, is_linear: bool) -> Vector4 {
+        if level.width == 0 || level.height == 0 {
+            return self.border_color.to_vector4();
+        }
+
+        let x = texture_coordinate.x * level.width as f32;
+        let y = texture_coordinate.y * level.height as f32;
+
+        if !is_linear {
+            return self.texel(level, x.floor() as i64, y.floor() as i64);
+        }
+
+        let x = x - 0.5;
+        let y = y - 0.5;
+        let x1 = x.floor();
+        let y1 = y.floor();
+        let amount_x = x - x1;
+        let amount_y = y - y1;
+        let x1 = x1 as i64;
+        let y1 = y1 as i64;
+
+        let top = Vector4::lerp(&self.texel(level, x1, y1), &self.texel(level, x1 + 1, y1), amount_x);
+        let bottom = Vector4::lerp(&self.texel(level, x1, y1 + 1), &self.texel(level, x1 + 1, y1 + 1), amount_x);
+
+        Vector4::lerp(&top, &bottom, amount_y)
+    }
+
+    fn texel(&self, level: &TexelBuffer, x: i64, y: i64) -> Vector4 {
+        let x = self.address_u.address(x, level.width as i64);
+        let y = self.address_v.address(y, level.height as i64);
+
+        match (x, y) {
+            (Some(x), Some(y)) => level.get(x as u32, y as u32),
+            _ => self.border_color.to_vector4(),
+        }
+    }
+}
+
+impl TextureAddressMode {
+    pub fn address(&self, coordinate: i64, size: i64) -> Option<i64> {
+        if size <= 0 {
+            return None;
+        }
+
+        match self {
+            TextureAddressMode::Wrap => Some(coordinate.rem_euclid(size)),
+            TextureAddressMode::Mirror => {
+                let value = coordinate.rem_euclid(size * 2);
+                Some(if value >= size { size * 2 - 1 - value } else { value })
+            }
+            TextureAddressMode::Clamp => Some(coordinate.clamp(0, size - 1)),
+            TextureAddressMode::Border => {
+                if coordinate < 0 || coordinate >= size { None } else { Some(coordinate) }
+            }
+            TextureAddressMode::MirrorOnce => {
+                let value = if coordinate < 0 { -1 - coordinate } else { coordinate };
+                Some(value.min(size - 1))
+            }
+        }
+    }
+}
+
+impl TextureFilter {
+    pub fn modes(&self) -> (bool, bool, bool) {
+        match self {
+            TextureFilter::Linear | TextureFilter::Anisotropic => (true, true, true),
+            TextureFilter::Point => (false, false, false),
+            TextureFilter::LinearMipPoint => (true, true, false),
+            TextureFilter::PointMipLinear => (false, false, true),
+            TextureFilter::MinLinearMagPointMipLinear => (true, false, true),
+            TextureFilter::MinLinearMagPointMipPoint => (true, false, false),
+            TextureFilter::MinPointMagLinearMipLinear => (false, true, true),
+            TextureFilter::MinPointMagLinearMipPoint => (false, true, false),
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(width: u32, height: u32, values: &[f32]) -> TexelBuffer {
+        let texels = values.iter().map(|value| Vector4 { x: *value, y: *value, z: *value, w: 1.0 }).collect();
+        TexelBuffer::from_texels(width, height, texels).unwrap()
+    }
+
+    fn chain() -> Vec<TexelBuffer> {
+        vec![level(4, 4, &[0.0; 16]), level(2, 2, &[1.0; 4]), level(1, 1, &[2.0])]
+    }
+
+    #[test]
+    fn address_modes_resolve_negative_and_out_of_range_coordinates() {
+        assert_eq!(TextureAddressMode::Wrap.address(-1, 4), Some(3));
+        assert_eq!(TextureAddressMode::Wrap.address(5, 4), Some(1));
+        assert_eq!(TextureAddressMode::Mirror.address(-1, 4), Some(0));
+        assert_eq!(TextureAddressMode::Mirror.address(-5, 4), Some(3));
+        assert_eq!(TextureAddressMode::Mirror.address(5, 4), Some(2));
+        assert_eq!(TextureAddressMode::Clamp.address(-3, 4), Some(0));
+        assert_eq!(TextureAddressMode::Clamp.address(9, 4), Some(3));
+        assert_eq!(TextureAddressMode::Border.address(-1, 4), None);
+        assert_eq!(TextureAddressMode::Border.address(4, 4), None);
+        assert_eq!(TextureAddressMode::Border.address(2, 4), Some(2));
+        assert_eq!(TextureAddressMode::MirrorOnce.address(-2, 4), Some(1));
+        assert_eq!(TextureAddressMode::MirrorOnce.address(-9, 4), Some(3));
+        assert_eq!(TextureAddressMode::MirrorOnce.address(6, 4), Some(3));
+        assert_eq!(TextureAddressMode::Wrap.address(0, 0), None);
+    }
+
+    #[test]
+    fn border_mode_samples_the_border_color() {
+        let sampler = SamplerState {
+            address_u: TextureAddressMode::Border,
+            address_v: TextureAddressMode::Border,
+            border_color: Color::red(),
+            ..SamplerState::point_clamp()
+        };
+        let texture = level(2, 1, &[0.25, 0.75]);
+
+        assert_eq!(sampler.sample_level(&texture, &Vector2 { x: -0.25, y: 0.5 }, false), Color::red().to_vector4());
+        assert_eq!(sampler.sample_level(&texture, &Vector2 { x: 0.5, y: 1.5 }, false), Color::red().to_vector4());
+        assert_eq!(sampler.sample_level(&texture, &Vector2 { x: 0.75, y: 0.5 }, false).x, 0.75);
+        assert_eq!(sampler.sample(&[], &Vector2 { x: 0.5, y: 0.5 }, 0.0), Color::red().to_vector4());
+    }
+
+    #[test]
+    fn point_and_linear_filtering_differ_between_texels() {
+        let texture = level(2, 1, &[0.0, 1.0]);
+        let clamp = SamplerState::linear_clamp();
+        let wrap = SamplerState::linear_wrap();
+
+        assert_eq!(clamp.sample_level(&texture, &Vector2 { x: 0.4, y: 0.5 }, false).x, 0.0);
+        assert_eq!(clamp.sample_level(&texture, &Vector2 { x: 0.5, y: 0.5 }, false).x, 1.0);
+        assert_eq!(clamp.sample_level(&texture, &Vector2 { x: 0.5, y: 0.5 }, true).x, 0.5);
+        assert_eq!(clamp.sample_level(&texture, &Vector2 { x: 0.25, y: 0.5 }, true).x, 0.0);
+        assert_eq!(clamp.sample_level(&texture, &Vector2 { x: 0.0, y: 0.5 }, true).x, 0.0);
+        assert_eq!(wrap.sample_level(&texture, &Vector2 { x: 0.0, y: 0.5 }, true).x, 0.5);
+    }
+
+    #[test]
+    fn level_of_detail_applies_bias_and_mip_clamping() {
+        let levels = chain();
+        let center = Vector2 { x: 0.5, y: 0.5 };
+        let point = SamplerState::point_clamp();
+
+        assert_eq!(point.sample(&levels, &center, 0.0).x, 0.0);
+        assert_eq!(point.sample(&levels, &center, -3.0).x, 0.0);
+        assert_eq!(point.sample(&levels, &center, 9.0).x, 2.0);
+
+        let biased = SamplerState { mip_map_level_of_detail_bias: 1.0, ..point };
+        assert_eq!(biased.sample(&levels, &center, 0.0).x, 1.0);
+
+        let limited = SamplerState { max_mip_level: 1.0, ..point };
+        assert_eq!(limited.sample(&levels, &center, 2.0).x, 1.0);
+
+        let raised = SamplerState { min_mip_level: 1.0, ..point };
+        assert_eq!(raised.sample(&levels, &center, 0.0).x, 1.0);
+
+        let linear = SamplerState::linear_clamp();
+        assert_eq!(linear.sample(&levels, &center, 1.25).x, 1.25);
+        assert_eq!(linear.sample(&levels, &center, 0.5).x, 0.5);
+    }
+}
@@ -0,0 +1,623 @@
+use crate::xna::csharp::Exception;
+use crate::xna::framework::{Color, Matrix, Rectangle, Vector4};
+use crate::xna::framework::graphics::{BlendState, CullMode, DepthStencilState, FillMode, IPackedVector, PrimitiveType, RasterizerState, SoftwareRenderer, VertexPositionColor, Viewport};
+
+#[derive(Default, Copy, Clone)]
+struct RasterVertex {
+    position: Vector4,
+    color: Vector4,
+}
+
+impl RasterVertex {
+    fn lerp(value1: &RasterVertex, value2: &RasterVertex, amount: f32) -> RasterVertex {
+        RasterVertex {
+            position: Vector4::lerp(&value1.position, &value2.position, amount),
+            color: Vector4::lerp(&value1.color, &value2.color, amount),
+        }
+    }
+}
+
+impl SoftwareRenderer {
+    const MINIMUM_W: f32 = 1e-6;
+
+    pub fn new(width: u32, height: u32) -> Self {
+        let mut renderer = SoftwareRenderer {
+            blend_state: BlendState::opaque(),
+            depth_stencil_state: DepthStencilState::default(),
+            rasterizer_state: RasterizerState::cull_counter_clock_wise(),
+            ..Default::default()
+        };
+
+        renderer.resize(width, height);
+        renderer
+    }
+
+    pub fn resize(&mut self, width: u32, height: u32) {
+        let size = (width * height) as usize;
+
+        self.width = width;
+        self.height = height;
+        self.color_buffer = vec![Color::transparent(); size];
+        self.depth_buffer = vec![1.0; size];
+        self.stencil_buffer = vec![0; size];
+        self.viewport = Viewport {
+            x: 0.0,
+            y: 0.0,
+            width: width as f32,
+            height: height as f32,
+            min_depth: 0.0,
+            max_depth: 1.0,
+        };
+        self.scissor_rectangle = Rectangle::new(0, 0, width as i32, height as i32);
+    }
+
+    pub fn clear(&mut self, color: Color, depth: f32, stencil: u8) {
+        self.clear_color(color);
+        self.clear_depth(depth);
+        self.clear_stencil(stencil);
+    }
+
+    pub fn clear_color(&mut self, color: Color) {
+        self.color_buffer.fill(color);
+    }
+
+    pub fn clear_depth(&mut self, depth: f32) {
+        self.depth_buffer.fill(depth);
+    }
+
+    pub fn clear_stencil(&mut self, stencil: u8) {
+        self.stencil_buffer.fill(stencil);
+    }
+
+    pub fn vertex_count(primitive_type: PrimitiveType, primitive_count: usize) -> usize {
+        if primitive_count == 0 {
+            return 0;
+        }
+
+        match primitive_type {
+            PrimitiveType::TriangleList => primitive_count * 3,
+            PrimitiveType::TriangleStrip => primitive_count + 2,
+            PrimitiveType::LineList => primitive_count * 2,
+            PrimitiveType::LineStrip => primitive_count + 1,
+        }
+    }
+
+    pub fn draw_user_primitives(&mut self, primitive_type: PrimitiveType, vertex_data: &[VertexPositionColor], vertex_offset: usize,
+                                primitive_count: usize, transform: &Matrix) -> Result<(), Exception> {
+        let vertex_count = Self::vertex_count(primitive_type, primitive_count);
+
+        if vertex_offset + vertex_count > vertex_data.len() {
+            return Err(Exception::argument_exception("vertex_data is too short for the requested primitive count.", None));
+        }
+
+        let vertices: Vec<RasterVertex> = vertex_data[vertex_offset..vertex_offset + vertex_count]
+            .iter()
+            .map(|vertex| RasterVertex {
+                position: Vector4::transform_vector3(&vertex.position, transform),
+                color: vertex.color.to_vector4(),
+            })
+            .collect();
+
+        for index in 0..primitive_count {
+            match primitive_type {
+                PrimitiveType::TriangleList => self.draw_triangle(&vertices[index * 3], &vertices[index * 3 + 1], &vertices[index * 3 + 2])?,
+                PrimitiveType::TriangleStrip if index % 2 == 0 => self.draw_triangle(&vertices[index], &vertices[index + 1], &vertices[index + 2])?,
+                PrimitiveType::TriangleStrip => self.draw_triangle(&vertices[index], &vertices[index + 2], &vertices[index + 1])?,
+                PrimitiveType::LineList => self.draw_line(&vertices[index * 2], &vertices[index * 2 + 1])?,
+                PrimitiveType::LineStrip => self.draw_line(&vertices[index], &vertices[index + 1])?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn draw_triangle(&mut self, vertex1: &RasterVertex, vertex2: &RasterVertex, vertex3: &RasterVertex) -> Result<(), Exception> {
+        let polygon = self.clip_polygon(vec![*vertex1, *vertex2, *vertex3]);
+
+        if polygon.len() < 3 {
+            return Ok(());
+        }
+
+        let screen: Vec<RasterVertex> = polygon.iter().map(|vertex| self.to_screen(vertex)).collect();
+
+        let mut area = 0.0;
+        for index in 0..screen.len() {
+            let current = &screen[index].position;
+            let next = &screen[(index + 1) % screen.len()].position;
+            area += current.x * next.y - next.x * current.y;
+        }
+
+        if area == 0.0 {
+            return Ok(());
+        }
+
+        let is_clockwise = area > 0.0;
+
+        match self.rasterizer_state.cull_mode {
+            CullMode::CullClockwiseFace if is_clockwise => return Ok(()),
+            CullMode::CullCounterClockwiseFace if !is_clockwise => return Ok(()),
+            _ => {}
+        }
+
+        let is_front_face = is_clockwise != self.rasterizer_state.front_counter_clockwise;
+        let depth_bias = self.depth_bias(&screen);
+
+        match self.rasterizer_state.fill_mode {
+            FillMode::Solid => {
+                for index in 1..screen.len() - 1 {
+                    self.fill_triangle(&screen[0], &screen[index], &screen[index + 1], is_front_face, depth_bias)?;
+                }
+            }
+            FillMode::WireFrame => {
+                for index in 0..screen.len() {
+                    self.draw_screen_line(&screen[index], &screen[(index + 1) % screen.len()], is_front_face, depth_bias)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn draw_line(&mut self, vertex1: &RasterVertex, vertex2: &RasterVertex) -> Result<(), Exception> {
+        let mut start = *vertex1;
+        let mut end = *vertex2;
+
+        for plane in self.clip_planes() {
+            let distance1 = plane(&start.position);
+            let distance2 = plane(&end.position);
+
+            if distance1 < 0.0 && distance2 < 0.0 {
+                return Ok(());
+            }
+
+            if distance1 < 0.0 {
+                start = RasterVertex::lerp(&start, &end, distance1 / (distance1 - distance2));
+            } else if distance2 < 0.0 {
+                end = RasterVertex::lerp(&start, &end, distance1 / (distance1 - distance2));
+            }
+        }
+
+        let start = self.to_screen(&start);
+        let end = self.to_screen(&end);
+
+        self.draw_screen_line(&start, &end, true, 0.0)
+    }
+
+    fn clip_planes(&self) -> Vec<fn(&Vector4) -> f32> {
+        let mut planes: Vec<fn(&Vector4) -> f32> = vec![|position| position.w - Self::MINIMUM_W];
+
+        if self.rasterizer_state.depth_clip_enable {
+            planes.push(|position| position.z);
+            planes.push(|position| position.w - position.z);
+        }
+
+        planes
+    }
+
+    fn clip_polygon(&self, polygon: Vec<RasterVertex>) -> Vec<RasterVertex> {
+        let mut result = polygon;
+
+        for plane in self.clip_planes() {
+            if result.is_empty() {
+                break;
+            }
+
+            let mut clipped = Vec::with_capacity(result.len() + 2);
+
+            for index in 0..result.len() {
+                let current = &result[index];
+                let next = &result[(index + 1) % result.len()];
+                let distance1 = plane(&current.position);
+                let distance2 = plane(&next.position);
+
+                if distance1 >= 0.0 {
+                    clipped.push(*current);
+                }
+
+                if (distance1 >= 0.0) != (distance2 >= 0.0) {
+                    clipped.push(RasterVertex::lerp(current, next, distance1 / (distance1 - distance2)));
+                }
+            }
+
+            result = clipped;
+        }
+
+        result
+    }
+
+    fn to_screen(&self, vertex: &RasterVertex) -> RasterVertex {
+        let viewport = &self.viewport;
+        let inverse_w = 1.0 / vertex.position.w;
+
+        RasterVertex {
+            position: Vector4 {
+                x: viewport.x + (vertex.position.x * inverse_w + 1.0) * 0.5 * viewport.width,
+                y: viewport.y + (1.0 - vertex.position.y * inverse_w) * 0.5 * viewport.height,
+                z: viewport.min_depth + vertex.position.z * inverse_w * (viewport.max_depth - viewport.min_depth),
+                w: inverse_w,
+            },
+            color: vertex.color,
+        }
+    }
+
+    fn depth_bias(&self, screen: &[RasterVertex]) -> f32 {
+        let state = &self.rasterizer_state;
+
+        if state.depth_bias == 0 && state.slope_scale_depth_bias == 0.0 {
+            return 0.0;
+        }
+
+        let v0 = &screen[0].position;
+        let v1 = &screen[1].position;
+        let v2 = &screen[2].position;
+        let area = Self::edge(v0, v1, v2.x, v2.y);
+
+        let slope = if area != 0.0 {
+            let dzdx = ((v1.z - v0.z) * (v2.y - v0.y) - (v2.z - v0.z) * (v1.y - v0.y)) / area;
+            let dzdy = ((v2.z - v0.z) * (v1.x - v0.x) - (v1.z - v0.z) * (v2.x - v0.x)) / area;
+            dzdx.abs().max(dzdy.abs())
+        } else {
+            0.0
+        };
+
+        let bias = state.depth_bias as f32 / 16777216.0 + state.slope_scale_depth_bias * slope;
+
+        if state.depth_bias_clamp > 0.0 {
+            bias.min(state.depth_bias_clamp)
+        } else if state.depth_bias_clamp < 0.0 {
+            bias.max(state.depth_bias_clamp)
+        } else {
+            bias
+        }
+    }
+
+    fn pixel_bounds(&self) -> (i32, i32, i32, i32) {
+        let viewport = &self.viewport;
+
+        let mut left = viewport.x.floor().max(0.0) as i32;
+        let mut top = viewport.y.floor().max(0.0) as i32;
+        let mut right = ((viewport.x + viewport.width).ceil() as i32).min(self.width as i32);
+        let mut bottom = ((viewport.y + viewport.height).ceil() as i32).min(self.height as i32);
+
+        if self.rasterizer_state.scissor_test_enable {
+            let scissor = &self.scissor_rectangle;
+            left = left.max(scissor.left());
+            top = top.max(scissor.top());
+            right = right.min(scissor.right());
+            bottom = bottom.min(scissor.bottom());
+        }
+
+        (left, top, right, bottom)
+    }
+
+    fn edge(a: &Vector4, b: &Vector4, x: f32, y: f32) -> f32 {
+        (b.x - a.x) * (y - a.y) - (b.y - a.y) * (x - a.x)
+    }
+
+    fn is_top_left(a: &Vector4, b: &Vector4) -> bool {
+        let dx = b.x - a.x;
+        let dy = b.y - a.y;
+
+        (dy == 0.0 && dx > 0.0) || dy < 0.0
+    }
+
+    fn fill_triangle(&mut self, vertex0: &RasterVertex, vertex1: &RasterVertex, vertex2: &RasterVertex, is_front_face: bool, depth_bias: f32) -> Result<(), Exception> {
+        let mut area = Self::edge(&vertex0.position, &vertex1.position, vertex2.position.x, vertex2.position.y);
+
+        if area == 0.0 {
+            return Ok(());
+        }
+
+        let (vertex1, vertex2) = if area < 0.0 { (vertex2, vertex1) } else { (vertex1, vertex2) };
+        area = area.abs();
+
+        let p0 = &vertex0.position;
+        let p1 = &vertex1.position;
+        let p2 = &vertex2.position;
+
+        let (left, top, right, bottom) = self.pixel_bounds();
+        let min_x = (p0.x.min(p1.x).min(p2.x).floor() as i32).max(left);
+        let min_y = (p0.y.min(p1.y).min(p2.y).floor() as i32).max(top);
+        let max_x = (p0.x.max(p1.x).max(p2.x).ceil() as i32).min(right);
+        let max_y = (p0.y.max(p1.y).max(p2.y).ceil() as i32).min(bottom);
+
+        let top_left0 = Self::is_top_left(p1, p2);
+        let top_left1 = Self::is_top_left(p2, p0);
+        let top_left2 = Self::is_top_left(p0, p1);
+
+        for y in min_y..max_y {
+            for x in min_x..max_x {
+                let sample_x = x as f32 + 0.5;
+                let sample_y = y as f32 + 0.5;
+
+                let w0 = Self::edge(p1, p2, sample_x, sample_y);
+                let w1 = Self::edge(p2, p0, sample_x, sample_y);
+                let w2 = Self::edge(p0, p1, sample_x, sample_y);
+
+                if w0 < 0.0 || w1 < 0.0 || w2 < 0.0
+                    || (w0 == 0.0 && !top_left0)
+                    || (w1 == 0.0 && !top_left1)
+                    || (w2 == 0.0 && !top_left2) {
+                    continue;
+                }
+
+                let l0 = w0 / area;
+                let l1 = w1 / area;
+                let l2 = w2 / area;
+
+                let depth = l0 * p0.z + l1 * p1.z + l2 * p2.z + depth_bias;
+
+                let q0 = l0 * p0.w;
+                let q1 = l1 * p1.w;
+                let q2 = l2 * p2.w;
+                let color = (vertex0.color * q0 + vertex1.color * q1 + vertex2.color * q2) / (q0 + q1 + q2);
+
+                self.process_pixel(x, y, depth, color, is_front_face)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn clip_screen_line(&self, p0: &Vector4, p1: &Vector4) -> Option<(f64, f64)> {
+        let (left, top, right, bottom) = self.pixel_bounds();
+        let dx = p1.x as f64 - p0.x as f64;
+        let dy = p1.y as f64 - p0.y as f64;
+        let mut start = 0.0f64;
+        let mut end = 1.0f64;
+
+        let boundaries = [
+            (-dx, p0.x as f64 - left as f64),
+            (dx, right as f64 - p0.x as f64),
+            (-dy, p0.y as f64 - top as f64),
+            (dy, bottom as f64 - p0.y as f64),
+        ];
+
+        for (direction, distance) in boundaries {
+            if direction == 0.0 {
+                if distance < 0.0 {
+                    return None;
+                }
+
+                continue;
+            }
+
+            let amount = distance / direction;
+
+            if direction < 0.0 {
+                start = start.max(amount);
+            } else {
+                end = end.min(amount);
+            }
+        }
+
+        if start.is_nan() || end.is_nan() || start > end {
+            return None;
+        }
+
+        Some((start, end))
+    }
+
+    fn draw_screen_line(&mut self, start: &RasterVertex, end: &RasterVertex, is_front_face: bool, depth_bias: f32) -> Result<(), Exception> {
+        let p0 = &start.position;
+        let p1 = &end.position;
+
+        let (clip_start, clip_end) = match self.clip_screen_line(p0, p1) {
+            Some(range) => range,
+            None => return Ok(()),
+        };
+
+        let dx = p1.x as f64 - p0.x as f64;
+        let dy = p1.y as f64 - p0.y as f64;
+        let steps = (dx.abs().max(dy.abs()) * (clip_end - clip_start)).ceil().max(1.0) as i32;
+        let (left, top, right, bottom) = self.pixel_bounds();
+
+        for step in 0..steps {
+            let position = clip_start + (clip_end - clip_start) * (step as f64 + 0.5) / steps as f64;
+            let x = (p0.x as f64 + dx * position).floor() as i32;
+            let y = (p0.y as f64 + dy * position).floor() as i32;
+
+            if x < left || x >= right || y < top || y >= bottom {
+                continue;
+            }
+
+            let amount = position as f32;
+            let depth = p0.z + (p1.z - p0.z) * amount + depth_bias;
+
+            let q0 = (1.0 - amount) * p0.w;
+            let q1 = amount * p1.w;
+            let color = (start.color * q0 + end.color * q1) / (q0 + q1);
+
+            self.process_pixel(x, y, depth, color, is_front_face)?;
+        }
+
+        Ok(())
+    }
+
+    fn process_pixel(&mut self, x: i32, y: i32, depth: f32, color: Vector4, is_front_face: bool) -> Result<(), Exception> {
+        let viewport = &self.viewport;
+        let depth = depth.clamp(viewport.min_depth.min(viewport.max_depth), viewport.min_depth.max(viewport.max_depth));
+        let index = (y as u32 * self.width + x as u32) as usize;
+
+        if !self.depth_stencil_test(index, depth, is_front_face) {
+            return Ok(());
+        }
+
+        let destination = self.color_buffer[index].to_vector4();
+        let result = self.blend_state.blend_vector4(&color, &destination)?;
+
+        self.color_buffer[index] = Color::from_vector4(result);
+
+        Ok(())
+    }
+
+    fn depth_stencil_test(&mut self, index: usize, depth: f32, is_front_face: bool) -> bool {
+        self.depth_stencil_state.test(depth, &mut self.depth_buffer[index], &mut self.stencil_buffer[index], is_front_face)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::xna::framework::Vector3;
+    use crate::xna::framework::graphics::{ColorWriteChannels, ComparisonFunction, DepthFace, StencilOperation};
+
+    fn vertex(x: f32, y: f32, color: Color) -> VertexPositionColor {
+        VertexPositionColor { position: Vector3 { x, y, z: 0.5 }, color }
+    }
+
+    #[test]
+    fn draw_user_primitives_fills_a_single_triangle() {
+        let mut renderer = SoftwareRenderer::new(4, 4);
+        renderer.rasterizer_state = RasterizerState::cull_none();
+        renderer.clear(Color::black(), 1.0, 0);
+
+        let vertices = [vertex(-1.0, 1.0, Color::red()), vertex(1.0, 1.0, Color::red()), vertex(-1.0, -1.0, Color::red())];
+        renderer.draw_user_primitives(PrimitiveType::TriangleList, &vertices, 0, 1, &Matrix::identity()).unwrap();
+
+        for y in 0..4 {
+            for x in 0..4 {
+                let expected = if x + y < 3 { Color::red() } else { Color::black() };
+                assert_eq!(renderer.color_buffer[y * 4 + x], expected, "pixel ({x}, {y})");
+            }
+        }
+
+        assert_eq!(renderer.depth_buffer[0], 0.5);
+        assert_eq!(renderer.depth_buffer[15], 1.0);
+    }
+
+    #[test]
+    fn draw_user_primitives_rejects_occluded_pixels() {
+        let mut renderer = SoftwareRenderer::new(4, 4);
+        renderer.rasterizer_state = RasterizerState::cull_none();
+        renderer.clear(Color::black(), 0.25, 0);
+
+        let vertices = [vertex(-1.0, 1.0, Color::red()), vertex(1.0, 1.0, Color::red()), vertex(-1.0, -1.0, Color::red())];
+        renderer.draw_user_primitives(PrimitiveType::TriangleList, &vertices, 0, 1, &Matrix::identity()).unwrap();
+
+        assert!(renderer.color_buffer.iter().all(|color| *color == Color::black()));
+    }
+
+    fn renderer() -> SoftwareRenderer {
+        let mut renderer = SoftwareRenderer::new(4, 4);
+        renderer.clear(Color::black(), 1.0, 0);
+
+        renderer
+    }
+
+    fn covered(renderer: &SoftwareRenderer) -> Vec<bool> {
+        renderer.color_buffer.iter().map(|color| *color != Color::black()).collect()
+    }
+
+    fn draw(renderer: &mut SoftwareRenderer, primitive_type: PrimitiveType, vertices: &[VertexPositionColor]) {
+        let primitive_count = match primitive_type {
+            PrimitiveType::LineList => vertices.len() / 2,
+            _ => vertices.len() / 3,
+        };
+
+        renderer.draw_user_primitives(primitive_type, vertices, 0, primitive_count, &Matrix::identity()).unwrap();
+    }
+
+    #[test]
+    fn cull_mode_discards_faces_by_winding() {
+        let clockwise = [vertex(-1.0, 1.0, Color::red()), vertex(1.0, 1.0, Color::red()), vertex(-1.0, -1.0, Color::red())];
+        let counter_clockwise = [clockwise[0], clockwise[2], clockwise[1]];
+
+        let mut renderer = renderer();
+        renderer.rasterizer_state = RasterizerState::cull_counter_clock_wise();
+        draw(&mut renderer, PrimitiveType::TriangleList, &counter_clockwise);
+        assert!(covered(&renderer).iter().all(|covered| !covered));
+        draw(&mut renderer, PrimitiveType::TriangleList, &clockwise);
+        assert_eq!(covered(&renderer).iter().filter(|covered| **covered).count(), 6);
+
+        let mut renderer = self::renderer();
+        renderer.rasterizer_state = RasterizerState::cull_clock_wise();
+        draw(&mut renderer, PrimitiveType::TriangleList, &clockwise);
+        assert!(covered(&renderer).iter().all(|covered| !covered));
+        draw(&mut renderer, PrimitiveType::TriangleList, &counter_clockwise);
+        assert_eq!(covered(&renderer).iter().filter(|covered| **covered).count(), 6);
+    }
+
+    #[test]
+    fn scissor_rectangle_limits_the_covered_pixels() {
+        let mut renderer = renderer();
+        renderer.rasterizer_state = RasterizerState::cull_none();
+        renderer.rasterizer_state.scissor_test_enable = true;
+        renderer.scissor_rectangle = Rectangle::new(1, 1, 2, 2);
+
+        let quad = [
+            vertex(-1.0, 1.0, Color::red()), vertex(1.0, 1.0, Color::red()), vertex(-1.0, -1.0, Color::red()),
+            vertex(1.0, 1.0, Color::red()), vertex(1.0, -1.0, Color::red()), vertex(-1.0, -1.0, Color::red()),
+        ];
+        draw(&mut renderer, PrimitiveType::TriangleList, &quad);
+
+        for y in 0..4 {
+            for x in 0..4 {
+                let expected = (1..3).contains(&x) && (1..3).contains(&y);
+                assert_eq!(covered(&renderer)[y * 4 + x], expected, "pixel ({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn wireframe_draws_only_the_triangle_edges() {
+        let mut renderer = SoftwareRenderer::new(8, 8);
+        renderer.clear(Color::black(), 1.0, 0);
+        renderer.rasterizer_state = RasterizerState::cull_none();
+        renderer.rasterizer_state.fill_mode = FillMode::WireFrame;
+
+        let vertices = [vertex(-0.875, 0.875, Color::red()), vertex(0.875, 0.875, Color::red()), vertex(-0.875, -0.875, Color::red())];
+        draw(&mut renderer, PrimitiveType::TriangleList, &vertices);
+
+        let covered = covered(&renderer);
+        assert!(covered[1] && covered[6] && covered[6 * 8]);
+        assert!(covered[4 * 8 + 3] || covered[4 * 8 + 4]);
+        assert!(!covered[2 * 8 + 2]);
+        assert!(!covered[7 * 8 + 7]);
+    }
+
+    #[test]
+    fn lines_far_outside_the_viewport_are_clipped_before_stepping() {
+        let mut renderer = renderer();
+
+        let vertices = [vertex(-1e9, 0.25, Color::red()), vertex(1e9, 0.25, Color::red())];
+        draw(&mut renderer, PrimitiveType::LineList, &vertices);
+
+        for x in 0..4 {
+            assert_eq!(renderer.color_buffer[4 + x], Color::red(), "pixel ({x}, 1)");
+        }
+
+        assert_eq!(covered(&renderer).iter().filter(|covered| **covered).count(), 4);
+    }
+
+    #[test]
+    fn stencil_operations_mask_later_draws() {
+        let mut renderer = renderer();
+        renderer.rasterizer_state = RasterizerState::cull_none();
+        renderer.depth_stencil_state = DepthStencilState::none();
+        renderer.depth_stencil_state.stencil_enable = true;
+        renderer.depth_stencil_state.reference_stencil = 1;
+        renderer.depth_stencil_state.front_face.stencil_pass_operation = StencilOperation::Replace;
+        renderer.depth_stencil_state.back_face.stencil_pass_operation = StencilOperation::Replace;
+        renderer.blend_state.render_targets[0].write_mask = ColorWriteChannels::NONE;
+
+        let triangle = [vertex(-1.0, 1.0, Color::red()), vertex(1.0, 1.0, Color::red()), vertex(-1.0, -1.0, Color::red())];
+        draw(&mut renderer, PrimitiveType::TriangleList, &triangle);
+        assert_eq!(renderer.stencil_buffer.iter().filter(|stencil| **stencil == 1).count(), 6);
+        assert!(covered(&renderer).iter().all(|covered| !covered));
+
+        renderer.blend_state = BlendState::opaque();
+        renderer.depth_stencil_state.front_face = DepthFace { stencil_function: ComparisonFunction::Equal, ..Default::default() };
+        renderer.depth_stencil_state.back_face = renderer.depth_stencil_state.front_face;
+
+        let quad = [
+            vertex(-1.0, 1.0, Color::red()), vertex(1.0, 1.0, Color::red()), vertex(-1.0, -1.0, Color::red()),
+            vertex(1.0, 1.0, Color::red()), vertex(1.0, -1.0, Color::red()), vertex(-1.0, -1.0, Color::red()),
+        ];
+        draw(&mut renderer, PrimitiveType::TriangleList, &quad);
+
+        let stencil: Vec<bool> = renderer.stencil_buffer.iter().map(|stencil| *stencil == 1).collect();
+        assert_eq!(covered(&renderer), stencil);
+    }
+}
+
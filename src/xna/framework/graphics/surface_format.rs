@@ -0,0 +1,241 @@
+use crate::xna::csharp::Exception;
+use crate::xna::framework::{Color, Vector4};
+use crate::xna::framework::graphics::{Alpha8, Bgr565, Bgra4444, Bgra5551, HalfSingle, HalfVector2, HalfVector4, IPackedVector, NormalizedByte2, NormalizedByte4, PackedVector, PackedVectorType, Rg32, Rgba1010102, Rgba64, SurfaceFormat};
+
+impl SurfaceFormat {
+    pub fn size(&self) -> u32 {
+        match self.to_linear() {
+            SurfaceFormat::Alpha8 => 1,
+            SurfaceFormat::Bgr565
+            | SurfaceFormat::Bgra5551
+            | SurfaceFormat::Bgra4444
+            | SurfaceFormat::NormalizedByte2
+            | SurfaceFormat::HalfSingle => 2,
+            SurfaceFormat::Color
+            | SurfaceFormat::Bgr32
+            | SurfaceFormat::Bgra32
+            | SurfaceFormat::NormalizedByte4
+            | SurfaceFormat::Rgba1010102
+            | SurfaceFormat::Rg32
+            | SurfaceFormat::Single
+            | SurfaceFormat::HalfVector2 => 4,
+            SurfaceFormat::Dxt1
+            | SurfaceFormat::Rgba64
+            | SurfaceFormat::Vector2
+            | SurfaceFormat::HalfVector4
+            | SurfaceFormat::HdrBlendable => 8,
+            SurfaceFormat::Dxt3
+            | SurfaceFormat::Dxt5
+            | SurfaceFormat::Vector4 => 16,
+            _ => 0,
+        }
+    }
+
+    pub fn block_size(&self) -> u32 {
+        if self.is_compressed() { 4 } else { 1 }
+    }
+
+    pub fn is_compressed(&self) -> bool {
+        matches!(self.to_linear(), SurfaceFormat::Dxt1 | SurfaceFormat::Dxt3 | SurfaceFormat::Dxt5)
+    }
+
+    pub fn is_srgb(&self) -> bool {
+        matches!(self,
+            SurfaceFormat::ColorSRgb
+            | SurfaceFormat::Bgr32SRgb
+            | SurfaceFormat::Bgra32SRgb
+            | SurfaceFormat::Dxt1SRgb
+            | SurfaceFormat::Dxt3SRgb
+            | SurfaceFormat::Dxt5SRgb)
+    }
+
+    pub fn is_unorm(&self) -> bool {
+        matches!(self.to_linear(),
+            SurfaceFormat::Color
+            | SurfaceFormat::Bgr32
+            | SurfaceFormat::Bgra32
+            | SurfaceFormat::Bgr565
+            | SurfaceFormat::Bgra5551
+            | SurfaceFormat::Bgra4444
+            | SurfaceFormat::Rgba1010102
+            | SurfaceFormat::Rg32
+            | SurfaceFormat::Rgba64
+            | SurfaceFormat::Alpha8
+            | SurfaceFormat::Dxt1
+            | SurfaceFormat::Dxt3
+            | SurfaceFormat::Dxt5)
+    }
+
+    pub fn to_linear(self) -> SurfaceFormat {
+        match self {
+            SurfaceFormat::ColorSRgb => SurfaceFormat::Color,
+            SurfaceFormat::Bgr32SRgb => SurfaceFormat::Bgr32,
+            SurfaceFormat::Bgra32SRgb => SurfaceFormat::Bgra32,
+            SurfaceFormat::Dxt1SRgb => SurfaceFormat::Dxt1,
+            SurfaceFormat::Dxt3SRgb => SurfaceFormat::Dxt3,
+            SurfaceFormat::Dxt5SRgb => SurfaceFormat::Dxt5,
+            _ => self,
+        }
+    }
+
+    pub fn to_srgb(self) -> SurfaceFormat {
+        match self {
+            SurfaceFormat::Color => SurfaceFormat::ColorSRgb,
+            SurfaceFormat::Bgr32 => SurfaceFormat::Bgr32SRgb,
+            SurfaceFormat::Bgra32 => SurfaceFormat::Bgra32SRgb,
+            SurfaceFormat::Dxt1 => SurfaceFormat::Dxt1SRgb,
+            SurfaceFormat::Dxt3 => SurfaceFormat::Dxt3SRgb,
+            SurfaceFormat::Dxt5 => SurfaceFormat::Dxt5SRgb,
+            _ => self,
+        }
+    }
+
+    pub fn packed_type(&self) -> Option<PackedVectorType> {
+        let packed_type = match self.to_linear() {
+            SurfaceFormat::Color => PackedVectorType::Color,
+            SurfaceFormat::Bgr565 => PackedVectorType::Bgr565,
+            SurfaceFormat::Bgra5551 => PackedVectorType::Bgra5551,
+            SurfaceFormat::Bgra4444 => PackedVectorType::Bgra4444,
+            SurfaceFormat::NormalizedByte2 => PackedVectorType::NormalizedByte2,
+            SurfaceFormat::NormalizedByte4 => PackedVectorType::NormalizedByte4,
+            SurfaceFormat::Rgba1010102 => PackedVectorType::Rgba1010102,
+            SurfaceFormat::Rg32 => PackedVectorType::Rg32,
+            SurfaceFormat::Rgba64 => PackedVectorType::Rgba64,
+            SurfaceFormat::Alpha8 => PackedVectorType::Alpha8,
+            SurfaceFormat::Single => PackedVectorType::Single,
+            SurfaceFormat::Vector2 => PackedVectorType::Vector2,
+            SurfaceFormat::Vector4 => PackedVectorType::Vector4,
+            SurfaceFormat::HalfSingle => PackedVectorType::HalfSingle,
+            SurfaceFormat::HalfVector2 => PackedVectorType::HalfVector2,
+            SurfaceFormat::HalfVector4 | SurfaceFormat::HdrBlendable => PackedVectorType::HalfVector4,
+            _ => return None,
+        };
+
+        Some(packed_type)
+    }
+
+    pub fn pitch(&self, width: u32) -> u32 {
+        let block_size = self.block_size();
+        width.div_ceil(block_size) * self.size()
+    }
+
+    pub fn surface_size(&self, width: u32, height: u32) -> u32 {
+        self.pitch(width) * height.div_ceil(self.block_size())
+    }
+
+    pub fn unpack_pixel(&self, data: &[u8]) -> Result<Vector4, Exception> {
+        let size = self.validate_pixel(data.len())?;
+        let mut bytes = [0u8; 16];
+        bytes[..size].copy_from_slice(&data[..size]);
+
+        let value16 = u16::from_le_bytes([bytes[0], bytes[1]]);
+        let value32 = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        let value64 = u64::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7]]);
+        let single = |index: usize| f32::from_le_bytes([bytes[index], bytes[index + 1], bytes[index + 2], bytes[index + 3]]);
+
+        let vector = match self.to_linear() {
+            SurfaceFormat::Color => Color::from_packed_value(value32).to_vector4(),
+            SurfaceFormat::Bgra32 | SurfaceFormat::Bgr32 => {
+                let vector = Color::from_packed_value(value32).to_vector4();
+                let w = if self.to_linear() == SurfaceFormat::Bgr32 { 1.0 } else { vector.w };
+
+                Vector4 { x: vector.z, y: vector.y, z: vector.x, w }
+            }
+            SurfaceFormat::Bgr565 => Bgr565::from_packed_value(value16).to_vector4(),
+            SurfaceFormat::Bgra5551 => Bgra5551::from_packed_value(value16).to_vector4(),
+            SurfaceFormat::Bgra4444 => Bgra4444::from_packed_value(value16).to_vector4(),
+            SurfaceFormat::NormalizedByte2 => NormalizedByte2::from_packed_value(value16).to_vector4(),
+            SurfaceFormat::NormalizedByte4 => NormalizedByte4::from_packed_value(value32).to_vector4(),
+            SurfaceFormat::Rgba1010102 => Rgba1010102::from_packed_value(value32).to_vector4(),
+            SurfaceFormat::Rg32 => Rg32::from_packed_value(value32).to_vector4(),
+            SurfaceFormat::Rgba64 => Rgba64::from_packed_value(value64).to_vector4(),
+            SurfaceFormat::Alpha8 => Alpha8::from_packed_value(bytes[0]).to_vector4(),
+            SurfaceFormat::Single => Vector4 { x: single(0), y: 0.0, z: 0.0, w: 1.0 },
+            SurfaceFormat::Vector2 => Vector4 { x: single(0), y: single(4), z: 0.0, w: 1.0 },
+            SurfaceFormat::Vector4 => Vector4 { x: single(0), y: single(4), z: single(8), w: single(12) },
+            SurfaceFormat::HalfSingle => HalfSingle::from_packed_value(value16).to_vector4(),
+            SurfaceFormat::HalfVector2 => HalfVector2::from_packed_value(value32).to_vector4(),
+            SurfaceFormat::HalfVector4 | SurfaceFormat::HdrBlendable => HalfVector4::from_packed_value(value64).to_vector4(),
+            _ => return Err(Self::unsupported_pixel_format()),
+        };
+
+        Ok(vector)
+    }
+
+    pub fn pack_pixel(&self, vector: Vector4, data: &mut [u8]) -> Result<(), Exception> {
+        let size = self.validate_pixel(data.len())?;
+        let mut bytes = [0u8; 16];
+
+        match self.to_linear() {
+            SurfaceFormat::Color => bytes[..4].copy_from_slice(&Color::from_vector4(vector).packed_value.to_le_bytes()),
+            SurfaceFormat::Bgra32 | SurfaceFormat::Bgr32 => {
+                let w = if self.to_linear() == SurfaceFormat::Bgr32 { 1.0 } else { vector.w };
+                let color = Color::from_vector4(Vector4 { x: vector.z, y: vector.y, z: vector.x, w });
+
+                bytes[..4].copy_from_slice(&color.packed_value.to_le_bytes());
+            }
+            SurfaceFormat::Bgr565 => bytes[..2].copy_from_slice(&<Bgr565 as IPackedVector>::from_vector4(vector).packed_value().to_le_bytes()),
+            SurfaceFormat::Bgra5551 => bytes[..2].copy_from_slice(&Bgra5551::from_vector4(vector).packed_value().to_le_bytes()),
+            SurfaceFormat::Bgra4444 => bytes[..2].copy_from_slice(&Bgra4444::from_vector4(vector).packed_value().to_le_bytes()),
+            SurfaceFormat::NormalizedByte2 => bytes[..2].copy_from_slice(&NormalizedByte2::from_vector4(vector).packed_value().to_le_bytes()),
+            SurfaceFormat::NormalizedByte4 => bytes[..4].copy_from_slice(&NormalizedByte4::from_vector4(vector).packed_value().to_le_bytes()),
+            SurfaceFormat::Rgba1010102 => bytes[..4].copy_from_slice(&Rgba1010102::from_vector4(vector).packed_value().to_le_bytes()),
+            SurfaceFormat::Rg32 => bytes[..4].copy_from_slice(&Rg32::from_vector4(vector).packed_value().to_le_bytes()),
+            SurfaceFormat::Rgba64 => bytes[..8].copy_from_slice(&Rgba64::from_vector4(vector).packed_value().to_le_bytes()),
+            SurfaceFormat::Alpha8 => bytes[0] = Alpha8::from_vector4(vector).packed_value(),
+            SurfaceFormat::Single => bytes[..4].copy_from_slice(&vector.x.to_le_bytes()),
+            SurfaceFormat::Vector2 => {
+                bytes[..4].copy_from_slice(&vector.x.to_le_bytes());
+                bytes[4..8].copy_from_slice(&vector.y.to_le_bytes());
+            }
+            SurfaceFormat::Vector4 => {
+                for (index, value) in [vector.x, vector.y, vector.z, vector.w].iter().enumerate() {
+                    bytes[index * 4..index * 4 + 4].copy_from_slice(&value.to_le_bytes());
+                }
+            }
+            SurfaceFormat::HalfSingle => bytes[..2].copy_from_slice(&HalfSingle::from_vector4(vector).packed_value().to_le_bytes()),
+            SurfaceFormat::HalfVector2 => bytes[..4].copy_from_slice(&HalfVector2::from_vector4(vector).packed_value().to_le_bytes()),
+            SurfaceFormat::HalfVector4 | SurfaceFormat::HdrBlendable => {
+                bytes[..8].copy_from_slice(&HalfVector4::from_vector4(vector).packed_value().to_le_bytes())
+            }
+            _ => return Err(Self::unsupported_pixel_format()),
+        }
+
+        data[..size].copy_from_slice(&bytes[..size]);
+        Ok(())
+    }
+
+    fn validate_pixel(&self, length: usize) -> Result<usize, Exception> {
+        if self.is_compressed() || self.size() == 0 {
+            return Err(Self::unsupported_pixel_format());
+        }
+
+        let size = self.size() as usize;
+
+        if length < size {
+            return Err(Exception::argument_exception("data is smaller than one pixel of the surface format.", None));
+        }
+
+        Ok(size)
+    }
+
+    fn unsupported_pixel_format() -> Exception {
+        Exception::invalid_operation("The surface format does not support per-pixel access.", None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packed_type_has_no_packed_vector_for_swizzled_or_compressed_formats() {
+        assert!(SurfaceFormat::Color.packed_type() == Some(PackedVectorType::Color));
+        assert!(SurfaceFormat::ColorSRgb.packed_type() == Some(PackedVectorType::Color));
+        assert!(SurfaceFormat::HdrBlendable.packed_type() == Some(PackedVectorType::HalfVector4));
+        assert!(SurfaceFormat::Bgr32.packed_type().is_none());
+        assert!(SurfaceFormat::Bgra32SRgb.packed_type().is_none());
+        assert!(SurfaceFormat::Dxt1.packed_type().is_none());
+        assert!(SurfaceFormat::Unknown.packed_type().is_none());
+    }
+}
@@ -0,0 +1,146 @@
+use crate::xna::csharp::Exception;
+use crate::xna::framework::{Color, Vector4};
+use crate::xna::framework::graphics::{IPackedVector, MipmapFilter, TexelBuffer};
+
+impl TexelBuffer {
+    pub fn new(width: u32, height: u32) -> Self {
+        TexelBuffer {
+            width,
+            height,
+            texels: vec![Vector4::zero(); (width * height) as usize],
+        }
+    }
+
+    pub fn from_texels(width: u32, height: u32, texels: Vec<Vector4>) -> Result<Self, Exception> {
+        if texels.len() != (width * height) as usize {
+            return Err(Exception::argument_exception("texels does not match the buffer dimensions.", None));
+        }
+
+        Ok(TexelBuffer { width, height, texels })
+    }
+
+    pub fn from_colors(width: u32, height: u32, colors: &[Color]) -> Result<Self, Exception> {
+        Self::from_texels(width, height, colors.iter().map(|color| color.to_vector4()).collect())
+    }
+
+    pub fn get(&self, x: u32, y: u32) -> Vector4 {
+        self.texels[(y * self.width + x) as usize]
+    }
+
+    pub fn set(&mut self, x: u32, y: u32, value: Vector4) {
+        self.texels[(y * self.width + x) as usize] = value;
+    }
+
+    pub fn downsample(&self, width: u32, height: u32, filter: MipmapFilter) -> TexelBuffer {
+        let horizontal = Self::filter_weights(self.width, width, filter);
+        let vertical = Self::filter_weights(self.height, height, filter);
+
+        let mut intermediate = TexelBuffer::new(width, self.height);
+
+        for y in 0..self.height {
+            for (x, weights) in horizontal.iter().enumerate() {
+                let mut value = Vector4::zero();
+
+                for (source, weight) in weights {
+                    value += self.get(*source, y) * *weight;
+                }
+
+                intermediate.set(x as u32, y, value);
+            }
+        }
+
+        let mut result = TexelBuffer::new(width, height);
+
+        for (y, weights) in vertical.iter().enumerate() {
+            for x in 0..width {
+                let mut value = Vector4::zero();
+
+                for (source, weight) in weights {
+                    value += intermediate.get(x, *source) * *weight;
+                }
+
+                result.set(x, y as u32, value);
+            }
+        }
+
+        result
+    }
+
+    pub fn alpha_coverage(&self, reference: f32) -> f32 {
+        if self.texels.is_empty() {
+            return 0.0;
+        }
+
+        let covered = self.texels.iter().filter(|texel| texel.w > reference).count();
+        covered as f32 / self.texels.len() as f32
+    }
+
+    pub fn scale_alpha_to_coverage(&mut self, coverage: f32, reference: f32) {
+        let mut minimum = 0.0f32;
+        let mut maximum = 4.0f32;
+        let mut scale = 1.0f32;
+        let mut best_scale = 1.0f32;
+        let mut best_error = f32::MAX;
+
+        for _ in 0..16 {
+            let covered = self.texels.iter().filter(|texel| (texel.w * scale).min(1.0) > reference).count();
+            let current = covered as f32 / self.texels.len().max(1) as f32;
+            let error = (current - coverage).abs();
+
+            if error < best_error {
+                best_error = error;
+                best_scale = scale;
+            }
+
+            if current < coverage {
+                minimum = scale;
+            } else if current > coverage {
+                maximum = scale;
+            } else {
+                break;
+            }
+
+            scale = (minimum + maximum) / 2.0;
+        }
+
+        for texel in self.texels.iter_mut() {
+            texel.w = (texel.w * best_scale).clamp(0.0, 1.0);
+        }
+    }
+
+    fn filter_weights(source_size: u32, target_size: u32, filter: MipmapFilter) -> Vec<Vec<(u32, f32)>> {
+        let scale = source_size as f32 / target_size as f32;
+        let support = filter.support() * scale.max(1.0);
+
+        (0..target_size)
+            .map(|target| {
+                let center = (target as f32 + 0.5) * scale;
+                let start = (center - support).floor() as i64;
+                let end = (center + support).ceil() as i64;
+
+                let mut weights: Vec<(u32, f32)> = Vec::new();
+
+                for source in start..=end {
+                    let weight = filter.weight((source as f32 + 0.5 - center) / scale.max(1.0));
+
+                    if weight != 0.0 {
+                        let index = source.clamp(0, source_size as i64 - 1) as u32;
+
+                        match weights.iter_mut().find(|(existing, _)| *existing == index) {
+                            Some((_, existing)) => *existing += weight,
+                            None => weights.push((index, weight)),
+                        }
+                    }
+                }
+
+                let total: f32 = weights.iter().map(|(_, weight)| weight).sum();
+
+                if total != 0.0 {
+                    weights.iter_mut().for_each(|(_, weight)| *weight /= total);
+                }
+
+                weights
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,435 @@
+use crate::xna::csharp::Exception;
+use crate::xna::csharp::io::{MemoryStream, Stream, StreamHelper};
+use crate::xna::framework::{Color, Rectangle, Vector2};
+use crate::xna::framework::graphics::{BmpCodec, DdsCodec, DxtCodec, DxtCompressionQuality, ITextureData, ImageCodec, ImageFileFormat, MipmapOptions, PngCodec, SamplerState, SurfaceFormat, TexelBuffer, Texture2D, TgaCodec};
+
+impl Texture2D {
+    pub fn new(width: u32, height: u32) -> Result<Texture2D, Exception> {
+        Self::new_with_format(width, height, false, SurfaceFormat::Color)
+    }
+
+    pub fn new_with_format(width: u32, height: u32, mip_map: bool, format: SurfaceFormat) -> Result<Texture2D, Exception> {
+        if width == 0 || height == 0 {
+            return Err(Exception::out_of_range("width and height must be greater than zero.", None));
+        }
+
+        if format.size() == 0 {
+            return Err(Exception::argument_exception("The surface format cannot be used for a texture.", None));
+        }
+
+        let level_count = if mip_map { Self::full_level_count(width, height) } else { 1 };
+
+        let levels = (0..level_count)
+            .map(|level| vec![0u8; format.surface_size((width >> level).max(1), (height >> level).max(1)) as usize])
+            .collect();
+
+        Ok(Texture2D { width, height, format, levels })
+    }
+
+    pub fn full_level_count(width: u32, height: u32) -> u32 {
+        32 - width.max(height).max(1).leading_zeros()
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn format(&self) -> SurfaceFormat {
+        self.format
+    }
+
+    pub fn level_count(&self) -> u32 {
+        self.levels.len() as u32
+    }
+
+    pub fn level_width(&self, level: u32) -> u32 {
+        (self.width >> level).max(1)
+    }
+
+    pub fn level_height(&self, level: u32) -> u32 {
+        (self.height >> level).max(1)
+    }
+
+    pub fn bounds(&self) -> Rectangle {
+        Rectangle::new(0, 0, self.width as i32, self.height as i32)
+    }
+
+    pub fn level_data(&self, level: u32) -> Result<&[u8], Exception> {
+        self.validate_level(level)?;
+        Ok(&self.levels[level as usize])
+    }
+
+    pub fn get_data<T: ITextureData>(&self, level: Option<u32>, rect: Option<Rectangle>, data: &mut [T]) -> Result<(), Exception> {
+        let level = level.unwrap_or(0);
+        let (offset, row_size, row_count) = self.validate_data::<T>(level, rect, data.len())?;
+        let pitch = self.format.pitch(self.level_width(level)) as usize;
+        let source = &self.levels[level as usize];
+
+        let mut bytes = vec![0u8; row_size * row_count];
+
+        for row in 0..row_count {
+            let start = offset + row * pitch;
+            bytes[row * row_size..(row + 1) * row_size].copy_from_slice(&source[start..start + row_size]);
+        }
+
+        for (index, value) in data.iter_mut().enumerate() {
+            *value = T::read_bytes(&bytes[index * T::SIZE..]);
+        }
+
+        Ok(())
+    }
+
+    pub fn set_data<T: ITextureData>(&mut self, level: Option<u32>, rect: Option<Rectangle>, data: &[T]) -> Result<(), Exception> {
+        let level = level.unwrap_or(0);
+        let (offset, row_size, row_count) = self.validate_data::<T>(level, rect, data.len())?;
+        let pitch = self.format.pitch(self.level_width(level)) as usize;
+
+        let mut bytes = vec![0u8; row_size * row_count];
+
+        for (index, value) in data.iter().enumerate() {
+            value.write_bytes(&mut bytes[index * T::SIZE..]);
+        }
+
+        let destination = &mut self.levels[level as usize];
+
+        for row in 0..row_count {
+            let start = offset + row * pitch;
+            destination[start..start + row_size].copy_from_slice(&bytes[row * row_size..(row + 1) * row_size]);
+        }
+
+        Ok(())
+    }
+
+    pub fn get_texel_buffer(&self, level: u32) -> Result<TexelBuffer, Exception> {
+        self.validate_level(level)?;
+
+        let width = self.level_width(level);
+        let height = self.level_height(level);
+        let size = self.format.size() as usize;
+        let source = &self.levels[level as usize];
+
+        if self.format.is_compressed() {
+            let colors = DxtCodec::decode(self.format, width, height, source)?;
+            return TexelBuffer::from_colors(width, height, &colors);
+        }
+
+        let mut texels = Vec::with_capacity((width * height) as usize);
+
+        for index in 0..(width * height) as usize {
+            texels.push(self.format.unpack_pixel(&source[index * size..])?);
+        }
+
+        TexelBuffer::from_texels(width, height, texels)
+    }
+
+    pub fn set_texel_buffer(&mut self, level: u32, buffer: &TexelBuffer) -> Result<(), Exception> {
+        self.set_texel_buffer_with_quality(level, buffer, DxtCompressionQuality::default())
+    }
+
+    pub fn set_texel_buffer_with_quality(&mut self, level: u32, buffer: &TexelBuffer, quality: DxtCompressionQuality) -> Result<(), Exception> {
+        self.validate_level(level)?;
+
+        if buffer.width != self.level_width(level) || buffer.height != self.level_height(level) {
+            return Err(Exception::argument_exception("buffer does not match the dimensions of the mip level.", None));
+        }
+
+        if self.format.is_compressed() {
+            let colors: Vec<Color> = buffer.texels.iter().map(|texel| Color::from_vector4(*texel)).collect();
+            self.levels[level as usize] = DxtCodec::encode(self.format, buffer.width, buffer.height, &colors, quality)?;
+
+            return Ok(());
+        }
+
+        let size = self.format.size() as usize;
+        let format = self.format;
+        let destination = &mut self.levels[level as usize];
+
+        for (index, texel) in buffer.texels.iter().enumerate() {
+            format.pack_pixel(*texel, &mut destination[index * size..])?;
+        }
+
+        Ok(())
+    }
+
+    pub fn get_texel_buffers(&self) -> Result<Vec<TexelBuffer>, Exception> {
+        (0..self.level_count()).map(|level| self.get_texel_buffer(level)).collect()
+    }
+
+    pub fn convert(&self, format: SurfaceFormat, quality: DxtCompressionQuality) -> Result<Texture2D, Exception> {
+        let mut texture = Self::new_with_format(self.width, self.height, self.level_count() > 1, format)?;
+        texture.levels.truncate(self.levels.len());
+
+        for level in 0..self.level_count() {
+            texture.set_texel_buffer_with_quality(level, &self.get_texel_buffer(level)?, quality)?;
+        }
+
+        Ok(texture)
+    }
+
+    pub fn generate_mipmaps(&mut self, options: &MipmapOptions) -> Result<(), Exception> {
+        if self.format.is_compressed() {
+            return Err(Exception::invalid_operation("Mipmaps cannot be generated for compressed surface formats.", None));
+        }
+
+        if options.gamma_correct && !self.format.is_unorm() {
+            return Err(Exception::invalid_operation("Gamma-correct mipmaps require an unsigned normalized surface format.", None));
+        }
+
+        let gamma_correct = options.gamma_correct || self.format.is_srgb();
+        let mut current = self.get_texel_buffer(0)?;
+
+        if gamma_correct {
+            Self::convert_texels(&mut current, Color::srgb_to_linear);
+        }
+
+        let coverage = options.alpha_coverage_reference.map(|reference| (reference, current.alpha_coverage(reference)));
+        let level_count = Self::full_level_count(self.width, self.height);
+
+        self.levels.truncate(1);
+
+        for level in 1..level_count {
+            let width = self.level_width(level);
+            let height = self.level_height(level);
+
+            current = current.downsample(width, height, options.filter);
+
+            let mut output = current.clone();
+
+            if let Some((reference, value)) = coverage {
+                output.scale_alpha_to_coverage(value, reference);
+            }
+
+            if gamma_correct {
+                Self::convert_texels(&mut output, Color::linear_to_srgb);
+            }
+
+            self.levels.push(vec![0u8; self.format.surface_size(width, height) as usize]);
+            self.set_texel_buffer(level, &output)?;
+        }
+
+        Ok(())
+    }
+
+    fn convert_texels(buffer: &mut TexelBuffer, conversion: fn(f32) -> f32) {
+        for texel in buffer.texels.iter_mut() {
+            texel.x = conversion(texel.x.clamp(0.0, 1.0));
+            texel.y = conversion(texel.y.clamp(0.0, 1.0));
+            texel.z = conversion(texel.z.clamp(0.0, 1.0));
+        }
+    }
+
+    pub fn from_stream(stream: &mut dyn Stream) -> Result<Texture2D, Exception> {
+        let mut data = Vec::new();
+        let mut buffer = vec![0u8; 81920];
+
+        loop {
+            let read = StreamHelper::read(stream, &mut buffer)?;
+
+            if read == 0 {
+                break;
+            }
+
+            data.extend_from_slice(&buffer[..read as usize]);
+        }
+
+        Self::from_bytes(&data)
+    }
+
+    pub fn from_file(path: &str) -> Result<Texture2D, Exception> {
+        let data = std::fs::read(path).map_err(|error| Exception::new(&error.to_string(), None))?;
+        let mut stream = MemoryStream::with_buffer(&data, false);
+
+        Self::from_stream(&mut stream)
+    }
+
+    pub fn from_bytes(data: &[u8]) -> Result<Texture2D, Exception> {
+        match ImageCodec::detect_format(data) {
+            ImageFileFormat::Png => PngCodec::decode(data),
+            ImageFileFormat::Bmp => BmpCodec::decode(data),
+            ImageFileFormat::Dds => DdsCodec::decode(data),
+            ImageFileFormat::Tga => TgaCodec::decode(data),
+        }
+    }
+
+    pub fn save_as_png(&self, stream: &mut dyn Stream, width: u32, height: u32) -> Result<(), Exception> {
+        let pixels = self.get_scaled_colors(width, height)?;
+        StreamHelper::write(stream, &PngCodec::encode(width, height, &pixels)?)
+    }
+
+    pub fn save_as_bmp(&self, stream: &mut dyn Stream, width: u32, height: u32) -> Result<(), Exception> {
+        let pixels = self.get_scaled_colors(width, height)?;
+        StreamHelper::write(stream, &BmpCodec::encode(width, height, &pixels)?)
+    }
+
+    fn get_scaled_colors(&self, width: u32, height: u32) -> Result<Vec<Color>, Exception> {
+        ImageCodec::validate_dimensions(width, height)?;
+
+        let buffer = self.get_texel_buffer(0)?;
+
+        if width == buffer.width && height == buffer.height {
+            return Ok(buffer.texels.iter().map(|texel| Color::from_vector4(*texel)).collect());
+        }
+
+        let sampler = SamplerState::linear_clamp();
+        let mut pixels = Vec::with_capacity((width * height) as usize);
+
+        for y in 0..height {
+            for x in 0..width {
+                let coordinate = Vector2 {
+                    x: (x as f32 + 0.5) / width as f32,
+                    y: (y as f32 + 0.5) / height as f32,
+                };
+
+                pixels.push(Color::from_vector4(sampler.sample_level(&buffer, &coordinate, true)));
+            }
+        }
+
+        Ok(pixels)
+    }
+
+    fn validate_level(&self, level: u32) -> Result<(), Exception> {
+        if level >= self.level_count() {
+            return Err(Exception::out_of_range("level is greater than the number of mip levels in the texture.", None));
+        }
+
+        Ok(())
+    }
+
+    fn validate_data<T: ITextureData>(&self, level: u32, rect: Option<Rectangle>, element_count: usize) -> Result<(usize, usize, usize), Exception> {
+        self.validate_level(level)?;
+
+        let format_size = self.format.size() as usize;
+
+        if T::SIZE == 0 || (!format_size.is_multiple_of(T::SIZE) && !T::SIZE.is_multiple_of(format_size)) {
+            return Err(Exception::argument_exception("The type you are using for T in this method is an invalid size for this resource.", None));
+        }
+
+        let width = self.level_width(level) as i32;
+        let height = self.level_height(level) as i32;
+        let rect = rect.unwrap_or(Rectangle::new(0, 0, width, height));
+
+        if rect.x < 0 || rect.y < 0 || rect.width <= 0 || rect.height <= 0 || rect.right() > width || rect.bottom() > height {
+            return Err(Exception::argument_exception("The rectangle is outside the bounds of the mip level.", None));
+        }
+
+        let block_size = self.format.block_size() as i32;
+        let is_aligned = |start: i32, length: i32, size: i32| start % block_size == 0 && (length % block_size == 0 || start + length == size);
+
+        if !is_aligned(rect.x, rect.width, width) || !is_aligned(rect.y, rect.height, height) {
+            return Err(Exception::argument_exception("The rectangle must be aligned to the block size of a compressed format.", None));
+        }
+
+        let pitch = self.format.pitch(width as u32) as usize;
+        let offset = (rect.y / block_size) as usize * pitch + (rect.x / block_size) as usize * format_size;
+        let row_size = self.format.pitch(rect.width as u32) as usize;
+        let row_count = (rect.height as u32).div_ceil(block_size as u32) as usize;
+
+        if element_count * T::SIZE != row_size * row_count {
+            return Err(Exception::argument_exception("The size of the data passed in is too large or too small for this resource.", None));
+        }
+
+        Ok((offset, row_size, row_count))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::xna::framework::{Vector3, Vector4};
+
+    fn vector(x: f32, y: f32, z: f32, w: f32) -> Vector4 {
+        Vector4 { x, y, z, w }
+    }
+
+    #[test]
+    fn accessors_report_the_texture_description() {
+        let texture = Texture2D::new_with_format(8, 4, true, SurfaceFormat::Bgr565).unwrap();
+
+        assert_eq!((texture.width(), texture.height(), texture.level_count()), (8, 4, 4));
+        assert!(texture.format() == SurfaceFormat::Bgr565);
+        assert_eq!((texture.level_width(3), texture.level_height(3)), (1, 1));
+        assert_eq!(texture.level_data(2).unwrap().len(), 4);
+        assert!(texture.level_data(4).is_err());
+    }
+
+    #[test]
+    fn sub_rectangle_get_and_set_data_touch_only_the_rectangle() {
+        let mut texture = Texture2D::new(4, 3).unwrap();
+        let values: Vec<u32> = (0..12).collect();
+        texture.set_data(None, None, &values).unwrap();
+
+        let mut region = [0u32; 4];
+        texture.get_data(None, Some(Rectangle::new(1, 1, 2, 2)), &mut region).unwrap();
+        assert_eq!(region, [5, 6, 9, 10]);
+
+        texture.set_data(None, Some(Rectangle::new(2, 0, 2, 1)), &[100u32, 101]).unwrap();
+
+        let mut all = [0u32; 12];
+        texture.get_data(None, None, &mut all).unwrap();
+        assert_eq!(all, [0, 1, 100, 101, 4, 5, 6, 7, 8, 9, 10, 11]);
+    }
+
+    #[test]
+    fn get_and_set_data_reject_invalid_requests() {
+        let mut texture = Texture2D::new_with_format(2, 2, false, SurfaceFormat::Vector4).unwrap();
+
+        assert!(texture.set_data(None, None, &[Vector3::zero(); 4]).is_err());
+        assert!(texture.get_data(None, None, &mut [0u64; 8]).is_ok());
+        assert!(texture.get_data(None, None, &mut [0u64; 7]).is_err());
+        assert!(texture.get_data(None, Some(Rectangle::new(1, 1, 2, 1)), &mut [Vector4::zero(); 2]).is_err());
+        assert!(texture.get_data(Some(1), None, &mut [Vector4::zero(); 1]).is_err());
+
+        let mut half = Texture2D::new_with_format(2, 2, false, SurfaceFormat::HalfVector4).unwrap();
+        assert!(half.set_data(None, None, &[Vector3::zero(); 4]).is_err());
+        assert!(half.set_data(None, None, &[0u32; 8]).is_ok());
+    }
+
+    #[test]
+    fn generate_mipmaps_preserves_hdr_values() {
+        let mut texture = Texture2D::new_with_format(2, 2, false, SurfaceFormat::Vector4).unwrap();
+        texture.set_data(None, None, &[vector(4.0, -2.0, 0.5, 1.0), vector(8.0, -2.0, 0.5, 1.0), vector(4.0, -2.0, 0.5, 1.0), vector(8.0, -2.0, 0.5, 1.0)]).unwrap();
+        texture.generate_mipmaps(&MipmapOptions::default()).unwrap();
+
+        assert_eq!(texture.get_texel_buffer(1).unwrap().texels, vec![vector(6.0, -2.0, 0.5, 1.0)]);
+    }
+
+    #[test]
+    fn generate_mipmaps_preserves_signed_values() {
+        let mut texture = Texture2D::new_with_format(2, 1, false, SurfaceFormat::NormalizedByte4).unwrap();
+        let buffer = TexelBuffer::from_texels(2, 1, vec![vector(-1.0, -0.5, 0.0, 1.0); 2]).unwrap();
+        texture.set_texel_buffer(0, &buffer).unwrap();
+        texture.generate_mipmaps(&MipmapOptions::default()).unwrap();
+
+        let texel = texture.get_texel_buffer(1).unwrap().texels[0];
+
+        assert_eq!(texel.x, -1.0);
+        assert!((texel.y + 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn generate_mipmaps_rejects_gamma_correction_for_float_and_signed_formats() {
+        let options = MipmapOptions { gamma_correct: true, ..MipmapOptions::default() };
+
+        for format in [SurfaceFormat::Vector4, SurfaceFormat::HalfVector4, SurfaceFormat::HdrBlendable, SurfaceFormat::NormalizedByte4] {
+            let mut texture = Texture2D::new_with_format(2, 2, false, format).unwrap();
+
+            assert!(texture.generate_mipmaps(&options).is_err());
+        }
+    }
+
+    #[test]
+    fn generate_mipmaps_averages_in_linear_space_when_gamma_correct() {
+        let mut texture = Texture2D::new(2, 1).unwrap();
+        texture.set_data(None, None, &[Color::black(), Color::white()]).unwrap();
+        texture.generate_mipmaps(&MipmapOptions { gamma_correct: true, ..MipmapOptions::default() }).unwrap();
+
+        let mut result = [Color::transparent()];
+        texture.get_data(Some(1), None, &mut result).unwrap();
+
+        assert_eq!((result[0].r(), result[0].g(), result[0].b(), result[0].a()), (188, 188, 188, 255));
+    }
+}
@@ -0,0 +1,122 @@
+use crate::xna::framework::{Color, Vector2, Vector3, Vector4};
+use crate::xna::framework::graphics::{Alpha8, Bgr565, Bgra4444, Bgra5551, Byte4, HalfSingle, HalfVector2, HalfVector4, ITextureData, NormalizedByte2, NormalizedByte4, NormalizedShort2, NormalizedShort4, PackedVector, Rg32, Rgba1010102, Rgba64, Short2, Short4};
+
+macro_rules! impl_texture_data_primitive {
+    ($type:ty) => {
+        impl ITextureData for $type {
+            const SIZE: usize = size_of::<$type>();
+
+            fn read_bytes(bytes: &[u8]) -> Self {
+                <$type>::from_le_bytes(bytes[..Self::SIZE].try_into().unwrap())
+            }
+
+            fn write_bytes(&self, bytes: &mut [u8]) {
+                bytes[..Self::SIZE].copy_from_slice(&self.to_le_bytes());
+            }
+        }
+    };
+}
+
+macro_rules! impl_texture_data_packed {
+    ($vector:ident, $packed:ty) => {
+        impl ITextureData for $vector {
+            const SIZE: usize = size_of::<$packed>();
+
+            fn read_bytes(bytes: &[u8]) -> Self {
+                $vector::from_packed_value(<$packed>::read_bytes(bytes))
+            }
+
+            fn write_bytes(&self, bytes: &mut [u8]) {
+                self.packed_value().write_bytes(bytes);
+            }
+        }
+    };
+}
+
+impl_texture_data_primitive!(u8);
+impl_texture_data_primitive!(i8);
+impl_texture_data_primitive!(u16);
+impl_texture_data_primitive!(i16);
+impl_texture_data_primitive!(u32);
+impl_texture_data_primitive!(i32);
+impl_texture_data_primitive!(u64);
+impl_texture_data_primitive!(i64);
+impl_texture_data_primitive!(f32);
+impl_texture_data_primitive!(f64);
+
+impl_texture_data_packed!(Alpha8, u8);
+impl_texture_data_packed!(Bgr565, u16);
+impl_texture_data_packed!(Bgra4444, u16);
+impl_texture_data_packed!(Bgra5551, u16);
+impl_texture_data_packed!(Byte4, u32);
+impl_texture_data_packed!(HalfSingle, u16);
+impl_texture_data_packed!(HalfVector2, u32);
+impl_texture_data_packed!(HalfVector4, u64);
+impl_texture_data_packed!(NormalizedByte2, u16);
+impl_texture_data_packed!(NormalizedByte4, u32);
+impl_texture_data_packed!(NormalizedShort2, u32);
+impl_texture_data_packed!(NormalizedShort4, u64);
+impl_texture_data_packed!(Rg32, u32);
+impl_texture_data_packed!(Rgba1010102, u32);
+impl_texture_data_packed!(Rgba64, u64);
+impl_texture_data_packed!(Short2, u32);
+impl_texture_data_packed!(Short4, u64);
+
+impl ITextureData for Color {
+    const SIZE: usize = 4;
+
+    fn read_bytes(bytes: &[u8]) -> Self {
+        Color::from_packed_value(u32::read_bytes(bytes))
+    }
+
+    fn write_bytes(&self, bytes: &mut [u8]) {
+        self.packed_value.write_bytes(bytes);
+    }
+}
+
+impl ITextureData for Vector2 {
+    const SIZE: usize = 8;
+
+    fn read_bytes(bytes: &[u8]) -> Self {
+        Vector2 { x: f32::read_bytes(bytes), y: f32::read_bytes(&bytes[4..]) }
+    }
+
+    fn write_bytes(&self, bytes: &mut [u8]) {
+        self.x.write_bytes(bytes);
+        self.y.write_bytes(&mut bytes[4..]);
+    }
+}
+
+impl ITextureData for Vector3 {
+    const SIZE: usize = 12;
+
+    fn read_bytes(bytes: &[u8]) -> Self {
+        Vector3 { x: f32::read_bytes(bytes), y: f32::read_bytes(&bytes[4..]), z: f32::read_bytes(&bytes[8..]) }
+    }
+
+    fn write_bytes(&self, bytes: &mut [u8]) {
+        self.x.write_bytes(bytes);
+        self.y.write_bytes(&mut bytes[4..]);
+        self.z.write_bytes(&mut bytes[8..]);
+    }
+}
+
+impl ITextureData for Vector4 {
+    const SIZE: usize = 16;
+
+    fn read_bytes(bytes: &[u8]) -> Self {
+        Vector4 {
+            x: f32::read_bytes(bytes),
+            y: f32::read_bytes(&bytes[4..]),
+            z: f32::read_bytes(&bytes[8..]),
+            w: f32::read_bytes(&bytes[12..]),
+        }
+    }
+
+    fn write_bytes(&self, bytes: &mut [u8]) {
+        self.x.write_bytes(bytes);
+        self.y.write_bytes(&mut bytes[4..]);
+        self.z.write_bytes(&mut bytes[8..]);
+        self.w.write_bytes(&mut bytes[12..]);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::xna::csharp::Exception;
+use crate::xna::framework::Color;
+use crate::xna::framework::graphics::{ImageCodec, TgaCodec, Texture2D};
+
+impl TgaCodec {
+    const HEADER_SIZE: usize = 18;
+
+    pub fn decode(data: &[u8]) -> Result<Texture2D, Exception> {
+        let header = ImageCodec::bytes(data, 0, Self::HEADER_SIZE)?;
+
+        let id_length = header[0] as usize;
+        let color_map_type = header[1];
+        let image_type = header[2];
+        let color_map_first = u16::from_le_bytes([header[3], header[4]]) as usize;
+        let color_map_length = u16::from_le_bytes([header[5], header[6]]) as usize;
+        let color_map_entry_size = header[7] as u32;
+        let width = u16::from_le_bytes([header[12], header[13]]) as u32;
+        let height = u16::from_le_bytes([header[14], header[15]]) as u32;
+        let pixel_depth = header[16] as u32;
+        let descriptor = header[17];
+
+        ImageCodec::validate_dimensions(width, height)?;
+
+        let is_color_mapped = matches!(image_type, 1 | 9);
+        let is_gray = matches!(image_type, 3 | 11);
+        let is_compressed = image_type >= 9;
+
+        let is_valid = match image_type {
+            1 | 9 => color_map_type == 1 && matches!(pixel_depth, 8 | 16),
+            2 | 10 => matches!(pixel_depth, 15 | 16 | 24 | 32),
+            3 | 11 => matches!(pixel_depth, 8 | 16),
+            _ => false,
+        };
+
+        if !is_valid {
+            return Err(Exception::new("The TGA image type is not supported.", None));
+        }
+
+        if color_map_type == 1 && !matches!(color_map_entry_size, 15 | 16 | 24 | 32) {
+            return Err(Exception::new("The TGA color map entry size is not supported.", None));
+        }
+
+        let mut offset = Self::HEADER_SIZE + id_length;
+        let alpha_bits = (descriptor & 0x0F) as u32;
+
+        let color_map: Vec<Color> = if color_map_type == 1 {
+            let entry_size = color_map_entry_size.div_ceil(8) as usize;
+            let bytes = ImageCodec::bytes(data, offset, color_map_length * entry_size)?;
+            offset += bytes.len();
+
+            bytes.chunks_exact(entry_size).map(|entry| Self::read_true_color(entry, color_map_entry_size, 8)).collect()
+        } else {
+            Vec::new()
+        };
+
+        let pixel_size = pixel_depth.div_ceil(8) as usize;
+        let pixel_count = (width * height) as usize;
+
+        let raw = if is_compressed {
+            Self::decompress(data, offset, pixel_count, pixel_size)?
+        } else {
+            ImageCodec::bytes(data, offset, pixel_count * pixel_size)?.to_vec()
+        };
+
+        let mut pixels: Vec<Color> = raw
+            .chunks_exact(pixel_size)
+            .map(|bytes| {
+                if is_color_mapped {
+                    let index = if pixel_size == 2 { u16::from_le_bytes([bytes[0], bytes[1]]) as usize } else { bytes[0] as usize };
+                    color_map.get(index.wrapping_sub(color_map_first)).copied().unwrap_or(Color::black())
+                } else if is_gray {
+                    let alpha = if pixel_size == 2 { bytes[1] } else { 255 };
+                    ImageCodec::rgba(bytes[0], bytes[0], bytes[0], alpha)
+                } else {
+                    Self::read_true_color(bytes, pixel_depth, alpha_bits)
+                }
+            })
+            .collect();
+
+        if pixel_depth == 32 && !is_color_mapped && pixels.iter().all(|pixel| pixel.a() == 0) {
+            pixels.iter_mut().for_each(|pixel| pixel.set_a(255));
+        }
+
+        let is_top_down = descriptor & 0x20 != 0;
+        let is_right_to_left = descriptor & 0x10 != 0;
+        let mut ordered = vec![Color::transparent(); pixel_count];
+
+        for y in 0..height as usize {
+            let source_y = if is_top_down { y } else { height as usize - 1 - y };
+
+            for x in 0..width as usize {
+                let source_x = if is_right_to_left { width as usize - 1 - x } else { x };
+                ordered[y * width as usize + x] = pixels[source_y * width as usize + source_x];
+            }
+        }
+
+        let mut texture = Texture2D::new(width, height)?;
+        texture.set_data(None, None, &ordered)?;
+
+        Ok(texture)
+    }
+
+    fn decompress(data: &[u8], offset: usize, pixel_count: usize, pixel_size: usize) -> Result<Vec<u8>, Exception> {
+        let mut result = Vec::with_capacity(pixel_count * pixel_size);
+        let mut position = offset;
+
+        while result.len() < pixel_count * pixel_size {
+            let packet = ImageCodec::read_u8(data, position)?;
+            let count = (packet & 0x7F) as usize + 1;
+            position += 1;
+
+            if packet & 0x80 != 0 {
+                let pixel = ImageCodec::bytes(data, position, pixel_size)?;
+                position += pixel_size;
+
+                for _ in 0..count {
+                    result.extend_from_slice(pixel);
+                }
+            } else {
+                result.extend_from_slice(ImageCodec::bytes(data, position, count * pixel_size)?);
+                position += count * pixel_size;
+            }
+        }
+
+        result.truncate(pixel_count * pixel_size);
+
+        Ok(result)
+    }
+
+    fn read_true_color(bytes: &[u8], depth: u32, alpha_bits: u32) -> Color {
+        match depth {
+            15 | 16 => {
+                let value = u16::from_le_bytes([bytes[0], bytes[1]]) as u32;
+                let alpha = if depth == 16 && alpha_bits > 0 && value & 0x8000 == 0 { 0 } else { 255 };
+
+                ImageCodec::rgba(
+                    ImageCodec::scale_bits((value >> 10) & 0x1F, 5),
+                    ImageCodec::scale_bits((value >> 5) & 0x1F, 5),
+                    ImageCodec::scale_bits(value & 0x1F, 5),
+                    alpha,
+                )
+            }
+            24 => ImageCodec::rgba(bytes[2], bytes[1], bytes[0], 255),
+            _ => ImageCodec::rgba(bytes[2], bytes[1], bytes[0], bytes[3]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn color_mapped(entry_size: u8, entries: &[u8], pixels: &[u8]) -> Vec<u8> {
+        let entry_count = (entries.len() / (entry_size as usize).div_ceil(8).max(1)) as u16;
+        let mut data = vec![0, 1, 1, 0, 0];
+        data.extend_from_slice(&entry_count.to_le_bytes());
+        data.extend_from_slice(&[entry_size, 0, 0, 0, 0, 2, 0, 1, 0, 8, 0x20]);
+        data.extend_from_slice(entries);
+        data.extend_from_slice(pixels);
+        data
+    }
+
+    #[test]
+    fn decode_reads_a_color_mapped_image() {
+        let data = color_mapped(24, &[0, 0, 255, 255, 0, 0], &[1, 0]);
+        let texture = TgaCodec::decode(&data).unwrap();
+        let mut pixels = [Color::transparent(); 2];
+        texture.get_data(None, None, &mut pixels).unwrap();
+
+        assert_eq!(pixels, [Color::blue(), Color::red()]);
+    }
+
+    #[test]
+    fn decode_rejects_a_zero_color_map_entry_size() {
+        let mut data = color_mapped(0, &[], &[0, 0]);
+        data[5] = 4;
+
+        assert!(TgaCodec::decode(&data).is_err());
+    }
+
+    #[test]
+    fn decode_rejects_an_8_bit_color_map_entry_size() {
+        let data = color_mapped(8, &[10, 20], &[0, 1]);
+
+        assert!(TgaCodec::decode(&data).is_err());
+    }
+}
@@ -0,0 +1,119 @@
+use std::ops::{Add, Mul, Sub};
+use crate::xna::framework::MathHelper;
+
+impl MathHelper {
+    pub const E: f32 = std::f32::consts::E;
+    pub const LOG10_E: f32 = std::f32::consts::LOG10_E;
+    pub const LOG2_E: f32 = std::f32::consts::LOG2_E;
+    pub const PI: f32 = std::f32::consts::PI;
+    pub const PI_OVER_2: f32 = std::f32::consts::FRAC_PI_2;
+    pub const PI_OVER_4: f32 = std::f32::consts::FRAC_PI_4;
+    pub const TWO_PI: f32 = std::f32::consts::TAU;
+
+    pub fn to_radians(degrees: f32) -> f32 {
+        degrees * (Self::PI / 180.0)
+    }
+
+    pub fn to_degrees(radians: f32) -> f32 {
+        radians * (180.0 / Self::PI)
+    }
+
+    pub fn wrap_angle(angle: f32) -> f32 {
+        let two_pi = Self::TWO_PI as f64;
+        let value = angle as f64;
+        let mut angle = (value - two_pi * (value / two_pi).round_ties_even()) as f32;
+
+        if angle <= -Self::PI {
+            angle += Self::TWO_PI;
+        } else if angle > Self::PI {
+            angle -= Self::TWO_PI;
+        }
+
+        angle
+    }
+
+    pub fn distance(value1: f32, value2: f32) -> f32 {
+        (value1 - value2).abs()
+    }
+
+    pub fn is_power_of_two(value: i32) -> bool {
+        value > 0 && (value & (value - 1)) == 0
+    }
+
+    pub fn min<T: PartialOrd>(value1: T, value2: T) -> T {
+        if value1 < value2 { value1 } else { value2 }
+    }
+
+    pub fn max<T: PartialOrd>(value1: T, value2: T) -> T {
+        if value1 > value2 { value1 } else { value2 }
+    }
+
+    pub fn clamp<T: PartialOrd>(value: T, min: T, max: T) -> T {
+        let value = if value > max { max } else { value };
+        if value < min { min } else { value }
+    }
+
+    pub fn lerp<T>(value1: T, value2: T, amount: f32) -> T
+    where T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T> {
+        value1 + (value2 - value1) * amount
+    }
+
+    pub fn lerp_precise<T>(value1: T, value2: T, amount: f32) -> T
+    where T: Copy + Add<Output = T> + Mul<f32, Output = T> {
+        value1 * (1.0 - amount) + value2 * amount
+    }
+
+    pub fn smooth_step<T>(value1: T, value2: T, amount: f32) -> T
+    where T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T> {
+        let num = Self::clamp(amount, 0.0, 1.0);
+        Self::lerp(value1, value2, num * num * (3.0 - 2.0 * num))
+    }
+
+    pub fn barycentric<T>(value1: T, value2: T, value3: T, amount1: f32, amount2: f32) -> T
+    where T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T> {
+        value1 + (value2 - value1) * amount1 + (value3 - value1) * amount2
+    }
+
+    pub fn hermite<T>(value1: T, tangent1: T, value2: T, tangent2: T, amount: f32) -> T
+    where T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T> {
+        if amount == 0.0 {
+            return value1;
+        }
+
+        if amount == 1.0 {
+            return value2;
+        }
+
+        let num1 = amount * amount;
+        let num2 = amount * num1;
+
+        value1 * (2.0 * num2 - 3.0 * num1 + 1.0)
+            + value2 * (-2.0 * num2 + 3.0 * num1)
+            + tangent1 * (num2 - 2.0 * num1 + amount)
+            + tangent2 * (num2 - num1)
+    }
+
+    pub fn catmull_rom<T>(value1: T, value2: T, value3: T, value4: T, amount: f32) -> T
+    where T: Copy + Add<Output = T> + Sub<Output = T> + Mul<f32, Output = T> {
+        let num1 = amount * amount;
+        let num2 = amount * num1;
+
+        (value2 * 2.0
+            + (value3 - value1) * amount
+            + (value1 * 2.0 - value2 * 5.0 + value3 * 4.0 - value4) * num1
+            + (value2 * 3.0 - value1 - value3 * 3.0 + value4) * num2) * 0.5
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrap_angle_matches_xna_at_the_boundaries() {
+        assert_eq!(MathHelper::wrap_angle(MathHelper::PI), MathHelper::PI);
+        assert_eq!(MathHelper::wrap_angle(-MathHelper::PI), MathHelper::PI);
+        assert_eq!(MathHelper::wrap_angle(MathHelper::TWO_PI), 0.0);
+        assert!((MathHelper::wrap_angle(MathHelper::PI * 3.5) + MathHelper::PI_OVER_2).abs() < 1e-5);
+    }
+}
@@ -0,0 +1,781 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::xna::csharp::Exception;
+use crate::xna::framework::{Matrix, Plane, Quaternion, Vector3};
+
+impl Matrix {
+    const DECOMPOSE_EPSILON: f32 = 0.0001;
+
+    pub fn identity() -> Matrix {
+        Matrix {
+            m11: 1.0,
+            m22: 1.0,
+            m33: 1.0,
+            m44: 1.0,
+            ..Default::default()
+        }
+    }
+
+    pub fn up(&self) -> Vector3 { Vector3 { x: self.m21, y: self.m22, z: self.m23 } }
+
+    pub fn down(&self) -> Vector3 { -self.up() }
+
+    pub fn right(&self) -> Vector3 { Vector3 { x: self.m11, y: self.m12, z: self.m13 } }
+
+    pub fn left(&self) -> Vector3 { -self.right() }
+
+    pub fn forward(&self) -> Vector3 { Vector3 { x: -self.m31, y: -self.m32, z: -self.m33 } }
+
+    pub fn backward(&self) -> Vector3 { -self.forward() }
+
+    pub fn translation(&self) -> Vector3 { Vector3 { x: self.m41, y: self.m42, z: self.m43 } }
+
+    pub fn set_translation(&mut self, value: &Vector3) {
+        self.m41 = value.x;
+        self.m42 = value.y;
+        self.m43 = value.z;
+    }
+
+    pub fn create_translation(position: &Vector3) -> Matrix {
+        Self::create_translation_xyz(position.x, position.y, position.z)
+    }
+
+    pub fn create_translation_xyz(x: f32, y: f32, z: f32) -> Matrix {
+        let mut matrix = Self::identity();
+        matrix.m41 = x;
+        matrix.m42 = y;
+        matrix.m43 = z;
+
+        matrix
+    }
+
+    pub fn create_scale(scale: f32) -> Matrix {
+        Self::create_scale_xyz(scale, scale, scale)
+    }
+
+    pub fn create_scale_vector(scales: &Vector3) -> Matrix {
+        Self::create_scale_xyz(scales.x, scales.y, scales.z)
+    }
+
+    pub fn create_scale_xyz(x: f32, y: f32, z: f32) -> Matrix {
+        Matrix {
+            m11: x,
+            m22: y,
+            m33: z,
+            m44: 1.0,
+            ..Default::default()
+        }
+    }
+
+    pub fn create_rotation_x(radians: f32) -> Matrix {
+        let cos = radians.cos();
+        let sin = radians.sin();
+
+        let mut matrix = Self::identity();
+        matrix.m22 = cos;
+        matrix.m23 = sin;
+        matrix.m32 = -sin;
+        matrix.m33 = cos;
+
+        matrix
+    }
+
+    pub fn create_rotation_y(radians: f32) -> Matrix {
+        let cos = radians.cos();
+        let sin = radians.sin();
+
+        let mut matrix = Self::identity();
+        matrix.m11 = cos;
+        matrix.m13 = -sin;
+        matrix.m31 = sin;
+        matrix.m33 = cos;
+
+        matrix
+    }
+
+    pub fn create_rotation_z(radians: f32) -> Matrix {
+        let cos = radians.cos();
+        let sin = radians.sin();
+
+        let mut matrix = Self::identity();
+        matrix.m11 = cos;
+        matrix.m12 = sin;
+        matrix.m21 = -sin;
+        matrix.m22 = cos;
+
+        matrix
+    }
+
+    pub fn create_from_axis_angle(axis: &Vector3, angle: f32) -> Matrix {
+        let x = axis.x;
+        let y = axis.y;
+        let z = axis.z;
+        let sin = angle.sin();
+        let cos = angle.cos();
+        let num1 = x * x;
+        let num2 = y * y;
+        let num3 = z * z;
+        let num4 = x * y;
+        let num5 = x * z;
+        let num6 = y * z;
+
+        Matrix {
+            m11: num1 + cos * (1.0 - num1),
+            m12: num4 - cos * num4 + sin * z,
+            m13: num5 - cos * num5 - sin * y,
+            m21: num4 - cos * num4 - sin * z,
+            m22: num2 + cos * (1.0 - num2),
+            m23: num6 - cos * num6 + sin * x,
+            m31: num5 - cos * num5 + sin * y,
+            m32: num6 - cos * num6 - sin * x,
+            m33: num3 + cos * (1.0 - num3),
+            m44: 1.0,
+            ..Default::default()
+        }
+    }
+
+    pub fn create_from_quaternion(quaternion: &Quaternion) -> Matrix {
+        let num1 = quaternion.x * quaternion.x;
+        let num2 = quaternion.y * quaternion.y;
+        let num3 = quaternion.z * quaternion.z;
+        let num4 = quaternion.x * quaternion.y;
+        let num5 = quaternion.z * quaternion.w;
+        let num6 = quaternion.z * quaternion.x;
+        let num7 = quaternion.y * quaternion.w;
+        let num8 = quaternion.y * quaternion.z;
+        let num9 = quaternion.x * quaternion.w;
+
+        Matrix {
+            m11: 1.0 - 2.0 * (num2 + num3),
+            m12: 2.0 * (num4 + num5),
+            m13: 2.0 * (num6 - num7),
+            m21: 2.0 * (num4 - num5),
+            m22: 1.0 - 2.0 * (num3 + num1),
+            m23: 2.0 * (num8 + num9),
+            m31: 2.0 * (num6 + num7),
+            m32: 2.0 * (num8 - num9),
+            m33: 1.0 - 2.0 * (num2 + num1),
+            m44: 1.0,
+            ..Default::default()
+        }
+    }
+
+    pub fn create_from_yaw_pitch_roll(yaw: f32, pitch: f32, roll: f32) -> Matrix {
+        let quaternion = Quaternion::create_from_yaw_pitch_roll(yaw, pitch, roll);
+        Self::create_from_quaternion(&quaternion)
+    }
+
+    pub fn create_look_at(camera_position: &Vector3, camera_target: &Vector3, camera_up_vector: &Vector3) -> Matrix {
+        let vector1 = Vector3::normalized(&(*camera_position - *camera_target));
+        let vector2 = Vector3::normalized(&Vector3::cross(camera_up_vector, &vector1));
+        let vector3 = Vector3::cross(&vector1, &vector2);
+
+        Matrix {
+            m11: vector2.x,
+            m12: vector3.x,
+            m13: vector1.x,
+            m21: vector2.y,
+            m22: vector3.y,
+            m23: vector1.y,
+            m31: vector2.z,
+            m32: vector3.z,
+            m33: vector1.z,
+            m41: -Vector3::dot(&vector2, camera_position),
+            m42: -Vector3::dot(&vector3, camera_position),
+            m43: -Vector3::dot(&vector1, camera_position),
+            m44: 1.0,
+            ..Default::default()
+        }
+    }
+
+    pub fn create_perspective_field_of_view(field_of_view: f32, aspect_ratio: f32, near_plane_distance: f32, far_plane_distance: f32) -> Result<Matrix, Exception> {
+        if field_of_view <= 0.0 || field_of_view >= std::f32::consts::PI {
+            return Err(Exception::out_of_range("field_of_view must be between 0 and PI.", None));
+        }
+
+        Self::validate_plane_distances(near_plane_distance, far_plane_distance)?;
+
+        let num1 = 1.0 / (field_of_view * 0.5).tan();
+        let num2 = num1 / aspect_ratio;
+
+        Ok(Matrix {
+            m11: num2,
+            m22: num1,
+            m33: far_plane_distance / (near_plane_distance - far_plane_distance),
+            m34: -1.0,
+            m43: near_plane_distance * far_plane_distance / (near_plane_distance - far_plane_distance),
+            ..Default::default()
+        })
+    }
+
+    pub fn create_perspective(width: f32, height: f32, near_plane_distance: f32, far_plane_distance: f32) -> Result<Matrix, Exception> {
+        Self::validate_plane_distances(near_plane_distance, far_plane_distance)?;
+
+        Ok(Matrix {
+            m11: 2.0 * near_plane_distance / width,
+            m22: 2.0 * near_plane_distance / height,
+            m33: far_plane_distance / (near_plane_distance - far_plane_distance),
+            m34: -1.0,
+            m43: near_plane_distance * far_plane_distance / (near_plane_distance - far_plane_distance),
+            ..Default::default()
+        })
+    }
+
+    pub fn create_perspective_off_center(left: f32, right: f32, bottom: f32, top: f32, near_plane_distance: f32, far_plane_distance: f32) -> Result<Matrix, Exception> {
+        Self::validate_plane_distances(near_plane_distance, far_plane_distance)?;
+
+        Ok(Matrix {
+            m11: 2.0 * near_plane_distance / (right - left),
+            m22: 2.0 * near_plane_distance / (top - bottom),
+            m31: (left + right) / (right - left),
+            m32: (top + bottom) / (top - bottom),
+            m33: far_plane_distance / (near_plane_distance - far_plane_distance),
+            m34: -1.0,
+            m43: near_plane_distance * far_plane_distance / (near_plane_distance - far_plane_distance),
+            ..Default::default()
+        })
+    }
+
+    pub fn create_orthographic(width: f32, height: f32, z_near_plane: f32, z_far_plane: f32) -> Matrix {
+        Matrix {
+            m11: 2.0 / width,
+            m22: 2.0 / height,
+            m33: 1.0 / (z_near_plane - z_far_plane),
+            m43: z_near_plane / (z_near_plane - z_far_plane),
+            m44: 1.0,
+            ..Default::default()
+        }
+    }
+
+    pub fn create_orthographic_off_center(left: f32, right: f32, bottom: f32, top: f32, z_near_plane: f32, z_far_plane: f32) -> Matrix {
+        Matrix {
+            m11: 2.0 / (right - left),
+            m22: 2.0 / (top - bottom),
+            m33: 1.0 / (z_near_plane - z_far_plane),
+            m41: (left + right) / (left - right),
+            m42: (top + bottom) / (bottom - top),
+            m43: z_near_plane / (z_near_plane - z_far_plane),
+            m44: 1.0,
+            ..Default::default()
+        }
+    }
+
+    pub fn create_world(position: &Vector3, forward: &Vector3, up: &Vector3) -> Matrix {
+        let vector1 = Vector3::normalized(&-*forward);
+        let vector2 = Vector3::normalized(&Vector3::cross(up, &vector1));
+        let vector3 = Vector3::cross(&vector1, &vector2);
+
+        Matrix {
+            m11: vector2.x,
+            m12: vector2.y,
+            m13: vector2.z,
+            m21: vector3.x,
+            m22: vector3.y,
+            m23: vector3.z,
+            m31: vector1.x,
+            m32: vector1.y,
+            m33: vector1.z,
+            m41: position.x,
+            m42: position.y,
+            m43: position.z,
+            m44: 1.0,
+            ..Default::default()
+        }
+    }
+
+    pub fn create_billboard(object_position: &Vector3, camera_position: &Vector3, camera_up_vector: &Vector3, camera_forward_vector: Option<&Vector3>) -> Matrix {
+        let mut vector1 = *object_position - *camera_position;
+        let num = vector1.length_squared();
+
+        if num < Self::DECOMPOSE_EPSILON {
+            vector1 = match camera_forward_vector {
+                Some(forward) => -*forward,
+                None => Vector3::forward(),
+            };
+        } else {
+            vector1 *= 1.0 / num.sqrt();
+        }
+
+        let vector2 = Vector3::normalized(&Vector3::cross(camera_up_vector, &vector1));
+        let vector3 = Vector3::cross(&vector1, &vector2);
+
+        Matrix {
+            m11: vector2.x,
+            m12: vector2.y,
+            m13: vector2.z,
+            m21: vector3.x,
+            m22: vector3.y,
+            m23: vector3.z,
+            m31: vector1.x,
+            m32: vector1.y,
+            m33: vector1.z,
+            m41: object_position.x,
+            m42: object_position.y,
+            m43: object_position.z,
+            m44: 1.0,
+            ..Default::default()
+        }
+    }
+
+    pub fn create_shadow(light_direction: &Vector3, plane: &Plane) -> Matrix {
+        let plane = Plane::normalized(plane);
+        let num1 = Vector3::dot(&plane.normal, light_direction);
+        let num2 = -plane.normal.x;
+        let num3 = -plane.normal.y;
+        let num4 = -plane.normal.z;
+        let num5 = -plane.d;
+
+        Matrix {
+            m11: num2 * light_direction.x + num1,
+            m21: num3 * light_direction.x,
+            m31: num4 * light_direction.x,
+            m41: num5 * light_direction.x,
+            m12: num2 * light_direction.y,
+            m22: num3 * light_direction.y + num1,
+            m32: num4 * light_direction.y,
+            m42: num5 * light_direction.y,
+            m13: num2 * light_direction.z,
+            m23: num3 * light_direction.z,
+            m33: num4 * light_direction.z + num1,
+            m43: num5 * light_direction.z,
+            m44: num1,
+            ..Default::default()
+        }
+    }
+
+    pub fn create_reflection(value: &Plane) -> Matrix {
+        let plane = Plane::normalized(value);
+        let x = plane.normal.x;
+        let y = plane.normal.y;
+        let z = plane.normal.z;
+        let num1 = -2.0 * x;
+        let num2 = -2.0 * y;
+        let num3 = -2.0 * z;
+
+        Matrix {
+            m11: num1 * x + 1.0,
+            m12: num2 * x,
+            m13: num3 * x,
+            m21: num1 * y,
+            m22: num2 * y + 1.0,
+            m23: num3 * y,
+            m31: num1 * z,
+            m32: num2 * z,
+            m33: num3 * z + 1.0,
+            m41: num1 * plane.d,
+            m42: num2 * plane.d,
+            m43: num3 * plane.d,
+            m44: 1.0,
+            ..Default::default()
+        }
+    }
+
+    pub fn transpose(matrix: &Matrix) -> Matrix {
+        Matrix {
+            m11: matrix.m11,
+            m12: matrix.m21,
+            m13: matrix.m31,
+            m14: matrix.m41,
+            m21: matrix.m12,
+            m22: matrix.m22,
+            m23: matrix.m32,
+            m24: matrix.m42,
+            m31: matrix.m13,
+            m32: matrix.m23,
+            m33: matrix.m33,
+            m34: matrix.m43,
+            m41: matrix.m14,
+            m42: matrix.m24,
+            m43: matrix.m34,
+            m44: matrix.m44,
+        }
+    }
+
+    pub fn determinant(&self) -> f32 {
+        let num1 = self.m33 * self.m44 - self.m34 * self.m43;
+        let num2 = self.m32 * self.m44 - self.m34 * self.m42;
+        let num3 = self.m32 * self.m43 - self.m33 * self.m42;
+        let num4 = self.m31 * self.m44 - self.m34 * self.m41;
+        let num5 = self.m31 * self.m43 - self.m33 * self.m41;
+        let num6 = self.m31 * self.m42 - self.m32 * self.m41;
+
+        self.m11 * (self.m22 * num1 - self.m23 * num2 + self.m24 * num3)
+            - self.m12 * (self.m21 * num1 - self.m23 * num4 + self.m24 * num5)
+            + self.m13 * (self.m21 * num2 - self.m22 * num4 + self.m24 * num6)
+            - self.m14 * (self.m21 * num3 - self.m22 * num5 + self.m23 * num6)
+    }
+
+    pub fn invert(matrix: &Matrix) -> Matrix {
+        let num1 = matrix.m33 * matrix.m44 - matrix.m34 * matrix.m43;
+        let num2 = matrix.m32 * matrix.m44 - matrix.m34 * matrix.m42;
+        let num3 = matrix.m32 * matrix.m43 - matrix.m33 * matrix.m42;
+        let num4 = matrix.m31 * matrix.m44 - matrix.m34 * matrix.m41;
+        let num5 = matrix.m31 * matrix.m43 - matrix.m33 * matrix.m41;
+        let num6 = matrix.m31 * matrix.m42 - matrix.m32 * matrix.m41;
+        let num7 = matrix.m22 * num1 - matrix.m23 * num2 + matrix.m24 * num3;
+        let num8 = -(matrix.m21 * num1 - matrix.m23 * num4 + matrix.m24 * num5);
+        let num9 = matrix.m21 * num2 - matrix.m22 * num4 + matrix.m24 * num6;
+        let num10 = -(matrix.m21 * num3 - matrix.m22 * num5 + matrix.m23 * num6);
+        let num11 = 1.0 / (matrix.m11 * num7 + matrix.m12 * num8 + matrix.m13 * num9 + matrix.m14 * num10);
+
+        let num12 = matrix.m23 * matrix.m44 - matrix.m24 * matrix.m43;
+        let num13 = matrix.m22 * matrix.m44 - matrix.m24 * matrix.m42;
+        let num14 = matrix.m22 * matrix.m43 - matrix.m23 * matrix.m42;
+        let num15 = matrix.m21 * matrix.m44 - matrix.m24 * matrix.m41;
+        let num16 = matrix.m21 * matrix.m43 - matrix.m23 * matrix.m41;
+        let num17 = matrix.m21 * matrix.m42 - matrix.m22 * matrix.m41;
+
+        let num18 = matrix.m23 * matrix.m34 - matrix.m24 * matrix.m33;
+        let num19 = matrix.m22 * matrix.m34 - matrix.m24 * matrix.m32;
+        let num20 = matrix.m22 * matrix.m33 - matrix.m23 * matrix.m32;
+        let num21 = matrix.m21 * matrix.m34 - matrix.m24 * matrix.m31;
+        let num22 = matrix.m21 * matrix.m33 - matrix.m23 * matrix.m31;
+        let num23 = matrix.m21 * matrix.m32 - matrix.m22 * matrix.m31;
+
+        Matrix {
+            m11: num7 * num11,
+            m21: num8 * num11,
+            m31: num9 * num11,
+            m41: num10 * num11,
+            m12: -(matrix.m12 * num1 - matrix.m13 * num2 + matrix.m14 * num3) * num11,
+            m22: (matrix.m11 * num1 - matrix.m13 * num4 + matrix.m14 * num5) * num11,
+            m32: -(matrix.m11 * num2 - matrix.m12 * num4 + matrix.m14 * num6) * num11,
+            m42: (matrix.m11 * num3 - matrix.m12 * num5 + matrix.m13 * num6) * num11,
+            m13: (matrix.m12 * num12 - matrix.m13 * num13 + matrix.m14 * num14) * num11,
+            m23: -(matrix.m11 * num12 - matrix.m13 * num15 + matrix.m14 * num16) * num11,
+            m33: (matrix.m11 * num13 - matrix.m12 * num15 + matrix.m14 * num17) * num11,
+            m43: -(matrix.m11 * num14 - matrix.m12 * num16 + matrix.m13 * num17) * num11,
+            m14: -(matrix.m12 * num18 - matrix.m13 * num19 + matrix.m14 * num20) * num11,
+            m24: (matrix.m11 * num18 - matrix.m13 * num21 + matrix.m14 * num22) * num11,
+            m34: -(matrix.m11 * num19 - matrix.m12 * num21 + matrix.m14 * num23) * num11,
+            m44: (matrix.m11 * num20 - matrix.m12 * num22 + matrix.m13 * num23) * num11,
+        }
+    }
+
+    pub fn multiply(matrix1: &Matrix, matrix2: &Matrix) -> Matrix {
+        Matrix {
+            m11: matrix1.m11 * matrix2.m11 + matrix1.m12 * matrix2.m21 + matrix1.m13 * matrix2.m31 + matrix1.m14 * matrix2.m41,
+            m12: matrix1.m11 * matrix2.m12 + matrix1.m12 * matrix2.m22 + matrix1.m13 * matrix2.m32 + matrix1.m14 * matrix2.m42,
+            m13: matrix1.m11 * matrix2.m13 + matrix1.m12 * matrix2.m23 + matrix1.m13 * matrix2.m33 + matrix1.m14 * matrix2.m43,
+            m14: matrix1.m11 * matrix2.m14 + matrix1.m12 * matrix2.m24 + matrix1.m13 * matrix2.m34 + matrix1.m14 * matrix2.m44,
+            m21: matrix1.m21 * matrix2.m11 + matrix1.m22 * matrix2.m21 + matrix1.m23 * matrix2.m31 + matrix1.m24 * matrix2.m41,
+            m22: matrix1.m21 * matrix2.m12 + matrix1.m22 * matrix2.m22 + matrix1.m23 * matrix2.m32 + matrix1.m24 * matrix2.m42,
+            m23: matrix1.m21 * matrix2.m13 + matrix1.m22 * matrix2.m23 + matrix1.m23 * matrix2.m33 + matrix1.m24 * matrix2.m43,
+            m24: matrix1.m21 * matrix2.m14 + matrix1.m22 * matrix2.m24 + matrix1.m23 * matrix2.m34 + matrix1.m24 * matrix2.m44,
+            m31: matrix1.m31 * matrix2.m11 + matrix1.m32 * matrix2.m21 + matrix1.m33 * matrix2.m31 + matrix1.m34 * matrix2.m41,
+            m32: matrix1.m31 * matrix2.m12 + matrix1.m32 * matrix2.m22 + matrix1.m33 * matrix2.m32 + matrix1.m34 * matrix2.m42,
+            m33: matrix1.m31 * matrix2.m13 + matrix1.m32 * matrix2.m23 + matrix1.m33 * matrix2.m33 + matrix1.m34 * matrix2.m43,
+            m34: matrix1.m31 * matrix2.m14 + matrix1.m32 * matrix2.m24 + matrix1.m33 * matrix2.m34 + matrix1.m34 * matrix2.m44,
+            m41: matrix1.m41 * matrix2.m11 + matrix1.m42 * matrix2.m21 + matrix1.m43 * matrix2.m31 + matrix1.m44 * matrix2.m41,
+            m42: matrix1.m41 * matrix2.m12 + matrix1.m42 * matrix2.m22 + matrix1.m43 * matrix2.m32 + matrix1.m44 * matrix2.m42,
+            m43: matrix1.m41 * matrix2.m13 + matrix1.m42 * matrix2.m23 + matrix1.m43 * matrix2.m33 + matrix1.m44 * matrix2.m43,
+            m44: matrix1.m41 * matrix2.m14 + matrix1.m42 * matrix2.m24 + matrix1.m43 * matrix2.m34 + matrix1.m44 * matrix2.m44,
+        }
+    }
+
+    pub fn lerp(matrix1: &Matrix, matrix2: &Matrix, amount: f32) -> Matrix {
+        *matrix1 + (*matrix2 - *matrix1) * amount
+    }
+
+    pub fn decompose(&self) -> Option<(Vector3, Quaternion, Vector3)> {
+        let translation = self.translation();
+        let canonical_basis = [Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z()];
+        let mut basis = [
+            Vector3 { x: self.m11, y: self.m12, z: self.m13 },
+            Vector3 { x: self.m21, y: self.m22, z: self.m23 },
+            Vector3 { x: self.m31, y: self.m32, z: self.m33 },
+        ];
+        let mut scales = [basis[0].length(), basis[1].length(), basis[2].length()];
+
+        let (a, b, c) = if scales[0] < scales[1] {
+            if scales[1] < scales[2] {
+                (2, 1, 0)
+            } else if scales[0] < scales[2] {
+                (1, 2, 0)
+            } else {
+                (1, 0, 2)
+            }
+        } else if scales[0] < scales[2] {
+            (2, 0, 1)
+        } else if scales[1] < scales[2] {
+            (0, 2, 1)
+        } else {
+            (0, 1, 2)
+        };
+
+        if scales[a] < Self::DECOMPOSE_EPSILON {
+            basis[a] = canonical_basis[a];
+        }
+
+        basis[a].normalize();
+
+        if scales[b] < Self::DECOMPOSE_EPSILON {
+            let num1 = basis[a].x.abs();
+            let num2 = basis[a].y.abs();
+            let num3 = basis[a].z.abs();
+
+            let index = if num1 < num2 {
+                if num2 < num3 || num1 < num3 { 0 } else { 2 }
+            } else if num1 < num3 || num2 < num3 {
+                1
+            } else {
+                2
+            };
+
+            basis[b] = Vector3::cross(&basis[a], &canonical_basis[index]);
+        }
+
+        basis[b].normalize();
+
+        if scales[c] < Self::DECOMPOSE_EPSILON {
+            basis[c] = Vector3::cross(&basis[a], &basis[b]);
+        }
+
+        basis[c].normalize();
+
+        let mut rotation = Matrix {
+            m11: basis[0].x,
+            m12: basis[0].y,
+            m13: basis[0].z,
+            m21: basis[1].x,
+            m22: basis[1].y,
+            m23: basis[1].z,
+            m31: basis[2].x,
+            m32: basis[2].y,
+            m33: basis[2].z,
+            m44: 1.0,
+            ..Default::default()
+        };
+
+        let mut determinant = rotation.determinant();
+
+        if determinant < 0.0 {
+            scales[a] = -scales[a];
+            basis[a] = -basis[a];
+            determinant = -determinant;
+
+            rotation.m11 = basis[0].x;
+            rotation.m12 = basis[0].y;
+            rotation.m13 = basis[0].z;
+            rotation.m21 = basis[1].x;
+            rotation.m22 = basis[1].y;
+            rotation.m23 = basis[1].z;
+            rotation.m31 = basis[2].x;
+            rotation.m32 = basis[2].y;
+            rotation.m33 = basis[2].z;
+        }
+
+        let num = (determinant - 1.0) * (determinant - 1.0);
+
+        if Self::DECOMPOSE_EPSILON < num {
+            return None;
+        }
+
+        let scale = Vector3 { x: scales[0], y: scales[1], z: scales[2] };
+
+        Some((scale, Quaternion::create_from_rotation_matrix(&rotation), translation))
+    }
+
+    fn validate_plane_distances(near_plane_distance: f32, far_plane_distance: f32) -> Result<(), Exception> {
+        if near_plane_distance <= 0.0 {
+            return Err(Exception::out_of_range("near_plane_distance must be greater than 0.", None));
+        }
+
+        if far_plane_distance <= 0.0 {
+            return Err(Exception::out_of_range("far_plane_distance must be greater than 0.", None));
+        }
+
+        if near_plane_distance >= far_plane_distance {
+            return Err(Exception::out_of_range("near_plane_distance must be less than far_plane_distance.", None));
+        }
+
+        Ok(())
+    }
+
+    fn to_array(self) -> [f32; 16] {
+        [
+            self.m11, self.m12, self.m13, self.m14,
+            self.m21, self.m22, self.m23, self.m24,
+            self.m31, self.m32, self.m33, self.m34,
+            self.m41, self.m42, self.m43, self.m44,
+        ]
+    }
+
+    fn from_array(values: [f32; 16]) -> Matrix {
+        let [m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34, m41, m42, m43, m44] = values;
+
+        Matrix { m11, m12, m13, m14, m21, m22, m23, m24, m31, m32, m33, m34, m41, m42, m43, m44 }
+    }
+
+    fn map(self, other: Matrix, function: impl Fn(f32, f32) -> f32) -> Matrix {
+        let mut values = self.to_array();
+        let others = other.to_array();
+
+        for (value, other) in values.iter_mut().zip(others) {
+            *value = function(*value, other);
+        }
+
+        Self::from_array(values)
+    }
+}
+
+impl Add for Matrix {
+    type Output = Matrix;
+
+    fn add(self, rhs: Matrix) -> Matrix {
+        self.map(rhs, |a, b| a + b)
+    }
+}
+
+impl Sub for Matrix {
+    type Output = Matrix;
+
+    fn sub(self, rhs: Matrix) -> Matrix {
+        self.map(rhs, |a, b| a - b)
+    }
+}
+
+impl Mul for Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: Matrix) -> Matrix {
+        Matrix::multiply(&self, &rhs)
+    }
+}
+
+impl Mul<f32> for Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: f32) -> Matrix {
+        self.map(self, |a, _| a * rhs)
+    }
+}
+
+impl Div<f32> for Matrix {
+    type Output = Matrix;
+
+    fn div(self, rhs: f32) -> Matrix {
+        let num = 1.0 / rhs;
+        self * num
+    }
+}
+
+impl Neg for Matrix {
+    type Output = Matrix;
+
+    fn neg(self) -> Matrix {
+        self.map(self, |a, _| -a)
+    }
+}
+
+impl AddAssign for Matrix {
+    fn add_assign(&mut self, rhs: Matrix) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Matrix {
+    fn sub_assign(&mut self, rhs: Matrix) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Matrix {
+    fn mul_assign(&mut self, rhs: Matrix) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<f32> for Matrix {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign<f32> for Matrix {
+    fn div_assign(&mut self, rhs: f32) {
+        *self = *self / rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_matrix_eq(actual: &Matrix, expected: &Matrix, tolerance: f32) {
+        for (index, (actual, expected)) in actual.to_array().iter().zip(expected.to_array()).enumerate() {
+            assert!((actual - expected).abs() <= tolerance, "element {index}: {actual} != {expected}");
+        }
+    }
+
+    fn transform() -> Matrix {
+        Matrix::create_scale_xyz(2.0, 3.0, 0.5)
+            * Matrix::create_from_axis_angle(&Vector3::normalized(&Vector3 { x: 1.0, y: 2.0, z: 3.0 }), 0.7)
+            * Matrix::create_translation_xyz(4.0, -5.0, 6.0)
+    }
+
+    #[test]
+    fn invert_times_matrix_is_identity() {
+        let matrix = transform();
+
+        assert_matrix_eq(&(Matrix::invert(&matrix) * matrix), &Matrix::identity(), 1e-5);
+        assert_matrix_eq(&(matrix * Matrix::invert(&matrix)), &Matrix::identity(), 1e-5);
+    }
+
+    #[test]
+    fn invert_of_singular_matrix_is_not_finite() {
+        let singular = Matrix::create_scale_xyz(1.0, 0.0, 1.0);
+
+        assert_eq!(singular.determinant(), 0.0);
+        assert!(Matrix::invert(&singular).to_array().iter().any(|value| !value.is_finite()));
+    }
+
+    #[test]
+    fn decompose_recovers_scale_rotation_and_translation() {
+        let scale = Vector3 { x: 2.0, y: 3.0, z: 0.5 };
+        let rotation = Matrix::create_from_axis_angle(&Vector3::normalized(&Vector3 { x: 1.0, y: 2.0, z: 3.0 }), 0.7);
+        let translation = Vector3 { x: 4.0, y: -5.0, z: 6.0 };
+
+        let (actual_scale, actual_rotation, actual_translation) = transform().decompose().unwrap();
+
+        assert!((actual_scale - scale).length() < 1e-5);
+        assert!((actual_translation - translation).length() < 1e-5);
+        assert_matrix_eq(&Matrix::create_from_quaternion(&actual_rotation), &rotation, 1e-5);
+    }
+
+    #[test]
+    fn decompose_rejects_a_skewed_matrix() {
+        let mut skewed = Matrix::identity();
+        skewed.m21 = 1.0;
+
+        assert!(skewed.decompose().is_none());
+    }
+
+    #[test]
+    fn create_look_at_matches_xna() {
+        let view = Matrix::create_look_at(&Vector3 { x: 1.0, y: 2.0, z: 3.0 }, &Vector3::zero(), &Vector3::up());
+        let expected = Matrix::from_array([
+            0.9486833, -0.16903085, 0.26726124, 0.0,
+            0.0, 0.8451543, 0.5345225, 0.0,
+            -0.31622776, -0.50709254, 0.8017837, 0.0,
+            0.0, 0.0, -3.7416575, 1.0,
+        ]);
+
+        assert_matrix_eq(&view, &expected, 1e-5);
+    }
+
+    #[test]
+    fn create_perspective_field_of_view_validates_arguments() {
+        let projection = Matrix::create_perspective_field_of_view(std::f32::consts::FRAC_PI_2, 2.0, 1.0, 101.0).unwrap();
+
+        assert!((projection.m11 - 0.5).abs() < 1e-6);
+        assert!((projection.m22 - 1.0).abs() < 1e-6);
+        assert!((projection.m33 + 1.01).abs() < 1e-6);
+        assert!((projection.m43 + 1.01).abs() < 1e-6);
+        assert_eq!(projection.m34, -1.0);
+
+        assert!(Matrix::create_perspective_field_of_view(0.0, 1.0, 1.0, 10.0).is_err());
+        assert!(Matrix::create_perspective_field_of_view(1.0, 1.0, 0.0, 10.0).is_err());
+        assert!(Matrix::create_perspective_field_of_view(1.0, 1.0, 10.0, 1.0).is_err());
+        assert!(Matrix::create_perspective(1.0, 1.0, 2.0, 2.0).is_err());
+    }
+}
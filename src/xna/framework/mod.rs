@@ -1,9 +1,20 @@
 pub mod game;
 pub mod graphics;
 pub mod color;
+pub mod math_helper;
 pub mod vector;
+pub mod matrix;
+pub mod quaternion;
+pub mod plane;
+pub mod ray;
+pub mod bounding_box;
+pub mod bounding_sphere;
+pub mod bounding_frustum;
+pub mod curve;
+pub mod curve_key;
 pub mod point;
-mod rectangle;
+pub mod rectangle;
+pub mod rectangle_f;
 pub mod content;
 
 #[derive(Default, Eq, PartialEq, Clone, Copy, Debug)]
@@ -20,6 +31,16 @@ pub struct Rectangle {
     pub height: i32,
 }
 
+#[derive(Default, PartialEq, Clone, Copy, Debug)]
+pub struct RectangleF {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+pub struct MathHelper;
+
 #[derive(Default, PartialEq, Clone, Copy, Debug)]
 pub struct Vector2 {
     pub x: f32,
@@ -98,6 +119,71 @@ pub struct BoundingBox {
     pub max: Vector3,
 }
 
+#[derive(Default, Eq, PartialEq, Clone, Copy, Debug)]
+pub enum ContainmentType {
+    #[default]
+    Disjoint,
+    Contains,
+    Intersects,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy, Debug)]
+pub enum PlaneIntersectionType {
+    #[default]
+    Front,
+    Back,
+    Intersecting,
+}
+
 #[derive(Default, PartialEq, Clone, Copy, Debug)]
 pub struct BoundingFrustum {
-}
\ No newline at end of file
+    matrix: Matrix,
+    planes: [Plane; 6],
+    corners: [Vector3; 8],
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy, Debug)]
+pub enum CurveLoopType {
+    #[default]
+    Constant,
+    Cycle,
+    CycleOffset,
+    Oscillate,
+    Linear,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy, Debug)]
+pub enum CurveTangent {
+    #[default]
+    Flat,
+    Linear,
+    Smooth,
+}
+
+#[derive(Default, Eq, PartialEq, Clone, Copy, Debug)]
+pub enum CurveContinuity {
+    #[default]
+    Smooth,
+    Step,
+}
+
+#[derive(Default, PartialEq, Clone, Copy, Debug)]
+pub struct CurveKey {
+    position: f32,
+    pub value: f32,
+    pub tangent_in: f32,
+    pub tangent_out: f32,
+    pub continuity: CurveContinuity,
+}
+
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct CurveKeyCollection {
+    keys: Vec<CurveKey>,
+}
+
+#[derive(Default, PartialEq, Clone, Debug)]
+pub struct Curve {
+    pub pre_loop: CurveLoopType,
+    pub post_loop: CurveLoopType,
+    pub keys: CurveKeyCollection,
+}
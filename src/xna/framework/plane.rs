@@ -0,0 +1,119 @@
+use crate::xna::framework::{BoundingBox, BoundingFrustum, BoundingSphere, Matrix, Plane, PlaneIntersectionType, Quaternion, Vector3, Vector4};
+
+impl Plane {
+    pub fn new(a: f32, b: f32, c: f32, d: f32) -> Plane {
+        Plane { normal: Vector3 { x: a, y: b, z: c }, d }
+    }
+
+    pub fn from_normal(normal: Vector3, d: f32) -> Plane {
+        Plane { normal, d }
+    }
+
+    pub fn from_vector4(value: &Vector4) -> Plane {
+        Plane { normal: Vector3 { x: value.x, y: value.y, z: value.z }, d: value.w }
+    }
+
+    pub fn from_points(point1: &Vector3, point2: &Vector3, point3: &Vector3) -> Plane {
+        let vector1 = *point2 - *point1;
+        let vector2 = *point3 - *point1;
+        let normal = Vector3::normalized(&Vector3::cross(&vector1, &vector2));
+        let d = -Vector3::dot(&normal, point1);
+
+        Plane { normal, d }
+    }
+
+    pub fn normalize(&mut self) {
+        *self = Self::normalized(self);
+    }
+
+    pub fn normalized(value: &Plane) -> Plane {
+        let num = value.normal.length_squared();
+
+        if (num - 1.0).abs() < f32::EPSILON {
+            return *value;
+        }
+
+        let num2 = 1.0 / num.sqrt();
+
+        Plane {
+            normal: value.normal * num2,
+            d: value.d * num2,
+        }
+    }
+
+    pub fn transform(plane: &Plane, matrix: &Matrix) -> Plane {
+        let matrix = Matrix::invert(matrix);
+        let x = plane.normal.x;
+        let y = plane.normal.y;
+        let z = plane.normal.z;
+        let d = plane.d;
+
+        Plane {
+            normal: Vector3 {
+                x: x * matrix.m11 + y * matrix.m12 + z * matrix.m13 + d * matrix.m14,
+                y: x * matrix.m21 + y * matrix.m22 + z * matrix.m23 + d * matrix.m24,
+                z: x * matrix.m31 + y * matrix.m32 + z * matrix.m33 + d * matrix.m34,
+            },
+            d: x * matrix.m41 + y * matrix.m42 + z * matrix.m43 + d * matrix.m44,
+        }
+    }
+
+    pub fn transform_by_quaternion(plane: &Plane, rotation: &Quaternion) -> Plane {
+        Plane {
+            normal: Vector3::transform_by_quaternion(&plane.normal, rotation),
+            d: plane.d,
+        }
+    }
+
+    pub fn dot(&self, value: &Vector4) -> f32 {
+        self.normal.x * value.x + self.normal.y * value.y + self.normal.z * value.z + self.d * value.w
+    }
+
+    pub fn dot_coordinate(&self, value: &Vector3) -> f32 {
+        Vector3::dot(&self.normal, value) + self.d
+    }
+
+    pub fn dot_normal(&self, value: &Vector3) -> f32 {
+        Vector3::dot(&self.normal, value)
+    }
+
+    pub fn intersects_box(&self, bounding_box: &BoundingBox) -> PlaneIntersectionType {
+        let mut vector1 = Vector3::zero();
+        let mut vector2 = Vector3::zero();
+
+        vector1.x = if self.normal.x >= 0.0 { bounding_box.min.x } else { bounding_box.max.x };
+        vector1.y = if self.normal.y >= 0.0 { bounding_box.min.y } else { bounding_box.max.y };
+        vector1.z = if self.normal.z >= 0.0 { bounding_box.min.z } else { bounding_box.max.z };
+        vector2.x = if self.normal.x >= 0.0 { bounding_box.max.x } else { bounding_box.min.x };
+        vector2.y = if self.normal.y >= 0.0 { bounding_box.max.y } else { bounding_box.min.y };
+        vector2.z = if self.normal.z >= 0.0 { bounding_box.max.z } else { bounding_box.min.z };
+
+        if self.dot_coordinate(&vector1) > 0.0 {
+            return PlaneIntersectionType::Front;
+        }
+
+        if self.dot_coordinate(&vector2) < 0.0 {
+            return PlaneIntersectionType::Back;
+        }
+
+        PlaneIntersectionType::Intersecting
+    }
+
+    pub fn intersects_sphere(&self, sphere: &BoundingSphere) -> PlaneIntersectionType {
+        let num = self.dot_coordinate(&sphere.center);
+
+        if num > sphere.radius {
+            return PlaneIntersectionType::Front;
+        }
+
+        if num < -sphere.radius {
+            return PlaneIntersectionType::Back;
+        }
+
+        PlaneIntersectionType::Intersecting
+    }
+
+    pub fn intersects_frustum(&self, frustum: &BoundingFrustum) -> PlaneIntersectionType {
+        frustum.intersects_plane(self)
+    }
+}
@@ -1,11 +1,124 @@
-use crate::xna::framework::Point;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::xna::framework::{Point, Vector2};
 
 impl Point {
     pub fn new(x: i32, y: i32) -> Point {
         Point { x, y }
     }
 
+    pub fn from_value(value: i32) -> Point {
+        Point { x: value, y: value }
+    }
+
     pub fn zero() -> Point {
         Point { x: 0, y: 0 }
     }
-}
\ No newline at end of file
+
+    pub fn one() -> Point {
+        Point { x: 1, y: 1 }
+    }
+
+    pub fn unit_x() -> Point {
+        Point { x: 1, y: 0 }
+    }
+
+    pub fn unit_y() -> Point {
+        Point { x: 0, y: 1 }
+    }
+
+    pub fn to_vector2(self) -> Vector2 {
+        Vector2 { x: self.x as f32, y: self.y as f32 }
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point { x: self.x + rhs.x, y: self.y + rhs.y }
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point { x: self.x - rhs.x, y: self.y - rhs.y }
+    }
+}
+
+impl Mul for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: Point) -> Point {
+        Point { x: self.x * rhs.x, y: self.y * rhs.y }
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i32) -> Point {
+        Point { x: self.x * rhs, y: self.y * rhs }
+    }
+}
+
+impl Div for Point {
+    type Output = Point;
+
+    fn div(self, rhs: Point) -> Point {
+        Point { x: self.x / rhs.x, y: self.y / rhs.y }
+    }
+}
+
+impl Div<i32> for Point {
+    type Output = Point;
+
+    fn div(self, rhs: i32) -> Point {
+        Point { x: self.x / rhs, y: self.y / rhs }
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point { x: -self.x, y: -self.y }
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Point {
+    fn mul_assign(&mut self, rhs: Point) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<i32> for Point {
+    fn mul_assign(&mut self, rhs: i32) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Point {
+    fn div_assign(&mut self, rhs: Point) {
+        *self = *self / rhs;
+    }
+}
+
+impl DivAssign<i32> for Point {
+    fn div_assign(&mut self, rhs: i32) {
+        *self = *self / rhs;
+    }
+}
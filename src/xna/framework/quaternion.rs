@@ -0,0 +1,267 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::xna::framework::{Matrix, Quaternion, Vector3};
+
+impl Quaternion {
+    pub fn from_xyzw(x: f32, y: f32, z: f32, w: f32) -> Quaternion { Quaternion { x, y, z, w } }
+
+    pub fn from_vector3(vector_part: Vector3, scalar_part: f32) -> Quaternion {
+        Quaternion { x: vector_part.x, y: vector_part.y, z: vector_part.z, w: scalar_part }
+    }
+
+    pub fn identity() -> Quaternion { Quaternion { x: 0.0, y: 0.0, z: 0.0, w: 1.0 } }
+
+    pub fn length(&self) -> f32 {
+        self.length_squared().sqrt()
+    }
+
+    pub fn length_squared(&self) -> f32 {
+        self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w
+    }
+
+    pub fn normalize(&mut self) {
+        *self = Self::normalized(self);
+    }
+
+    pub fn normalized(quaternion: &Quaternion) -> Quaternion {
+        let num = 1.0 / quaternion.length();
+        *quaternion * num
+    }
+
+    pub fn conjugate(value: &Quaternion) -> Quaternion {
+        Quaternion { x: -value.x, y: -value.y, z: -value.z, w: value.w }
+    }
+
+    pub fn inverse(quaternion: &Quaternion) -> Quaternion {
+        let num = 1.0 / quaternion.length_squared();
+
+        Quaternion {
+            x: -quaternion.x * num,
+            y: -quaternion.y * num,
+            z: -quaternion.z * num,
+            w: quaternion.w * num,
+        }
+    }
+
+    pub fn dot(quaternion1: &Quaternion, quaternion2: &Quaternion) -> f32 {
+        quaternion1.x * quaternion2.x + quaternion1.y * quaternion2.y
+            + quaternion1.z * quaternion2.z + quaternion1.w * quaternion2.w
+    }
+
+    pub fn create_from_axis_angle(axis: &Vector3, angle: f32) -> Quaternion {
+        let num = angle * 0.5;
+        let sin = num.sin();
+
+        Quaternion {
+            x: axis.x * sin,
+            y: axis.y * sin,
+            z: axis.z * sin,
+            w: num.cos(),
+        }
+    }
+
+    pub fn create_from_yaw_pitch_roll(yaw: f32, pitch: f32, roll: f32) -> Quaternion {
+        let num1 = roll * 0.5;
+        let num2 = num1.sin();
+        let num3 = num1.cos();
+        let num4 = pitch * 0.5;
+        let num5 = num4.sin();
+        let num6 = num4.cos();
+        let num7 = yaw * 0.5;
+        let num8 = num7.sin();
+        let num9 = num7.cos();
+
+        Quaternion {
+            x: num9 * num5 * num3 + num8 * num6 * num2,
+            y: num8 * num6 * num3 - num9 * num5 * num2,
+            z: num9 * num6 * num2 - num8 * num5 * num3,
+            w: num9 * num6 * num3 + num8 * num5 * num2,
+        }
+    }
+
+    pub fn create_from_rotation_matrix(matrix: &Matrix) -> Quaternion {
+        let num = matrix.m11 + matrix.m22 + matrix.m33;
+
+        if num > 0.0 {
+            let num1 = (num + 1.0).sqrt();
+            let num2 = 0.5 / num1;
+
+            return Quaternion {
+                x: (matrix.m23 - matrix.m32) * num2,
+                y: (matrix.m31 - matrix.m13) * num2,
+                z: (matrix.m12 - matrix.m21) * num2,
+                w: num1 * 0.5,
+            };
+        }
+
+        if matrix.m11 >= matrix.m22 && matrix.m11 >= matrix.m33 {
+            let num1 = (1.0 + matrix.m11 - matrix.m22 - matrix.m33).sqrt();
+            let num2 = 0.5 / num1;
+
+            return Quaternion {
+                x: 0.5 * num1,
+                y: (matrix.m12 + matrix.m21) * num2,
+                z: (matrix.m13 + matrix.m31) * num2,
+                w: (matrix.m23 - matrix.m32) * num2,
+            };
+        }
+
+        if matrix.m22 > matrix.m33 {
+            let num1 = (1.0 + matrix.m22 - matrix.m11 - matrix.m33).sqrt();
+            let num2 = 0.5 / num1;
+
+            return Quaternion {
+                x: (matrix.m21 + matrix.m12) * num2,
+                y: 0.5 * num1,
+                z: (matrix.m32 + matrix.m23) * num2,
+                w: (matrix.m31 - matrix.m13) * num2,
+            };
+        }
+
+        let num1 = (1.0 + matrix.m33 - matrix.m11 - matrix.m22).sqrt();
+        let num2 = 0.5 / num1;
+
+        Quaternion {
+            x: (matrix.m31 + matrix.m13) * num2,
+            y: (matrix.m32 + matrix.m23) * num2,
+            z: 0.5 * num1,
+            w: (matrix.m12 - matrix.m21) * num2,
+        }
+    }
+
+    pub fn multiply(quaternion1: &Quaternion, quaternion2: &Quaternion) -> Quaternion {
+        let num1 = quaternion1.y * quaternion2.z - quaternion1.z * quaternion2.y;
+        let num2 = quaternion1.z * quaternion2.x - quaternion1.x * quaternion2.z;
+        let num3 = quaternion1.x * quaternion2.y - quaternion1.y * quaternion2.x;
+        let num4 = quaternion1.x * quaternion2.x + quaternion1.y * quaternion2.y + quaternion1.z * quaternion2.z;
+
+        Quaternion {
+            x: quaternion1.x * quaternion2.w + quaternion2.x * quaternion1.w + num1,
+            y: quaternion1.y * quaternion2.w + quaternion2.y * quaternion1.w + num2,
+            z: quaternion1.z * quaternion2.w + quaternion2.z * quaternion1.w + num3,
+            w: quaternion1.w * quaternion2.w - num4,
+        }
+    }
+
+    pub fn concatenate(value1: &Quaternion, value2: &Quaternion) -> Quaternion {
+        Self::multiply(value2, value1)
+    }
+
+    pub fn lerp(quaternion1: &Quaternion, quaternion2: &Quaternion, amount: f32) -> Quaternion {
+        let num = 1.0 - amount;
+
+        let result = if Self::dot(quaternion1, quaternion2) >= 0.0 {
+            *quaternion1 * num + *quaternion2 * amount
+        } else {
+            *quaternion1 * num - *quaternion2 * amount
+        };
+
+        Self::normalized(&result)
+    }
+
+    pub fn slerp(quaternion1: &Quaternion, quaternion2: &Quaternion, amount: f32) -> Quaternion {
+        let mut num1 = Self::dot(quaternion1, quaternion2);
+        let mut flag = false;
+
+        if num1 < 0.0 {
+            flag = true;
+            num1 = -num1;
+        }
+
+        let num2;
+        let mut num3;
+
+        if num1 > 0.999999 {
+            num2 = 1.0 - amount;
+            num3 = amount;
+        } else {
+            let num4 = num1.acos();
+            let num5 = 1.0 / num4.sin();
+            num2 = ((1.0 - amount) * num4).sin() * num5;
+            num3 = (amount * num4).sin() * num5;
+        }
+
+        if flag {
+            num3 = -num3;
+        }
+
+        *quaternion1 * num2 + *quaternion2 * num3
+    }
+}
+
+impl Add for Quaternion {
+    type Output = Quaternion;
+
+    fn add(self, rhs: Quaternion) -> Quaternion {
+        Quaternion { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z, w: self.w + rhs.w }
+    }
+}
+
+impl Sub for Quaternion {
+    type Output = Quaternion;
+
+    fn sub(self, rhs: Quaternion) -> Quaternion {
+        Quaternion { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z, w: self.w - rhs.w }
+    }
+}
+
+impl Mul for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: Quaternion) -> Quaternion {
+        Quaternion::multiply(&self, &rhs)
+    }
+}
+
+impl Mul<f32> for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: f32) -> Quaternion {
+        Quaternion { x: self.x * rhs, y: self.y * rhs, z: self.z * rhs, w: self.w * rhs }
+    }
+}
+
+impl Div for Quaternion {
+    type Output = Quaternion;
+
+    fn div(self, rhs: Quaternion) -> Quaternion {
+        Quaternion::multiply(&self, &Quaternion::inverse(&rhs))
+    }
+}
+
+impl Neg for Quaternion {
+    type Output = Quaternion;
+
+    fn neg(self) -> Quaternion {
+        Quaternion { x: -self.x, y: -self.y, z: -self.z, w: -self.w }
+    }
+}
+
+impl AddAssign for Quaternion {
+    fn add_assign(&mut self, rhs: Quaternion) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Quaternion {
+    fn sub_assign(&mut self, rhs: Quaternion) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Quaternion {
+    fn mul_assign(&mut self, rhs: Quaternion) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<f32> for Quaternion {
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+impl DivAssign for Quaternion {
+    fn div_assign(&mut self, rhs: Quaternion) {
+        *self = *self / rhs;
+    }
+}
@@ -0,0 +1,60 @@
+use crate::xna::framework::{BoundingBox, BoundingFrustum, BoundingSphere, Plane, Ray, Vector3};
+
+impl Ray {
+    pub fn new(position: Vector3, direction: Vector3) -> Ray {
+        Ray { position, direction }
+    }
+
+    pub fn intersects_box(&self, bounding_box: &BoundingBox) -> Option<f32> {
+        bounding_box.intersects_ray(self)
+    }
+
+    pub fn intersects_sphere(&self, sphere: &BoundingSphere) -> Option<f32> {
+        let vector = sphere.center - self.position;
+        let num1 = vector.length_squared();
+        let num2 = sphere.radius * sphere.radius;
+
+        if num1 <= num2 {
+            return Some(0.0);
+        }
+
+        let num3 = Vector3::dot(&self.direction, &vector);
+
+        if num3 < 0.0 {
+            return None;
+        }
+
+        let num4 = num1 - num3 * num3;
+
+        if num4 > num2 {
+            return None;
+        }
+
+        Some(num3 - (num2 - num4).sqrt())
+    }
+
+    pub fn intersects_plane(&self, plane: &Plane) -> Option<f32> {
+        let num1 = Vector3::dot(&plane.normal, &self.direction);
+
+        if num1.abs() < 1E-05 {
+            return None;
+        }
+
+        let num2 = Vector3::dot(&plane.normal, &self.position);
+        let num3 = (-plane.d - num2) / num1;
+
+        if num3 < 0.0 {
+            if num3 < -1E-05 {
+                return None;
+            }
+
+            return Some(0.0);
+        }
+
+        Some(num3)
+    }
+
+    pub fn intersects_frustum(&self, frustum: &BoundingFrustum) -> Option<f32> {
+        frustum.intersects_ray(self)
+    }
+}
@@ -1,6 +1,9 @@
-use crate::xna::framework::{Point, Rectangle};
+use crate::xna::csharp;
+use crate::xna::framework::{Point, Rectangle, Vector2};
 
 impl Rectangle {
+    pub const EMPTY: Rectangle = Rectangle { x: 0, y: 0, width: 0, height: 0 };
+
     pub fn new(x: i32, y: i32, width: i32, height: i32) -> Rectangle {
         Rectangle {
             x,
@@ -10,31 +13,54 @@ impl Rectangle {
         }
     }
 
+    pub fn from_location_size(location: &Point, size: &Point) -> Rectangle {
+        Rectangle::new(location.x, location.y, size.x, size.y)
+    }
+
     pub fn left(&self) -> i32 {
-        return self.x;
+        self.x
     }
 
     pub fn right(&self) -> i32 {
-        return self.x + self.width;
+        self.x + self.width
     }
 
     pub fn top(&self) -> i32 {
-        return self.y;
+        self.y
     }
 
     pub fn bottom(&self) -> i32 {
-        return self.y + self.height;
+        self.y + self.height
     }
 
     pub fn location(&self) -> Point {
         Point { x: self.x, y: self.y }
     }
 
+    pub fn set_location(&mut self, value: &Point) {
+        self.x = value.x;
+        self.y = value.y;
+    }
+
+    pub fn size(&self) -> Point {
+        Point { x: self.width, y: self.height }
+    }
+
+    pub fn set_size(&mut self, value: &Point) {
+        self.width = value.x;
+        self.height = value.y;
+    }
+
     pub fn center(&self) -> Point {
         Point { x: self.x + self.width / 2, y: self.y + self.height / 2 }
     }
 
+    #[deprecated(note = "use is_empty instead")]
     pub fn empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    pub fn is_empty(&self) -> bool {
         self.x == 0 && self.y == 0 && self.width == 0 && self.height == 0
     }
 
@@ -43,6 +69,10 @@ impl Rectangle {
         self.y += y;
     }
 
+    pub fn offset_point(&mut self, amount: &Point) {
+        self.offset(amount.x, amount.y);
+    }
+
     pub fn inflate(&mut self, horizontal_amount: i32, vertical_amount: i32) {
         self.x -= horizontal_amount;
         self.y -= vertical_amount;
@@ -50,11 +80,27 @@ impl Rectangle {
         self.height += vertical_amount * 2;
     }
 
+    pub fn inflated(value: &Rectangle, horizontal_amount: i32, vertical_amount: i32) -> Rectangle {
+        let mut rectangle = *value;
+        rectangle.inflate(horizontal_amount, vertical_amount);
+
+        rectangle
+    }
+
     pub fn contains(&self, x: i32, y: i32) -> bool {
         self.x <= x && x < self.x + self.width
             && self.y <= y && y < self.y + self.height
     }
 
+    pub fn contains_point(&self, value: &Point) -> bool {
+        self.contains(value.x, value.y)
+    }
+
+    pub fn contains_vector2(&self, value: &Vector2) -> bool {
+        self.x as f32 <= value.x && value.x < (self.x + self.width) as f32
+            && self.y as f32 <= value.y && value.y < (self.y + self.height) as f32
+    }
+
     pub fn contains_rectangle(&self, value: &Rectangle) -> bool {
         self.x <= value.x && value.x + value.width <= self.x + self.width
             && self.y <= value.y && value.y + value.height <= self.y + self.height
@@ -93,11 +139,23 @@ impl Rectangle {
         let num7 = if num1 > num2 { num1 } else { num2 };
         let num8 = if num3 > num4 { num3 } else { num4 };
 
-        Self::new(num5, num6, num7, num8 - num6)
+        Self::new(num5, num6, num7 - num5, num8 - num6)
     }
 
     pub fn equals(&self, other: &Rectangle) -> bool {
         self.x == other.x && self.y == other.y
             && self.width == other.width && self.height == other.height
     }
-}
\ No newline at end of file
+}
+
+impl From<csharp::Rectangle> for Rectangle {
+    fn from(value: csharp::Rectangle) -> Self {
+        Rectangle::new(value.x, value.y, value.width, value.height)
+    }
+}
+
+impl From<Rectangle> for csharp::Rectangle {
+    fn from(value: Rectangle) -> Self {
+        csharp::Rectangle { x: value.x, y: value.y, width: value.width, height: value.height }
+    }
+}
@@ -0,0 +1,177 @@
+use crate::xna::framework::{BoundingBox, Rectangle, RectangleF, Vector2, Vector3};
+
+impl RectangleF {
+    pub const EMPTY: RectangleF = RectangleF { x: 0.0, y: 0.0, width: 0.0, height: 0.0 };
+
+    pub fn new(x: f32, y: f32, width: f32, height: f32) -> RectangleF {
+        RectangleF {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn from_location_size(location: &Vector2, size: &Vector2) -> RectangleF {
+        RectangleF::new(location.x, location.y, size.x, size.y)
+    }
+
+    pub fn from_ltrb(left: f32, top: f32, right: f32, bottom: f32) -> RectangleF {
+        RectangleF::new(left, top, right - left, bottom - top)
+    }
+
+    pub fn left(&self) -> f32 {
+        self.x
+    }
+
+    pub fn right(&self) -> f32 {
+        self.x + self.width
+    }
+
+    pub fn top(&self) -> f32 {
+        self.y
+    }
+
+    pub fn bottom(&self) -> f32 {
+        self.y + self.height
+    }
+
+    pub fn location(&self) -> Vector2 {
+        Vector2 { x: self.x, y: self.y }
+    }
+
+    pub fn set_location(&mut self, value: &Vector2) {
+        self.x = value.x;
+        self.y = value.y;
+    }
+
+    pub fn size(&self) -> Vector2 {
+        Vector2 { x: self.width, y: self.height }
+    }
+
+    pub fn set_size(&mut self, value: &Vector2) {
+        self.width = value.x;
+        self.height = value.y;
+    }
+
+    pub fn center(&self) -> Vector2 {
+        Vector2 { x: self.x + self.width * 0.5, y: self.y + self.height * 0.5 }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.x == 0.0 && self.y == 0.0 && self.width == 0.0 && self.height == 0.0
+    }
+
+    pub fn offset(&mut self, x: f32, y: f32) {
+        self.x += x;
+        self.y += y;
+    }
+
+    pub fn offset_vector2(&mut self, amount: &Vector2) {
+        self.offset(amount.x, amount.y);
+    }
+
+    pub fn inflate(&mut self, horizontal_amount: f32, vertical_amount: f32) {
+        self.x -= horizontal_amount;
+        self.y -= vertical_amount;
+        self.width += horizontal_amount * 2.0;
+        self.height += vertical_amount * 2.0;
+    }
+
+    pub fn inflated(value: &RectangleF, horizontal_amount: f32, vertical_amount: f32) -> RectangleF {
+        let mut rectangle = *value;
+        rectangle.inflate(horizontal_amount, vertical_amount);
+
+        rectangle
+    }
+
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        self.x <= x && x < self.x + self.width
+            && self.y <= y && y < self.y + self.height
+    }
+
+    pub fn contains_vector2(&self, value: &Vector2) -> bool {
+        self.contains(value.x, value.y)
+    }
+
+    pub fn contains_rectangle(&self, value: &RectangleF) -> bool {
+        self.x <= value.x && value.x + value.width <= self.x + self.width
+            && self.y <= value.y && value.y + value.height <= self.y + self.height
+    }
+
+    pub fn intersects(&self, value: &RectangleF) -> bool {
+        value.x < self.x + self.width && self.x < value.x + value.width
+            && value.y < self.y + self.height && self.y < value.y + value.height
+    }
+
+    pub fn intersect(value1: &RectangleF, value2: &RectangleF) -> RectangleF {
+        let num1 = value1.right().min(value2.right());
+        let num2 = value1.bottom().min(value2.bottom());
+        let num3 = value1.x.max(value2.x);
+        let num4 = value1.y.max(value2.y);
+
+        if num1 > num3 && num2 > num4 {
+            return RectangleF::new(num3, num4, num1 - num3, num2 - num4);
+        }
+
+        Self::EMPTY
+    }
+
+    pub fn union(value1: &RectangleF, value2: &RectangleF) -> RectangleF {
+        let num1 = value1.x.min(value2.x);
+        let num2 = value1.y.min(value2.y);
+        let num3 = value1.right().max(value2.right());
+        let num4 = value1.bottom().max(value2.bottom());
+
+        RectangleF::new(num1, num2, num3 - num1, num4 - num2)
+    }
+
+    pub fn minimum_translation_vector(value1: &RectangleF, value2: &RectangleF) -> Vector2 {
+        if !value1.intersects(value2) {
+            return Vector2::zero();
+        }
+
+        let num1 = value1.right() - value2.left();
+        let num2 = value2.right() - value1.left();
+        let num3 = value1.bottom() - value2.top();
+        let num4 = value2.bottom() - value1.top();
+
+        let x = if num1 < num2 { -num1 } else { num2 };
+        let y = if num3 < num4 { -num3 } else { num4 };
+
+        if x.abs() < y.abs() {
+            Vector2 { x, y: 0.0 }
+        } else {
+            Vector2 { x: 0.0, y }
+        }
+    }
+
+    pub fn to_rectangle(self) -> Rectangle {
+        Rectangle::new(self.x as i32, self.y as i32, self.width as i32, self.height as i32)
+    }
+
+    pub fn to_bounding_box(self) -> BoundingBox {
+        BoundingBox {
+            min: Vector3 { x: self.x, y: self.y, z: 0.0 },
+            max: Vector3 { x: self.right(), y: self.bottom(), z: 0.0 },
+        }
+    }
+}
+
+impl From<Rectangle> for RectangleF {
+    fn from(value: Rectangle) -> Self {
+        RectangleF::new(value.x as f32, value.y as f32, value.width as f32, value.height as f32)
+    }
+}
+
+impl From<RectangleF> for Rectangle {
+    fn from(value: RectangleF) -> Self {
+        value.to_rectangle()
+    }
+}
+
+impl From<RectangleF> for BoundingBox {
+    fn from(value: RectangleF) -> Self {
+        value.to_bounding_box()
+    }
+}
@@ -1,9 +1,13 @@
-use crate::xna::framework::{Vector2, Vector3, Vector4};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::xna::csharp::Exception;
+use crate::xna::framework::{MathHelper, Matrix, Point, Quaternion, Vector2, Vector3, Vector4};
 
 impl Vector2 {
 
     pub fn from_xy(x: f32, y: f32) -> Vector2 { Vector2 { x, y }}
 
+    pub fn from_value(value: f32) -> Vector2 { Vector2 { x: value, y: value }}
+
     pub fn zero() -> Vector2 { Vector2::default() }
 
     pub fn one() -> Vector2 { Vector2 { x: 1.0, y: 1.0 }}
@@ -12,11 +16,142 @@ impl Vector2 {
 
     pub fn unit_y() -> Vector2 { Vector2 { x: 0.0, y: 1.0 }}
 
+    pub fn to_point(self) -> Point {
+        Point { x: self.x as i32, y: self.y as i32 }
+    }
+
+    pub fn length(&self) -> f32 {
+        self.length_squared().sqrt()
+    }
+
+    pub fn length_squared(&self) -> f32 {
+        self.x * self.x + self.y * self.y
+    }
+
+    pub fn normalize(&mut self) {
+        *self = Self::normalized(self);
+    }
+
+    pub fn normalized(value: &Vector2) -> Vector2 {
+        let num = 1.0 / value.length();
+        Vector2 { x: value.x * num, y: value.y * num }
+    }
+
+    pub fn distance(value1: &Vector2, value2: &Vector2) -> f32 {
+        Self::distance_squared(value1, value2).sqrt()
+    }
+
+    pub fn distance_squared(value1: &Vector2, value2: &Vector2) -> f32 {
+        let num1 = value1.x - value2.x;
+        let num2 = value1.y - value2.y;
+
+        num1 * num1 + num2 * num2
+    }
+
+    pub fn dot(value1: &Vector2, value2: &Vector2) -> f32 {
+        value1.x * value2.x + value1.y * value2.y
+    }
+
+    pub fn reflect(vector: &Vector2, normal: &Vector2) -> Vector2 {
+        let num = 2.0 * Self::dot(vector, normal);
+
+        Vector2 {
+            x: vector.x - num * normal.x,
+            y: vector.y - num * normal.y,
+        }
+    }
+
+    pub fn min(value1: &Vector2, value2: &Vector2) -> Vector2 {
+        Vector2 {
+            x: if value1.x < value2.x { value1.x } else { value2.x },
+            y: if value1.y < value2.y { value1.y } else { value2.y },
+        }
+    }
+
+    pub fn max(value1: &Vector2, value2: &Vector2) -> Vector2 {
+        Vector2 {
+            x: if value1.x > value2.x { value1.x } else { value2.x },
+            y: if value1.y > value2.y { value1.y } else { value2.y },
+        }
+    }
+
+    pub fn clamp(value1: &Vector2, min: &Vector2, max: &Vector2) -> Vector2 {
+        Self::max(min, &Self::min(value1, max))
+    }
+
+    pub fn lerp(value1: &Vector2, value2: &Vector2, amount: f32) -> Vector2 {
+        MathHelper::lerp(*value1, *value2, amount)
+    }
+
+    pub fn barycentric(value1: &Vector2, value2: &Vector2, value3: &Vector2, amount1: f32, amount2: f32) -> Vector2 {
+        MathHelper::barycentric(*value1, *value2, *value3, amount1, amount2)
+    }
+
+    pub fn smooth_step(value1: &Vector2, value2: &Vector2, amount: f32) -> Vector2 {
+        MathHelper::smooth_step(*value1, *value2, amount)
+    }
+
+    pub fn catmull_rom(value1: &Vector2, value2: &Vector2, value3: &Vector2, value4: &Vector2, amount: f32) -> Vector2 {
+        MathHelper::catmull_rom(*value1, *value2, *value3, *value4, amount)
+    }
+
+    pub fn hermite(value1: &Vector2, tangent1: &Vector2, value2: &Vector2, tangent2: &Vector2, amount: f32) -> Vector2 {
+        MathHelper::hermite(*value1, *tangent1, *value2, *tangent2, amount)
+    }
+
+    pub fn transform_by_quaternion(value: &Vector2, rotation: &Quaternion) -> Vector2 {
+        let vector = Vector3::transform_by_quaternion(&Vector3 { x: value.x, y: value.y, z: 0.0 }, rotation);
+        Vector2 { x: vector.x, y: vector.y }
+    }
+
+    pub fn transform(position: &Vector2, matrix: &Matrix) -> Vector2 {
+        Vector2 {
+            x: position.x * matrix.m11 + position.y * matrix.m21 + matrix.m41,
+            y: position.x * matrix.m12 + position.y * matrix.m22 + matrix.m42,
+        }
+    }
+
+    pub fn transform_normal(normal: &Vector2, matrix: &Matrix) -> Vector2 {
+        Vector2 {
+            x: normal.x * matrix.m11 + normal.y * matrix.m21,
+            y: normal.x * matrix.m12 + normal.y * matrix.m22,
+        }
+    }
+
+    pub fn transform_array(source_array: &[Vector2], matrix: &Matrix, destination_array: &mut [Vector2]) -> Result<(), Exception> {
+        validate_transform_arrays(source_array.len(), destination_array.len())?;
+
+        let (m11, m12, m21, m22, m41, m42) = (matrix.m11, matrix.m12, matrix.m21, matrix.m22, matrix.m41, matrix.m42);
+
+        for (source, destination) in source_array.iter().zip(destination_array.iter_mut()) {
+            destination.x = source.x * m11 + source.y * m21 + m41;
+            destination.y = source.x * m12 + source.y * m22 + m42;
+        }
+
+        Ok(())
+    }
+
+    pub fn transform_normal_array(source_array: &[Vector2], matrix: &Matrix, destination_array: &mut [Vector2]) -> Result<(), Exception> {
+        validate_transform_arrays(source_array.len(), destination_array.len())?;
+
+        let (m11, m12, m21, m22) = (matrix.m11, matrix.m12, matrix.m21, matrix.m22);
+
+        for (source, destination) in source_array.iter().zip(destination_array.iter_mut()) {
+            destination.x = source.x * m11 + source.y * m21;
+            destination.y = source.x * m12 + source.y * m22;
+        }
+
+        Ok(())
+    }
 }
 
 impl Vector3 {
     pub fn from_xyz(x: f32, y: f32, z: f32) -> Vector3 { Vector3 { x, y, z }}
 
+    pub fn from_value(value: f32) -> Vector3 { Vector3 { x: value, y: value, z: value }}
+
+    pub fn from_vector2(value: Vector2, z: f32) -> Vector3 { Vector3 { x: value.x, y: value.y, z }}
+
     pub fn zero() -> Vector3 { Vector3::default() }
 
     pub fn one() -> Vector3 { Vector3 { x: 1.0, y: 1.0, z: 1.0 }}
@@ -38,20 +173,431 @@ impl Vector3 {
     pub fn forward() -> Vector3 {Vector3 { x: 0.0, y: 0.0, z: -1.0 } }
 
     pub fn backward() -> Vector3 { Self::unit_z() }
+
+    pub fn length(&self) -> f32 {
+        self.length_squared().sqrt()
+    }
+
+    pub fn length_squared(&self) -> f32 {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    pub fn normalize(&mut self) {
+        *self = Self::normalized(self);
+    }
+
+    pub fn normalized(value: &Vector3) -> Vector3 {
+        let num = 1.0 / value.length();
+        Vector3 { x: value.x * num, y: value.y * num, z: value.z * num }
+    }
+
+    pub fn distance(value1: &Vector3, value2: &Vector3) -> f32 {
+        Self::distance_squared(value1, value2).sqrt()
+    }
+
+    pub fn distance_squared(value1: &Vector3, value2: &Vector3) -> f32 {
+        let num1 = value1.x - value2.x;
+        let num2 = value1.y - value2.y;
+        let num3 = value1.z - value2.z;
+
+        num1 * num1 + num2 * num2 + num3 * num3
+    }
+
+    pub fn dot(value1: &Vector3, value2: &Vector3) -> f32 {
+        value1.x * value2.x + value1.y * value2.y + value1.z * value2.z
+    }
+
+    pub fn cross(vector1: &Vector3, vector2: &Vector3) -> Vector3 {
+        Vector3 {
+            x: vector1.y * vector2.z - vector1.z * vector2.y,
+            y: vector1.z * vector2.x - vector1.x * vector2.z,
+            z: vector1.x * vector2.y - vector1.y * vector2.x,
+        }
+    }
+
+    pub fn reflect(vector: &Vector3, normal: &Vector3) -> Vector3 {
+        let num = 2.0 * Self::dot(vector, normal);
+
+        Vector3 {
+            x: vector.x - num * normal.x,
+            y: vector.y - num * normal.y,
+            z: vector.z - num * normal.z,
+        }
+    }
+
+    pub fn min(value1: &Vector3, value2: &Vector3) -> Vector3 {
+        Vector3 {
+            x: if value1.x < value2.x { value1.x } else { value2.x },
+            y: if value1.y < value2.y { value1.y } else { value2.y },
+            z: if value1.z < value2.z { value1.z } else { value2.z },
+        }
+    }
+
+    pub fn max(value1: &Vector3, value2: &Vector3) -> Vector3 {
+        Vector3 {
+            x: if value1.x > value2.x { value1.x } else { value2.x },
+            y: if value1.y > value2.y { value1.y } else { value2.y },
+            z: if value1.z > value2.z { value1.z } else { value2.z },
+        }
+    }
+
+    pub fn clamp(value1: &Vector3, min: &Vector3, max: &Vector3) -> Vector3 {
+        Self::max(min, &Self::min(value1, max))
+    }
+
+    pub fn lerp(value1: &Vector3, value2: &Vector3, amount: f32) -> Vector3 {
+        MathHelper::lerp(*value1, *value2, amount)
+    }
+
+    pub fn barycentric(value1: &Vector3, value2: &Vector3, value3: &Vector3, amount1: f32, amount2: f32) -> Vector3 {
+        MathHelper::barycentric(*value1, *value2, *value3, amount1, amount2)
+    }
+
+    pub fn smooth_step(value1: &Vector3, value2: &Vector3, amount: f32) -> Vector3 {
+        MathHelper::smooth_step(*value1, *value2, amount)
+    }
+
+    pub fn catmull_rom(value1: &Vector3, value2: &Vector3, value3: &Vector3, value4: &Vector3, amount: f32) -> Vector3 {
+        MathHelper::catmull_rom(*value1, *value2, *value3, *value4, amount)
+    }
+
+    pub fn hermite(value1: &Vector3, tangent1: &Vector3, value2: &Vector3, tangent2: &Vector3, amount: f32) -> Vector3 {
+        MathHelper::hermite(*value1, *tangent1, *value2, *tangent2, amount)
+    }
+
+    pub fn transform_by_quaternion(value: &Vector3, rotation: &Quaternion) -> Vector3 {
+        let num1 = rotation.x + rotation.x;
+        let num2 = rotation.y + rotation.y;
+        let num3 = rotation.z + rotation.z;
+        let num4 = rotation.w * num1;
+        let num5 = rotation.w * num2;
+        let num6 = rotation.w * num3;
+        let num7 = rotation.x * num1;
+        let num8 = rotation.x * num2;
+        let num9 = rotation.x * num3;
+        let num10 = rotation.y * num2;
+        let num11 = rotation.y * num3;
+        let num12 = rotation.z * num3;
+
+        Vector3 {
+            x: value.x * (1.0 - num10 - num12) + value.y * (num8 - num6) + value.z * (num9 + num5),
+            y: value.x * (num8 + num6) + value.y * (1.0 - num7 - num12) + value.z * (num11 - num4),
+            z: value.x * (num9 - num5) + value.y * (num11 + num4) + value.z * (1.0 - num7 - num10),
+        }
+    }
+
+    pub fn transform(position: &Vector3, matrix: &Matrix) -> Vector3 {
+        Vector3 {
+            x: position.x * matrix.m11 + position.y * matrix.m21 + position.z * matrix.m31 + matrix.m41,
+            y: position.x * matrix.m12 + position.y * matrix.m22 + position.z * matrix.m32 + matrix.m42,
+            z: position.x * matrix.m13 + position.y * matrix.m23 + position.z * matrix.m33 + matrix.m43,
+        }
+    }
+
+    pub fn transform_normal(normal: &Vector3, matrix: &Matrix) -> Vector3 {
+        Vector3 {
+            x: normal.x * matrix.m11 + normal.y * matrix.m21 + normal.z * matrix.m31,
+            y: normal.x * matrix.m12 + normal.y * matrix.m22 + normal.z * matrix.m32,
+            z: normal.x * matrix.m13 + normal.y * matrix.m23 + normal.z * matrix.m33,
+        }
+    }
+
+    pub fn transform_array(source_array: &[Vector3], matrix: &Matrix, destination_array: &mut [Vector3]) -> Result<(), Exception> {
+        validate_transform_arrays(source_array.len(), destination_array.len())?;
+
+        let (m11, m12, m13) = (matrix.m11, matrix.m12, matrix.m13);
+        let (m21, m22, m23) = (matrix.m21, matrix.m22, matrix.m23);
+        let (m31, m32, m33) = (matrix.m31, matrix.m32, matrix.m33);
+        let (m41, m42, m43) = (matrix.m41, matrix.m42, matrix.m43);
+
+        for (source, destination) in source_array.iter().zip(destination_array.iter_mut()) {
+            let (x, y, z) = (source.x, source.y, source.z);
+
+            destination.x = x * m11 + y * m21 + z * m31 + m41;
+            destination.y = x * m12 + y * m22 + z * m32 + m42;
+            destination.z = x * m13 + y * m23 + z * m33 + m43;
+        }
+
+        Ok(())
+    }
+
+    pub fn transform_normal_array(source_array: &[Vector3], matrix: &Matrix, destination_array: &mut [Vector3]) -> Result<(), Exception> {
+        validate_transform_arrays(source_array.len(), destination_array.len())?;
+
+        let (m11, m12, m13) = (matrix.m11, matrix.m12, matrix.m13);
+        let (m21, m22, m23) = (matrix.m21, matrix.m22, matrix.m23);
+        let (m31, m32, m33) = (matrix.m31, matrix.m32, matrix.m33);
+
+        for (source, destination) in source_array.iter().zip(destination_array.iter_mut()) {
+            let (x, y, z) = (source.x, source.y, source.z);
+
+            destination.x = x * m11 + y * m21 + z * m31;
+            destination.y = x * m12 + y * m22 + z * m32;
+            destination.z = x * m13 + y * m23 + z * m33;
+        }
+
+        Ok(())
+    }
+
+    pub fn transform_array_by_quaternion(source_array: &[Vector3], rotation: &Quaternion, destination_array: &mut [Vector3]) -> Result<(), Exception> {
+        let matrix = Matrix::create_from_quaternion(rotation);
+        Self::transform_normal_array(source_array, &matrix, destination_array)
+    }
 }
 
 impl Vector4 {
     pub fn from_xyzw(x: f32, y: f32, z: f32, w:f32) -> Vector4 { Vector4 { x, y, z, w }}
 
+    pub fn from_value(value: f32) -> Vector4 { Vector4 { x: value, y: value, z: value, w: value }}
+
+    pub fn from_vector2(value: Vector2, z: f32, w: f32) -> Vector4 { Vector4 { x: value.x, y: value.y, z, w }}
+
+    pub fn from_vector3(value: Vector3, w: f32) -> Vector4 { Vector4 { x: value.x, y: value.y, z: value.z, w }}
+
     pub fn zero() -> Vector4 { Vector4::default() }
 
     pub fn one() -> Vector4 { Vector4 { x: 1.0, y: 1.0, z: 1.0, w: 1.0 }}
 
-    pub fn unit_x() -> Vector4 { Vector4 { x: 1.0, y: 0.0, z: 0.0, w: 1.0 }}
+    pub fn unit_x() -> Vector4 { Vector4 { x: 1.0, y: 0.0, z: 0.0, w: 0.0 }}
+
+    pub fn unit_y() -> Vector4 { Vector4 { x: 0.0, y: 1.0, z: 0.0, w: 0.0 }}
+
+    pub fn unit_z() -> Vector4 { Vector4 { x: 0.0, y: 0.0, z: 1.0, w: 0.0 }}
+
+    pub fn unit_w() -> Vector4 { Vector4 { x: 0.0, y: 0.0, z: 0.0, w: 1.0 }}
+
+    pub fn length(&self) -> f32 {
+        self.length_squared().sqrt()
+    }
+
+    pub fn length_squared(&self) -> f32 {
+        self.x * self.x + self.y * self.y + self.z * self.z + self.w * self.w
+    }
+
+    pub fn normalize(&mut self) {
+        *self = Self::normalized(self);
+    }
+
+    pub fn normalized(value: &Vector4) -> Vector4 {
+        let num = 1.0 / value.length();
+        Vector4 { x: value.x * num, y: value.y * num, z: value.z * num, w: value.w * num }
+    }
+
+    pub fn distance(value1: &Vector4, value2: &Vector4) -> f32 {
+        Self::distance_squared(value1, value2).sqrt()
+    }
+
+    pub fn distance_squared(value1: &Vector4, value2: &Vector4) -> f32 {
+        let num1 = value1.x - value2.x;
+        let num2 = value1.y - value2.y;
+        let num3 = value1.z - value2.z;
+        let num4 = value1.w - value2.w;
+
+        num1 * num1 + num2 * num2 + num3 * num3 + num4 * num4
+    }
+
+    pub fn dot(vector1: &Vector4, vector2: &Vector4) -> f32 {
+        vector1.x * vector2.x + vector1.y * vector2.y + vector1.z * vector2.z + vector1.w * vector2.w
+    }
+
+    pub fn min(value1: &Vector4, value2: &Vector4) -> Vector4 {
+        Vector4 {
+            x: if value1.x < value2.x { value1.x } else { value2.x },
+            y: if value1.y < value2.y { value1.y } else { value2.y },
+            z: if value1.z < value2.z { value1.z } else { value2.z },
+            w: if value1.w < value2.w { value1.w } else { value2.w },
+        }
+    }
+
+    pub fn max(value1: &Vector4, value2: &Vector4) -> Vector4 {
+        Vector4 {
+            x: if value1.x > value2.x { value1.x } else { value2.x },
+            y: if value1.y > value2.y { value1.y } else { value2.y },
+            z: if value1.z > value2.z { value1.z } else { value2.z },
+            w: if value1.w > value2.w { value1.w } else { value2.w },
+        }
+    }
+
+    pub fn clamp(value1: &Vector4, min: &Vector4, max: &Vector4) -> Vector4 {
+        Self::max(min, &Self::min(value1, max))
+    }
+
+    pub fn lerp(value1: &Vector4, value2: &Vector4, amount: f32) -> Vector4 {
+        MathHelper::lerp(*value1, *value2, amount)
+    }
+
+    pub fn barycentric(value1: &Vector4, value2: &Vector4, value3: &Vector4, amount1: f32, amount2: f32) -> Vector4 {
+        MathHelper::barycentric(*value1, *value2, *value3, amount1, amount2)
+    }
+
+    pub fn smooth_step(value1: &Vector4, value2: &Vector4, amount: f32) -> Vector4 {
+        MathHelper::smooth_step(*value1, *value2, amount)
+    }
+
+    pub fn catmull_rom(value1: &Vector4, value2: &Vector4, value3: &Vector4, value4: &Vector4, amount: f32) -> Vector4 {
+        MathHelper::catmull_rom(*value1, *value2, *value3, *value4, amount)
+    }
+
+    pub fn hermite(value1: &Vector4, tangent1: &Vector4, value2: &Vector4, tangent2: &Vector4, amount: f32) -> Vector4 {
+        MathHelper::hermite(*value1, *tangent1, *value2, *tangent2, amount)
+    }
+
+    pub fn transform_by_quaternion(value: &Vector4, rotation: &Quaternion) -> Vector4 {
+        let vector = Vector3::transform_by_quaternion(&Vector3 { x: value.x, y: value.y, z: value.z }, rotation);
+        Vector4 { x: vector.x, y: vector.y, z: vector.z, w: value.w }
+    }
+
+    pub fn transform(vector: &Vector4, matrix: &Matrix) -> Vector4 {
+        Vector4 {
+            x: vector.x * matrix.m11 + vector.y * matrix.m21 + vector.z * matrix.m31 + vector.w * matrix.m41,
+            y: vector.x * matrix.m12 + vector.y * matrix.m22 + vector.z * matrix.m32 + vector.w * matrix.m42,
+            z: vector.x * matrix.m13 + vector.y * matrix.m23 + vector.z * matrix.m33 + vector.w * matrix.m43,
+            w: vector.x * matrix.m14 + vector.y * matrix.m24 + vector.z * matrix.m34 + vector.w * matrix.m44,
+        }
+    }
+
+    pub fn transform_vector2(position: &Vector2, matrix: &Matrix) -> Vector4 {
+        Self::transform(&Vector4 { x: position.x, y: position.y, z: 0.0, w: 1.0 }, matrix)
+    }
+
+    pub fn transform_vector3(position: &Vector3, matrix: &Matrix) -> Vector4 {
+        Self::transform(&Vector4 { x: position.x, y: position.y, z: position.z, w: 1.0 }, matrix)
+    }
 
-    pub fn unit_y() -> Vector4 { Vector4 { x: 0.0, y: 1.0, z: 0.0, w: 1.0 }}
+    pub fn transform_array(source_array: &[Vector4], matrix: &Matrix, destination_array: &mut [Vector4]) -> Result<(), Exception> {
+        validate_transform_arrays(source_array.len(), destination_array.len())?;
 
-    pub fn unit_z() -> Vector4 { Vector4 { x: 0.0, y: 0.0, z: 1.0, w: 1.0 }}
+        let (m11, m12, m13, m14) = (matrix.m11, matrix.m12, matrix.m13, matrix.m14);
+        let (m21, m22, m23, m24) = (matrix.m21, matrix.m22, matrix.m23, matrix.m24);
+        let (m31, m32, m33, m34) = (matrix.m31, matrix.m32, matrix.m33, matrix.m34);
+        let (m41, m42, m43, m44) = (matrix.m41, matrix.m42, matrix.m43, matrix.m44);
+
+        for (source, destination) in source_array.iter().zip(destination_array.iter_mut()) {
+            let (x, y, z, w) = (source.x, source.y, source.z, source.w);
+
+            destination.x = x * m11 + y * m21 + z * m31 + w * m41;
+            destination.y = x * m12 + y * m22 + z * m32 + w * m42;
+            destination.z = x * m13 + y * m23 + z * m33 + w * m43;
+            destination.w = x * m14 + y * m24 + z * m34 + w * m44;
+        }
+
+        Ok(())
+    }
+}
+
+fn validate_transform_arrays(source_length: usize, destination_length: usize) -> Result<(), Exception> {
+    if destination_length < source_length {
+        return Err(Exception::argument_exception("destination_array is shorter than source_array.", None));
+    }
+
+    Ok(())
+}
+
+macro_rules! impl_vector_operators {
+    ($vector:ident { $($field:ident),+ }) => {
+        impl Add for $vector {
+            type Output = $vector;
+
+            fn add(self, rhs: $vector) -> $vector {
+                $vector { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl Sub for $vector {
+            type Output = $vector;
+
+            fn sub(self, rhs: $vector) -> $vector {
+                $vector { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl Mul for $vector {
+            type Output = $vector;
+
+            fn mul(self, rhs: $vector) -> $vector {
+                $vector { $($field: self.$field * rhs.$field),+ }
+            }
+        }
+
+        impl Mul<f32> for $vector {
+            type Output = $vector;
+
+            fn mul(self, rhs: f32) -> $vector {
+                $vector { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl Mul<$vector> for f32 {
+            type Output = $vector;
+
+            fn mul(self, rhs: $vector) -> $vector {
+                rhs * self
+            }
+        }
+
+        impl Div for $vector {
+            type Output = $vector;
+
+            fn div(self, rhs: $vector) -> $vector {
+                $vector { $($field: self.$field / rhs.$field),+ }
+            }
+        }
+
+        impl Div<f32> for $vector {
+            type Output = $vector;
+
+            fn div(self, rhs: f32) -> $vector {
+                let num = 1.0 / rhs;
+                $vector { $($field: self.$field * num),+ }
+            }
+        }
+
+        impl Neg for $vector {
+            type Output = $vector;
+
+            fn neg(self) -> $vector {
+                $vector { $($field: -self.$field),+ }
+            }
+        }
+
+        impl AddAssign for $vector {
+            fn add_assign(&mut self, rhs: $vector) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $vector {
+            fn sub_assign(&mut self, rhs: $vector) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign for $vector {
+            fn mul_assign(&mut self, rhs: $vector) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl MulAssign<f32> for $vector {
+            fn mul_assign(&mut self, rhs: f32) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign for $vector {
+            fn div_assign(&mut self, rhs: $vector) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl DivAssign<f32> for $vector {
+            fn div_assign(&mut self, rhs: f32) {
+                *self = *self / rhs;
+            }
+        }
+    };
+}
 
-    pub fn unit_w() -> Vector4 { Vector4 { x: 0.0, y: 0.0, z: 1.0, w: 1.0 }}
-}
\ No newline at end of file
+impl_vector_operators!(Vector2 { x, y });
+impl_vector_operators!(Vector3 { x, y, z });
+impl_vector_operators!(Vector4 { x, y, z, w });
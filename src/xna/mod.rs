@@ -1,14 +1,15 @@
 use crate::xna::csharp::Exception;
-use std::ops::Deref;
 
 pub mod framework;
 pub mod csharp;
 pub mod platform;
 
+#[cfg(target_os = "windows")]
 trait ToWide {
     fn to_wide(&self) -> Vec<u16>;
 }
 
+#[cfg(target_os = "windows")]
 impl ToWide for str {
     fn to_wide(&self) -> Vec<u16> {
         self.encode_utf16().chain(std::iter::once(0)).collect()
@@ -23,8 +24,8 @@ pub trait ExceptionConverter<T> {
 
 impl<T> ExceptionConverter<T> for Option<T> {
     fn unwrap_or_exception(self, message: &str) -> Result<T, Exception> {
-        if self.is_some() {
-            return Ok(self.unwrap())
+        if let Some(value) = self {
+            return Ok(value)
         }
 
         Err(Exception::new(message, None))
@@ -55,8 +56,8 @@ pub trait SilentExceptionConverter<T> {
 
 impl<T> SilentExceptionConverter<T> for Option<T> {
     fn unwrap_or_default_exception(self) -> Result<T, Exception> {
-        if self.is_some() {
-            return Ok(self.unwrap())
+        if let Some(value) = self {
+            return Ok(value)
         }
 
         Err(Exception::new("Invalid unwrap() operation.", None))
@@ -0,0 +1,184 @@
+use crate::xna::csharp::{Exception, TimeSpan};
+use crate::xna::framework::game::{Game, GameTime};
+use crate::xna::framework::graphics::GraphicsDevice;
+use crate::xna::platform::headless::StepTimer;
+use crate::xna::SilentExceptionConverter;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+impl Game {
+    pub fn exit(&mut self) -> Result<(), Exception> {
+        let gw = self.game_window.unwrap_ref_or_default_exception()?;
+        gw.borrow().close()
+    }
+
+    fn start_game_loop(&mut self) -> Result<(), Exception> {
+        self.platform.step_timer = StepTimer::new();
+        self.platform.step_timer.is_fixed_time_step = self.is_fixed_time_step;
+        self.platform.step_timer.target_elapsed_ticks = self.target_elapsed_time.ticks as u64;
+
+        let game_window = self.game_window.unwrap_ref_or_default_exception()?.clone();
+
+        while !game_window.borrow().platform.is_closed.get() {
+            self.tick()?;
+        }
+
+        Ok(())
+    }
+
+    fn tick(&mut self) -> Result<(), Exception> {
+        let mut timer = self.platform.step_timer;
+
+        let mut lambda = |step_timer: &StepTimer| -> Result<(), Exception> {
+            self.current_game_time.elapsed_time = TimeSpan::from_ticks(step_timer.get_elapsed_ticks() as i64);
+            self.current_game_time.total_time = TimeSpan::from_ticks(step_timer.get_total_ticks() as i64);
+
+            let current_game_time = self.current_game_time;
+            self.update(&current_game_time)
+        };
+
+        timer.tick(&mut lambda)?;
+
+        self.platform.step_timer = timer;
+
+        self.begin_draw()?;
+        let current_game_time = self.current_game_time;
+        self.draw(&current_game_time)?;
+        self.end_draw()?;
+
+        Ok(())
+    }
+
+    pub fn create_window(&mut self) -> Result<(), Exception> {
+        if self.platform.is_running {
+            return Ok(());
+        }
+
+        let game_window = self.game_window.unwrap_ref_or_default_exception()?;
+        game_window.borrow_mut().create()?;
+
+        self.is_window_created = true;
+
+        Ok(())
+    }
+
+    pub fn run(&mut self) -> Result<(), Exception> {
+        if self.platform.is_running {
+            return Ok(());
+        }
+
+        if !self.is_window_created {
+            return Err(Exception::new("Window is not running", None));
+        }
+
+        self.initialize()?;
+
+        self.platform.is_running = true;
+
+        if let Some(handler) = &self.handler {
+            handler.borrow_mut().on_begin_run()?;
+        }
+
+        self.start_game_loop()?;
+        self.platform.is_running = false;
+
+        if let Some(handler) = &self.handler {
+            handler.borrow_mut().on_end_run()?;
+        }
+
+        Ok(())
+    }
+
+    fn initialize(&mut self) -> Result<(), Exception> {
+        if let Some(handler) = &self.handler {
+            handler.borrow_mut().on_initialize()?;
+        }
+
+        self.load_content()
+    }
+
+    fn load_content(&mut self) -> Result<(), Exception> {
+        if let Some(handler) = &self.handler {
+            handler.borrow_mut().on_load_content()?;
+        }
+
+        Ok(())
+    }
+
+    fn update(&mut self, game_time: &GameTime) -> Result<(), Exception> {
+        if let Some(handler) = &self.handler {
+            handler.borrow_mut().on_update(game_time)?;
+        }
+
+        Ok(())
+    }
+
+    fn begin_draw(&self) -> Result<(), Exception> {
+        if let Some(handler) = &self.handler {
+            handler.borrow_mut().on_begin_draw()?;
+        }
+
+        Ok(())
+    }
+
+    fn draw(&mut self, game_time: &GameTime) -> Result<(), Exception> {
+        if let Some(handler) = &self.handler {
+            handler.borrow_mut().on_draw(game_time)?;
+        }
+
+        if let Some(device) = &self.graphics_device {
+            device.borrow().present()?;
+        }
+
+        Ok(())
+    }
+
+    fn end_draw(&mut self) -> Result<(), Exception> {
+        if let Some(handler) = &self.handler {
+            handler.borrow_mut().on_end_draw()?;
+        }
+
+        Ok(())
+    }
+
+    pub fn resize_window(&mut self, width: u32, height: u32) -> Result<(), Exception> {
+        let gw_temp = self.game_window.unwrap_ref_or_default_exception()?;
+        let mut game_window = gw_temp.borrow_mut();
+
+        let windows_bounds = game_window.client_bounds();
+
+        if windows_bounds.width != width as i32 || windows_bounds.height != height as i32 {
+            game_window.width = width;
+            game_window.height = height;
+
+            game_window.update()?
+        }
+
+        Ok(())
+    }
+
+    pub fn attach_graphics_device(&mut self, device: Rc<RefCell<GraphicsDevice>>) {
+        self.graphics_device = Some(device);
+    }
+
+    pub fn run_one_frame(&mut self) -> Result<(), Exception> {
+        self.tick()
+    }
+
+    pub fn reset_elapsed_time(&mut self) -> Result<(), Exception> {
+        self.platform.step_timer.reset_elapsed_time()
+    }
+
+    pub fn set_target_elapsed_time(&mut self, value: TimeSpan) {
+        if !self.is_fixed_time_step {
+            return;
+        }
+
+        self.platform.step_timer.target_elapsed_ticks = value.ticks as u64;
+    }
+
+    pub fn set_is_fixed_time_step(&mut self, value: bool) {
+        self.is_fixed_time_step = value;
+        self.platform.step_timer.is_fixed_time_step = value;
+    }
+}
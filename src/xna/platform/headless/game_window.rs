@@ -0,0 +1,42 @@
+use crate::xna::csharp::Exception;
+use crate::xna::csharp::forms::Screen;
+use crate::xna::framework::game::{GameWindow, GameWindowStyle};
+
+impl GameWindow {
+    pub fn close(&self) -> Result<(), Exception> {
+        self.platform.is_closed.set(true);
+
+        Ok(())
+    }
+
+    pub fn create(&mut self) -> Result<(), Exception> {
+        self.platform.is_created = true;
+        self.platform.is_closed.set(false);
+
+        self.update()
+    }
+
+    pub fn update(&mut self) -> Result<(), Exception> {
+        let screens = Screen::all_screens();
+        let primary = screens.iter().find(|screen| screen.primary);
+
+        let Some(screen) = primary else {
+            return Ok(());
+        };
+
+        match self.style {
+            GameWindowStyle::Windowed => {
+                self.x = screen.bounds.x + (screen.bounds.width / 2) - (self.width as i32 / 2);
+                self.y = screen.bounds.y + (screen.bounds.height / 2) - (self.height as i32 / 2);
+            }
+            GameWindowStyle::FullScreen | GameWindowStyle::BorderlessFullScreen => {
+                self.x = screen.bounds.x;
+                self.y = screen.bounds.y;
+                self.width = screen.bounds.width as u32;
+                self.height = screen.bounds.height as u32;
+            }
+        }
+
+        Ok(())
+    }
+}
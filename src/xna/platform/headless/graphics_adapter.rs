@@ -0,0 +1,71 @@
+use crate::xna::csharp::Exception;
+use crate::xna::framework::Rectangle;
+use crate::xna::framework::graphics::{DepthFormat, DisplayMode, DisplayModeCollection, DisplayModeScaling, GraphicsAdapter, GraphicsAdapterOutput, ScanlineOrder, SurfaceFormat};
+
+impl GraphicsAdapter {
+    pub const HEADLESS_DEVICE_NAME: &'static str = "HEADLESS";
+
+    pub fn adapters() -> Result<Vec<GraphicsAdapter>, Exception> {
+        Ok(vec![Self::default_adapter()?])
+    }
+
+    pub fn default_adapter() -> Result<GraphicsAdapter, Exception> {
+        let current_display_mode = Self::headless_display_mode();
+
+        let output = GraphicsAdapterOutput {
+            device_name: Self::HEADLESS_DEVICE_NAME.to_string(),
+            desktop_coordinates: Rectangle::new(0, 0, current_display_mode.width as i32, current_display_mode.height as i32),
+            attached_to_desktop: true,
+            display_mode_collection: Self::headless_display_modes(),
+            current_display_mode: Some(current_display_mode),
+        };
+
+        Ok(GraphicsAdapter {
+            index: 0,
+            description: "Headless Graphics Adapter".to_string(),
+            is_default: true,
+            outputs: vec![output.clone()],
+            current_output: Some(output),
+            ..Default::default()
+        })
+    }
+
+    pub fn is_wide_screen() -> bool {
+        let mode = Self::headless_display_mode();
+
+        mode.width as f32 / mode.height as f32 > 1.34
+    }
+
+    pub fn query_back_buffer_format(&self, format: &SurfaceFormat, depth_format: &DepthFormat, _multi_sample_count: u32)
+    -> Result<(SurfaceFormat, DepthFormat, u32), Exception> {
+        if format.is_compressed() || format.size() == 0 {
+            return Err(Exception::new("Unsupported backbuffer format.", None));
+        }
+
+        Ok((*format, *depth_format, 0))
+    }
+
+    pub fn headless_display_mode() -> DisplayMode {
+        Self::display_mode(1920, 1080)
+    }
+
+    fn headless_display_modes() -> DisplayModeCollection {
+        let sizes = [(640, 480), (800, 600), (1024, 768), (1280, 720), (1366, 768), (1600, 900), (1920, 1080)];
+
+        DisplayModeCollection {
+            display_modes: sizes.iter().map(|(width, height)| Self::display_mode(*width, *height)).collect(),
+        }
+    }
+
+    fn display_mode(width: u32, height: u32) -> DisplayMode {
+        DisplayMode {
+            width,
+            height,
+            refresh_rate_numerator: 60,
+            refresh_rate_denominator: 1,
+            format: SurfaceFormat::Color,
+            scanline_order: ScanlineOrder::Unspecified,
+            scaling: DisplayModeScaling::Unspecified,
+        }
+    }
+}
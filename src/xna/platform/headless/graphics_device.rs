@@ -0,0 +1,130 @@
+use crate::xna::csharp::Exception;
+use crate::xna::framework::{Color, Matrix, Rectangle};
+use crate::xna::framework::graphics::{GraphicsAdapter, GraphicsDevice, PresentationParameters, PrimitiveType, SoftwareRenderer, Texture2D, VertexPositionColor, Viewport};
+use crate::xna::platform::headless::HeadlessGraphicsDevice;
+
+impl GraphicsDevice {
+    pub fn initialize(&mut self, adapter: Option<GraphicsAdapter>) -> Result<(), Exception> {
+        if !self.platform.is_initialized {
+            self.create(adapter)?
+        }
+
+        let width = self.presentation_parameters.back_buffer_width;
+        let height = self.presentation_parameters.back_buffer_height;
+
+        self.platform.renderer.replace(SoftwareRenderer::new(width, height));
+        self.platform.present_count.set(0);
+
+        self.viewport = Viewport {
+            x: 0.0,
+            y: 0.0,
+            width: width as f32,
+            height: height as f32,
+            max_depth: 1.0,
+            min_depth: 0.0,
+        };
+        self.scissor_rectangle = Rectangle::new(0, 0, width as i32, height as i32);
+
+        self.apply_blend_state()?;
+        self.apply_rasterizer_state()?;
+        self.apply_depth_stencil_state()?;
+        self.apply_sampler_states()?;
+
+        self.render_target.texture = Texture2D::new_with_format(width, height, false, self.presentation_parameters.back_buffer_format)?;
+
+        Ok(())
+    }
+
+    fn create(&mut self, adapter: Option<GraphicsAdapter>) -> Result<(), Exception> {
+        let graphics_adapter = match adapter {
+            Some(adapter) => adapter,
+            None => GraphicsAdapter::default_adapter()?,
+        };
+
+        self.adapter = Some(graphics_adapter);
+        self.platform.is_initialized = true;
+
+        Ok(())
+    }
+
+    pub fn present(&self) -> Result<(), Exception> {
+        if !self.platform.is_initialized {
+            return Err(Exception::invalid_operation("The graphics device is not initialized.", None));
+        }
+
+        self.platform.present_count.set(self.platform.present_count.get() + 1);
+
+        Ok(())
+    }
+
+    pub fn clear(&self, color: Color) -> Result<(), Exception> {
+        if !self.platform.is_initialized {
+            return Err(Exception::invalid_operation("The graphics device is not initialized.", None));
+        }
+
+        self.platform.renderer.borrow_mut().clear(color, 1.0, 0);
+
+        Ok(())
+    }
+
+    pub fn draw_user_primitives(&mut self, primitive_type: PrimitiveType, vertex_data: &[VertexPositionColor], vertex_offset: usize,
+                                primitive_count: usize, transform: &Matrix) -> Result<(), Exception> {
+        if !self.platform.is_initialized {
+            return Err(Exception::invalid_operation("The graphics device is not initialized.", None));
+        }
+
+        self.apply_blend_state()?;
+        self.apply_rasterizer_state()?;
+        self.apply_depth_stencil_state()?;
+
+        let mut renderer = self.platform.renderer.borrow_mut();
+        renderer.viewport = self.viewport;
+        renderer.scissor_rectangle = self.scissor_rectangle;
+        renderer.draw_user_primitives(primitive_type, vertex_data, vertex_offset, primitive_count, transform)
+    }
+
+    pub fn get_back_buffer_data(&self, data: &mut [Color]) -> Result<(), Exception> {
+        let renderer = self.platform.renderer.borrow();
+        let back_buffer = &renderer.color_buffer;
+
+        if data.len() < back_buffer.len() {
+            return Err(Exception::argument_exception("data is smaller than the back buffer.", None));
+        }
+
+        data[..back_buffer.len()].copy_from_slice(back_buffer);
+
+        Ok(())
+    }
+
+    fn apply_sampler_states(&mut self) -> Result<(), Exception> {
+        self.platform.sampler_state_collection = self.sampler_state_collection.samplers.clone();
+
+        Ok(())
+    }
+
+    fn apply_depth_stencil_state(&mut self) -> Result<(), Exception> {
+        self.platform.renderer.borrow_mut().depth_stencil_state = self.depth_stencil_state;
+
+        Ok(())
+    }
+
+    fn apply_rasterizer_state(&mut self) -> Result<(), Exception> {
+        self.platform.renderer.borrow_mut().rasterizer_state = self.rasterizer_state;
+
+        Ok(())
+    }
+
+    fn apply_blend_state(&mut self) -> Result<(), Exception> {
+        self.platform.renderer.borrow_mut().blend_state = self.blend_state;
+
+        Ok(())
+    }
+
+    pub fn reset(&mut self, parameters: &PresentationParameters, adapter: &GraphicsAdapter) -> Result<(), Exception> {
+        self.adapter = Some(adapter.clone());
+        self.presentation_parameters = *parameters;
+        self.platform = HeadlessGraphicsDevice::default();
+
+        self.initialize(Some(adapter.clone()))
+    }
+}
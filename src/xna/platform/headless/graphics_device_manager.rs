@@ -0,0 +1,184 @@
+use crate::xna::csharp::Exception;
+use crate::xna::framework::game::{GraphicsDeviceInformation, GraphicsDeviceManager};
+use crate::xna::framework::graphics::{DisplayMode, GraphicsAdapter, GraphicsDevice, PresentInterval, PresentationParameters};
+use crate::xna::SilentExceptionConverter;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+impl GraphicsDeviceManager {
+    pub fn apply_changes(&mut self) -> Result<(), Exception> {
+        if self.graphics_device.is_some() && !self.is_device_dirty {
+            return Ok(())
+        }
+
+        if self.game.is_none() {
+            return Err(Exception::new("Game is not defined.", None));
+        }
+
+        self.change_device(false)
+    }
+
+    pub fn toggle_full_screen(&mut self) -> Result<(), Exception> {
+        self.is_full_screen = !self.is_full_screen;
+        self.is_device_dirty = true;
+
+        self.apply_changes()
+    }
+
+    fn change_device(&mut self, force_create: bool) -> Result<(), Exception> {
+        self.in_device_transition = true;
+
+        let best_device = self.find_best_platform_device(force_create)?;
+        Self::validate_graphics_device_information(&best_device)?;
+
+        let can_reset = !force_create && self.graphics_device.is_some() && self.can_reset_device(&best_device)?;
+
+        if can_reset {
+            let temp_device = self.graphics_device.unwrap_ref_or_default_exception()?;
+            temp_device.borrow_mut().reset(&best_device.presentation_parameters, &best_device.adapter)?;
+        } else {
+            self.create_device(&best_device)?;
+        }
+
+        self.presentation_parameters = best_device.presentation_parameters;
+        self.is_device_dirty = false;
+        self.in_device_transition = false;
+
+        Ok(())
+    }
+
+    fn find_best_platform_device(&mut self, any_suitable_device: bool) -> Result<GraphicsDeviceInformation, Exception> {
+        let mut found_devices: Vec<GraphicsDeviceInformation> = Vec::new();
+
+        self.add_devices(any_suitable_device, &mut found_devices)?;
+
+        if found_devices.is_empty() {
+            return Err(Exception::new("No devices found.", None));
+        }
+
+        found_devices.sort();
+
+        Ok(found_devices[0].clone())
+    }
+
+    fn create_device(&mut self, graphics_device_information: &GraphicsDeviceInformation) -> Result<(), Exception> {
+        self.graphics_device = None;
+
+        let width = graphics_device_information.presentation_parameters.back_buffer_width;
+        let height = graphics_device_information.presentation_parameters.back_buffer_height;
+
+        let temp_game = self.game.unwrap_ref_or_default_exception()?;
+        let mut game = temp_game.borrow_mut();
+
+        game.resize_window(width, height)?;
+
+        let device = Rc::new(RefCell::new(GraphicsDevice::new_from_profile(
+            &graphics_device_information.adapter,
+            &graphics_device_information.profile,
+            &graphics_device_information.presentation_parameters)));
+
+        self.graphics_device = Some(device.clone());
+        game.attach_graphics_device(device.clone());
+
+        device.borrow_mut().initialize(Some(graphics_device_information.adapter.clone()))
+    }
+
+    fn add_devices(&self, _any_suitable_device: bool, found_devices: &mut Vec<GraphicsDeviceInformation>) -> Result<(), Exception> {
+        for adapter in GraphicsAdapter::adapters()? {
+            let base_device_info = GraphicsDeviceInformation {
+                adapter: adapter.clone(),
+                profile: self.graphics_profile,
+                presentation_parameters: PresentationParameters {
+                    multi_sample_count: 0,
+                    is_full_screen: self.is_full_screen,
+                    presentation_interval: if self.synchronize_with_vertical_retrace { PresentInterval::Default } else { PresentInterval::Immediate },
+                    ..Default::default()
+                },
+            };
+
+            let current_output = adapter.current_output.unwrap_ref_or_default_exception()?;
+            let current_display_mode = current_output.current_display_mode.unwrap_ref_or_default_exception()?;
+
+            self.add_devices_with_display_mode(current_display_mode, &base_device_info, found_devices)?;
+
+            if self.is_full_screen {
+                for supported_mode in &current_output.display_mode_collection.display_modes {
+                    if supported_mode.width >= 640 && supported_mode.height >= 480 {
+                        self.add_devices_with_display_mode(supported_mode, &base_device_info, found_devices)?
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn add_devices_with_display_mode(&self, mode: &DisplayMode,
+                                     base_device_info: &GraphicsDeviceInformation,
+                                     found_devices: &mut Vec<GraphicsDeviceInformation>)
+        -> Result<(), Exception> {
+
+        let mut device_information = base_device_info.clone();
+        let parameters = &mut device_information.presentation_parameters;
+
+        if self.is_full_screen {
+            parameters.back_buffer_width = mode.width;
+            parameters.back_buffer_height = mode.height;
+        } else if self.use_resized_back_buffer {
+            parameters.back_buffer_width = self.resized_back_buffer_width;
+            parameters.back_buffer_height = self.resized_back_buffer_height;
+        } else {
+            parameters.back_buffer_width = self.back_buffer_width;
+            parameters.back_buffer_height = self.back_buffer_height;
+        }
+
+        let format = if self.is_full_screen { mode.format } else { self.back_buffer_format };
+        let query = base_device_info.adapter.query_back_buffer_format(
+            &format,
+            &self.depth_stencil_format,
+            if self.allow_multi_sampling { 16 } else { 0 })?;
+
+        parameters.back_buffer_format = query.0;
+        parameters.depth_stencil_format = query.1;
+        parameters.multi_sample_count = query.2;
+
+        if !found_devices.contains(&device_information) {
+            found_devices.push(device_information);
+        }
+
+        Ok(())
+    }
+
+    fn can_reset_device(&self, new_device_info: &GraphicsDeviceInformation) -> Result<bool, Exception> {
+        let profile = self.graphics_device
+            .unwrap_ref_or_default_exception()?
+            .borrow()
+            .graphics_profile;
+
+        Ok(profile == new_device_info.profile)
+    }
+
+    fn validate_graphics_device_information(device_info: &GraphicsDeviceInformation) -> Result<(), Exception> {
+        let presentation_parameters = &device_info.presentation_parameters;
+
+        if presentation_parameters.back_buffer_height == 0 || presentation_parameters.back_buffer_width == 0 {
+            return Err(Exception::argument_exception("Validate backbuffer full screen fail", None));
+        }
+
+        if !presentation_parameters.is_full_screen {
+            return Ok(())
+        }
+
+        let current_output = device_info.adapter.current_output.unwrap_ref_or_default_exception()?;
+        let supported = current_output.display_mode_collection.display_modes.iter().any(|display_mode|
+            display_mode.format == presentation_parameters.back_buffer_format
+                && display_mode.width == presentation_parameters.back_buffer_width
+                && display_mode.height == presentation_parameters.back_buffer_height);
+
+        if !supported {
+            return Err(Exception::argument_exception("Validate backbuffer full screen fail", None));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,51 @@
+pub mod game;
+pub mod game_window;
+pub mod graphics_adapter;
+pub mod graphics_device;
+pub mod graphics_device_manager;
+pub mod screen;
+pub mod step_timer;
+
+use crate::xna::framework::graphics::{SamplerState, SoftwareRenderer};
+use std::cell::{Cell, RefCell};
+use std::time::Instant;
+
+#[derive(Clone, Copy, Debug)]
+pub struct StepTimer {
+    start_time: Option<Instant>,
+    last_time: u64,
+    max_delta: u64,
+
+    elapsed_ticks: u64,
+    total_ticks: u64,
+    left_over_ticks: u64,
+
+    frame_count: u32,
+    frames_per_second: u32,
+    frames_this_second: u32,
+    second_counter: u64,
+
+    pub is_fixed_time_step: bool,
+    pub target_elapsed_ticks: u64,
+}
+
+#[derive(Default, Clone, Copy, Debug)]
+pub struct HeadlessGame {
+    pub is_running: bool,
+    pub step_timer: StepTimer,
+}
+
+#[derive(Default, Clone, PartialEq, Eq, Debug)]
+pub struct HeadlessGameWindow {
+    pub is_created: bool,
+    pub is_closed: Cell<bool>,
+}
+
+#[derive(Default, Clone, PartialEq)]
+pub struct HeadlessGraphicsDevice {
+    pub renderer: RefCell<SoftwareRenderer>,
+    pub present_count: Cell<u64>,
+    pub sampler_state_collection: Vec<SamplerState>,
+
+    pub is_initialized: bool,
+}
@@ -0,0 +1,18 @@
+use crate::xna::csharp::forms::Screen;
+use crate::xna::csharp::Rectangle;
+use crate::xna::framework::graphics::GraphicsAdapter;
+
+impl Screen {
+    pub fn all_screens() -> Vec<Self> {
+        let mode = GraphicsAdapter::headless_display_mode();
+        let bounds = Rectangle { x: 0, y: 0, width: mode.width as i32, height: mode.height as i32 };
+
+        vec![Screen {
+            primary: true,
+            device_name: GraphicsAdapter::HEADLESS_DEVICE_NAME.to_string(),
+            bounds,
+            working_area: bounds,
+            bit_depth: (mode.format.size() * 8) as i32,
+        }]
+    }
+}
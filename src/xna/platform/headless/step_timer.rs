@@ -0,0 +1,135 @@
+use crate::xna::csharp::Exception;
+use crate::xna::platform::headless::StepTimer;
+use std::time::Instant;
+
+impl StepTimer {
+    pub fn new() -> Self {
+        StepTimer {
+            start_time: Some(Instant::now()),
+            ..Default::default()
+        }
+    }
+
+    pub fn get_elapsed_ticks(&self) -> u64 {
+        self.elapsed_ticks
+    }
+
+    pub fn get_elapsed_seconds(&self) -> f64 {
+        Self::ticks_to_seconds(self.elapsed_ticks)
+    }
+
+    pub fn get_total_ticks(&self) -> u64 {
+        self.total_ticks
+    }
+
+    pub fn get_total_seconds(&self) -> f64 {
+        Self::ticks_to_seconds(self.total_ticks)
+    }
+
+    pub fn get_frame_count(&self) -> u32 {
+        self.frame_count
+    }
+
+    pub fn get_frame_per_second(&self) -> u32 {
+        self.frames_per_second
+    }
+
+    pub fn set_target_elapsed_seconds(&mut self, target_elapsed: f64) {
+        self.target_elapsed_ticks = Self::seconds_to_ticks(target_elapsed);
+    }
+
+    pub fn reset_elapsed_time(&mut self) -> Result<(), Exception> {
+        self.last_time = self.current_time();
+        self.left_over_ticks = 0;
+        self.frames_per_second = 0;
+        self.frames_this_second = 0;
+        self.second_counter = 0;
+
+        Ok(())
+    }
+
+    pub fn tick<FUpdate>(&mut self, update: &mut FUpdate) -> Result<(), Exception> where FUpdate: FnMut(&StepTimer) -> Result<(), Exception> {
+        let current_time = self.current_time();
+        let mut time_delta = current_time - self.last_time;
+
+        self.last_time = current_time;
+        self.second_counter += time_delta;
+
+        if time_delta > self.max_delta {
+            time_delta = self.max_delta;
+        }
+
+        let last_frame_count = self.frame_count;
+
+        if self.is_fixed_time_step {
+            if time_delta.abs_diff(self.target_elapsed_ticks) < Self::TICKS_PER_SECOND / 4000 {
+                time_delta = self.target_elapsed_ticks;
+            }
+
+            self.left_over_ticks += time_delta;
+
+            while self.left_over_ticks >= self.target_elapsed_ticks {
+                self.elapsed_ticks = self.target_elapsed_ticks;
+                self.total_ticks += self.target_elapsed_ticks;
+                self.left_over_ticks -= self.target_elapsed_ticks;
+                self.frame_count += 1;
+
+                update(self)?;
+            }
+        } else {
+            self.elapsed_ticks = time_delta;
+            self.total_ticks += time_delta;
+            self.left_over_ticks = 0;
+            self.frame_count += 1;
+
+            update(self)?;
+        }
+
+        if self.frame_count != last_frame_count {
+            self.frames_this_second += 1;
+        }
+
+        if self.second_counter >= Self::TICKS_PER_SECOND {
+            self.frames_per_second = self.frames_this_second;
+            self.frames_this_second = 0;
+            self.second_counter %= Self::TICKS_PER_SECOND;
+        }
+
+        Ok(())
+    }
+
+    fn current_time(&mut self) -> u64 {
+        let start_time = *self.start_time.get_or_insert_with(Instant::now);
+
+        (start_time.elapsed().as_nanos() / 100) as u64
+    }
+
+    fn ticks_to_seconds(ticks: u64) -> f64 {
+        ticks as f64 / Self::TICKS_PER_SECOND as f64
+    }
+
+    fn seconds_to_ticks(seconds: f64) -> u64 {
+        (seconds * Self::TICKS_PER_SECOND as f64) as u64
+    }
+
+    pub const TICKS_PER_SECOND: u64 = 10000000;
+}
+
+impl Default for StepTimer {
+    fn default() -> Self {
+        StepTimer {
+            start_time: None,
+            last_time: 0,
+            max_delta: Self::TICKS_PER_SECOND / 10,
+            elapsed_ticks: 0,
+            total_ticks: 0,
+            left_over_ticks: 0,
+            frame_count: 0,
+            frames_per_second: 0,
+            frames_this_second: 0,
+            second_counter: 0,
+            is_fixed_time_step: false,
+            target_elapsed_ticks: Self::TICKS_PER_SECOND / 60,
+        }
+    }
+}
@@ -1,2 +1,5 @@
 #[cfg(target_os = "windows")]
 pub mod windows;
+
+#[cfg(not(target_os = "windows"))]
+pub mod headless;
@@ -1,7 +1,7 @@
 use windows::core::Interface;
 use crate::xna::csharp::Exception;
-use crate::xna::framework::graphics::{GraphicsAdapter, GraphicsDevice, IPackedVector, PresentInterval, PresentationParameters, RenderTarget2D};
-use crate::xna::framework::Color;
+use crate::xna::framework::graphics::{GraphicsAdapter, GraphicsDevice, IPackedVector, PresentInterval, PresentationParameters, PrimitiveType, RenderTarget2D, VertexPositionColor};
+use crate::xna::framework::{Color, Matrix};
 use crate::xna::platform::windows::WindowsGraphicsDevice;
 use windows::Win32::Foundation::HMODULE;
 use windows::Win32::Graphics::Direct3D::{D3D_DRIVER_TYPE_HARDWARE, D3D_FEATURE_LEVEL_10_0, D3D_FEATURE_LEVEL_10_1, D3D_FEATURE_LEVEL_11_0, D3D_FEATURE_LEVEL_9_1, D3D_FEATURE_LEVEL_9_2, D3D_FEATURE_LEVEL_9_3};
@@ -121,6 +121,8 @@ impl GraphicsDevice {
                 .Present(vsync, DXGI_PRESENT::default())
                 .unwrap();
 
+            self.platform.present_count.set(self.platform.present_count.get() + 1);
+
             let view = self.platform.render_target
                 .unwrap_ref_or_default_exception()?
                 .clone();
@@ -151,6 +153,15 @@ impl GraphicsDevice {
         Ok(())
     }
 
+    pub fn draw_user_primitives(&mut self, _primitive_type: PrimitiveType, _vertex_data: &[VertexPositionColor], _vertex_offset: usize,
+                                _primitive_count: usize, _transform: &Matrix) -> Result<(), Exception> {
+        Err(Exception::invalid_operation("Drawing user primitives is not supported by the Direct3D backend.", None))
+    }
+
+    pub fn get_back_buffer_data(&self, _data: &mut [Color]) -> Result<(), Exception> {
+        Err(Exception::invalid_operation("Reading back buffer data is not supported by the Direct3D backend.", None))
+    }
+
     fn apply_sampler_states(&mut self) -> Result<(), Exception> {
         let collection = &self.sampler_state_collection;
 
@@ -187,7 +198,7 @@ impl GraphicsDevice {
             device.CreateDepthStencilState(&description, Some(&mut dx_depth))
                 .unwrap_or_exception("Error creating DXGI depth state")?;
 
-            context.OMSetDepthStencilState(dx_depth.as_ref(), 0);
+            context.OMSetDepthStencilState(dx_depth.as_ref(), self.depth_stencil_state.reference_stencil as u32);
 
             self.platform.depth_stencil_state = dx_depth;
         }
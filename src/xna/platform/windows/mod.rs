@@ -9,13 +9,14 @@ pub mod graphics_adapter;
 pub mod screen;
 pub mod system_information;
 
+use std::cell::Cell;
 use crate::xna::csharp::{Exception};
 use crate::xna::framework::graphics::{Blend, BlendFunction, BlendState, ColorWriteChannels, ComparisonFunction, CullMode, DepthFace, DepthStencilState, DisplayMode, DisplayModeScaling, FillMode, IPackedVector, RasterizerState, SamplerState, ScanlineOrder, StencilOperation, SurfaceFormat, SurfaceUsage, SwapChain, SwapChainFlag, SwapEffect, TextureAddressMode, TextureFilter};
 use windows::core::{Error, BOOL};
 use windows::Win32::Foundation::HWND;
 use windows::Win32::Graphics::Direct3D::D3D_FEATURE_LEVEL;
-use windows::Win32::Graphics::Direct3D11::{ID3D11BlendState, ID3D11DepthStencilState, ID3D11Device, ID3D11DeviceContext, ID3D11RasterizerState, ID3D11RenderTargetView, ID3D11SamplerState, ID3D11Texture2D, D3D11_BLEND, D3D11_BLEND_BLEND_FACTOR, D3D11_BLEND_DESC, D3D11_BLEND_DEST_ALPHA, D3D11_BLEND_DEST_COLOR, D3D11_BLEND_INV_BLEND_FACTOR, D3D11_BLEND_INV_DEST_ALPHA, D3D11_BLEND_INV_DEST_COLOR, D3D11_BLEND_INV_SRC1_ALPHA, D3D11_BLEND_INV_SRC1_COLOR, D3D11_BLEND_INV_SRC_ALPHA, D3D11_BLEND_INV_SRC_COLOR, D3D11_BLEND_ONE, D3D11_BLEND_OP, D3D11_BLEND_OP_ADD, D3D11_BLEND_OP_MAX, D3D11_BLEND_OP_MIN, D3D11_BLEND_OP_REV_SUBTRACT, D3D11_BLEND_OP_SUBTRACT, D3D11_BLEND_SRC1_ALPHA, D3D11_BLEND_SRC1_COLOR, D3D11_BLEND_SRC_ALPHA, D3D11_BLEND_SRC_ALPHA_SAT, D3D11_BLEND_SRC_COLOR, D3D11_BLEND_ZERO, D3D11_COLOR_WRITE_ENABLE, D3D11_COLOR_WRITE_ENABLE_ALL, D3D11_COLOR_WRITE_ENABLE_ALPHA, D3D11_COLOR_WRITE_ENABLE_BLUE, D3D11_COLOR_WRITE_ENABLE_GREEN, D3D11_COLOR_WRITE_ENABLE_RED, D3D11_COMPARISON_ALWAYS, D3D11_COMPARISON_EQUAL, D3D11_COMPARISON_FUNC, D3D11_COMPARISON_GREATER, D3D11_COMPARISON_GREATER_EQUAL, D3D11_COMPARISON_LESS, D3D11_COMPARISON_LESS_EQUAL, D3D11_COMPARISON_NEVER, D3D11_COMPARISON_NOT_EQUAL, D3D11_CULL_BACK, D3D11_CULL_FRONT, D3D11_CULL_MODE, D3D11_CULL_NONE, D3D11_DEPTH_STENCILOP_DESC, D3D11_DEPTH_STENCIL_DESC, D3D11_DEPTH_WRITE_MASK, D3D11_FILL_MODE, D3D11_FILL_SOLID, D3D11_FILL_WIREFRAME, D3D11_FILTER, D3D11_FILTER_ANISOTROPIC, D3D11_FILTER_MIN_LINEAR_MAG_MIP_POINT, D3D11_FILTER_MIN_LINEAR_MAG_POINT_MIP_LINEAR, D3D11_FILTER_MIN_MAG_LINEAR_MIP_POINT, D3D11_FILTER_MIN_MAG_MIP_LINEAR, D3D11_FILTER_MIN_MAG_MIP_POINT, D3D11_FILTER_MIN_MAG_POINT_MIP_LINEAR, D3D11_FILTER_MIN_POINT_MAG_LINEAR_MIP_POINT, D3D11_RASTERIZER_DESC, D3D11_SAMPLER_DESC, D3D11_STENCIL_OP, D3D11_STENCIL_OP_DECR, D3D11_STENCIL_OP_DECR_SAT, D3D11_STENCIL_OP_INCR, D3D11_STENCIL_OP_INCR_SAT, D3D11_STENCIL_OP_INVERT, D3D11_STENCIL_OP_KEEP, D3D11_STENCIL_OP_REPLACE, D3D11_STENCIL_OP_ZERO, D3D11_TEXTURE_ADDRESS_BORDER, D3D11_TEXTURE_ADDRESS_CLAMP, D3D11_TEXTURE_ADDRESS_MIRROR, D3D11_TEXTURE_ADDRESS_MIRROR_ONCE, D3D11_TEXTURE_ADDRESS_MODE, D3D11_TEXTURE_ADDRESS_WRAP};
-use windows::Win32::Graphics::Dxgi::Common::{DXGI_FORMAT, DXGI_FORMAT_R8G8B8A8_UNORM, DXGI_FORMAT_UNKNOWN, DXGI_MODE_DESC, DXGI_MODE_SCALING, DXGI_MODE_SCALING_CENTERED, DXGI_MODE_SCALING_STRETCHED, DXGI_MODE_SCALING_UNSPECIFIED, DXGI_MODE_SCANLINE_ORDER, DXGI_MODE_SCANLINE_ORDER_LOWER_FIELD_FIRST, DXGI_MODE_SCANLINE_ORDER_PROGRESSIVE, DXGI_MODE_SCANLINE_ORDER_UNSPECIFIED, DXGI_MODE_SCANLINE_ORDER_UPPER_FIELD_FIRST, DXGI_RATIONAL, DXGI_SAMPLE_DESC};
+use windows::Win32::Graphics::Direct3D11::{ID3D11BlendState, ID3D11DepthStencilState, ID3D11Device, ID3D11DeviceContext, ID3D11RasterizerState, ID3D11RenderTargetView, ID3D11SamplerState, ID3D11Texture2D, D3D11_BLEND, D3D11_BLEND_BLEND_FACTOR, D3D11_BLEND_DESC, D3D11_BLEND_DEST_ALPHA, D3D11_BLEND_DEST_COLOR, D3D11_BLEND_INV_BLEND_FACTOR, D3D11_BLEND_INV_DEST_ALPHA, D3D11_BLEND_INV_DEST_COLOR, D3D11_BLEND_INV_SRC1_ALPHA, D3D11_BLEND_INV_SRC1_COLOR, D3D11_BLEND_INV_SRC_ALPHA, D3D11_BLEND_INV_SRC_COLOR, D3D11_BLEND_ONE, D3D11_BLEND_OP, D3D11_BLEND_OP_ADD, D3D11_BLEND_OP_MAX, D3D11_BLEND_OP_MIN, D3D11_BLEND_OP_REV_SUBTRACT, D3D11_BLEND_OP_SUBTRACT, D3D11_BLEND_SRC1_ALPHA, D3D11_BLEND_SRC1_COLOR, D3D11_BLEND_SRC_ALPHA, D3D11_BLEND_SRC_ALPHA_SAT, D3D11_BLEND_SRC_COLOR, D3D11_BLEND_ZERO, D3D11_COLOR_WRITE_ENABLE, D3D11_COLOR_WRITE_ENABLE_ALPHA, D3D11_COLOR_WRITE_ENABLE_BLUE, D3D11_COLOR_WRITE_ENABLE_GREEN, D3D11_COLOR_WRITE_ENABLE_RED, D3D11_COMPARISON_ALWAYS, D3D11_COMPARISON_EQUAL, D3D11_COMPARISON_FUNC, D3D11_COMPARISON_GREATER, D3D11_COMPARISON_GREATER_EQUAL, D3D11_COMPARISON_LESS, D3D11_COMPARISON_LESS_EQUAL, D3D11_COMPARISON_NEVER, D3D11_COMPARISON_NOT_EQUAL, D3D11_CULL_BACK, D3D11_CULL_FRONT, D3D11_CULL_MODE, D3D11_CULL_NONE, D3D11_DEPTH_STENCILOP_DESC, D3D11_DEPTH_STENCIL_DESC, D3D11_DEPTH_WRITE_MASK, D3D11_FILL_MODE, D3D11_FILL_SOLID, D3D11_FILL_WIREFRAME, D3D11_FILTER, D3D11_FILTER_ANISOTROPIC, D3D11_FILTER_MIN_LINEAR_MAG_MIP_POINT, D3D11_FILTER_MIN_LINEAR_MAG_POINT_MIP_LINEAR, D3D11_FILTER_MIN_MAG_LINEAR_MIP_POINT, D3D11_FILTER_MIN_MAG_MIP_LINEAR, D3D11_FILTER_MIN_MAG_MIP_POINT, D3D11_FILTER_MIN_MAG_POINT_MIP_LINEAR, D3D11_FILTER_MIN_POINT_MAG_LINEAR_MIP_POINT, D3D11_FILTER_MIN_POINT_MAG_MIP_LINEAR, D3D11_RASTERIZER_DESC, D3D11_SAMPLER_DESC, D3D11_STENCIL_OP, D3D11_STENCIL_OP_DECR, D3D11_STENCIL_OP_DECR_SAT, D3D11_STENCIL_OP_INCR, D3D11_STENCIL_OP_INCR_SAT, D3D11_STENCIL_OP_INVERT, D3D11_STENCIL_OP_KEEP, D3D11_STENCIL_OP_REPLACE, D3D11_STENCIL_OP_ZERO, D3D11_TEXTURE_ADDRESS_BORDER, D3D11_TEXTURE_ADDRESS_CLAMP, D3D11_TEXTURE_ADDRESS_MIRROR, D3D11_TEXTURE_ADDRESS_MIRROR_ONCE, D3D11_TEXTURE_ADDRESS_MODE, D3D11_TEXTURE_ADDRESS_WRAP};
+use windows::Win32::Graphics::Dxgi::Common::{DXGI_FORMAT, DXGI_FORMAT_A8_UNORM, DXGI_FORMAT_B4G4R4A4_UNORM, DXGI_FORMAT_B5G5R5A1_UNORM, DXGI_FORMAT_B5G6R5_UNORM, DXGI_FORMAT_B8G8R8A8_UNORM, DXGI_FORMAT_B8G8R8A8_UNORM_SRGB, DXGI_FORMAT_B8G8R8X8_UNORM, DXGI_FORMAT_B8G8R8X8_UNORM_SRGB, DXGI_FORMAT_BC1_UNORM, DXGI_FORMAT_BC1_UNORM_SRGB, DXGI_FORMAT_BC2_UNORM, DXGI_FORMAT_BC2_UNORM_SRGB, DXGI_FORMAT_BC3_UNORM, DXGI_FORMAT_BC3_UNORM_SRGB, DXGI_FORMAT_R10G10B10A2_UNORM, DXGI_FORMAT_R16G16B16A16_FLOAT, DXGI_FORMAT_R16G16B16A16_UNORM, DXGI_FORMAT_R16G16_FLOAT, DXGI_FORMAT_R16G16_UNORM, DXGI_FORMAT_R16_FLOAT, DXGI_FORMAT_R32G32B32A32_FLOAT, DXGI_FORMAT_R32G32_FLOAT, DXGI_FORMAT_R32_FLOAT, DXGI_FORMAT_R8G8B8A8_SNORM, DXGI_FORMAT_R8G8B8A8_UNORM, DXGI_FORMAT_R8G8B8A8_UNORM_SRGB, DXGI_FORMAT_R8G8_SNORM, DXGI_FORMAT_UNKNOWN, DXGI_MODE_DESC, DXGI_MODE_SCALING, DXGI_MODE_SCALING_CENTERED, DXGI_MODE_SCALING_STRETCHED, DXGI_MODE_SCALING_UNSPECIFIED, DXGI_MODE_SCANLINE_ORDER, DXGI_MODE_SCANLINE_ORDER_LOWER_FIELD_FIRST, DXGI_MODE_SCANLINE_ORDER_PROGRESSIVE, DXGI_MODE_SCANLINE_ORDER_UNSPECIFIED, DXGI_MODE_SCANLINE_ORDER_UPPER_FIELD_FIRST, DXGI_RATIONAL, DXGI_SAMPLE_DESC};
 use windows::Win32::Graphics::Dxgi::{IDXGIAdapter, IDXGIFactory, IDXGIOutput, IDXGISwapChain, DXGI_SWAP_CHAIN_DESC, DXGI_SWAP_CHAIN_FLAG, DXGI_SWAP_CHAIN_FLAG_ALLOW_MODE_SWITCH, DXGI_SWAP_CHAIN_FLAG_ALLOW_TEARING, DXGI_SWAP_CHAIN_FLAG_DISPLAY_ONLY, DXGI_SWAP_CHAIN_FLAG_FOREGROUND_LAYER, DXGI_SWAP_CHAIN_FLAG_FRAME_LATENCY_WAITABLE_OBJECT, DXGI_SWAP_CHAIN_FLAG_FULLSCREEN_VIDEO, DXGI_SWAP_CHAIN_FLAG_GDI_COMPATIBLE, DXGI_SWAP_CHAIN_FLAG_HW_PROTECTED, DXGI_SWAP_CHAIN_FLAG_NONPREROTATED, DXGI_SWAP_CHAIN_FLAG_RESTRICTED_CONTENT, DXGI_SWAP_CHAIN_FLAG_RESTRICTED_TO_ALL_HOLOGRAPHIC_DISPLAYS, DXGI_SWAP_CHAIN_FLAG_RESTRICT_SHARED_RESOURCE_DRIVER, DXGI_SWAP_CHAIN_FLAG_YUV_VIDEO, DXGI_SWAP_EFFECT, DXGI_SWAP_EFFECT_DISCARD, DXGI_SWAP_EFFECT_FLIP_DISCARD, DXGI_SWAP_EFFECT_FLIP_SEQUENTIAL, DXGI_SWAP_EFFECT_SEQUENTIAL, DXGI_USAGE, DXGI_USAGE_BACK_BUFFER, DXGI_USAGE_DISCARD_ON_PRESENT, DXGI_USAGE_READ_ONLY, DXGI_USAGE_RENDER_TARGET_OUTPUT, DXGI_USAGE_SHADER_INPUT, DXGI_USAGE_SHARED, DXGI_USAGE_UNORDERED_ACCESS};
 use windows::Win32::Graphics::Gdi::HMONITOR;
 use crate::xna::ExceptionConverter;
@@ -114,6 +115,7 @@ pub struct WindowsGraphicsDevice {
     depth_stencil_state: Option<ID3D11DepthStencilState>,
     render_target: Option<ID3D11RenderTargetView>,
     sampler_state_collection: Vec<Option<ID3D11SamplerState>>,
+    pub present_count: Cell<u64>,
 
     is_initialized: bool
 }
@@ -206,13 +208,22 @@ impl From<BlendFunction> for D3D11_BLEND_OP {
 
 impl From<ColorWriteChannels> for  D3D11_COLOR_WRITE_ENABLE {
     fn from(value: ColorWriteChannels) -> Self {
-        match value {
-            ColorWriteChannels::Red => D3D11_COLOR_WRITE_ENABLE_RED,
-            ColorWriteChannels::Green => D3D11_COLOR_WRITE_ENABLE_GREEN,
-            ColorWriteChannels::Blue => D3D11_COLOR_WRITE_ENABLE_BLUE,
-            ColorWriteChannels::Alpha => D3D11_COLOR_WRITE_ENABLE_ALPHA,
-            ColorWriteChannels::All => D3D11_COLOR_WRITE_ENABLE_ALL,
+        let mut result = D3D11_COLOR_WRITE_ENABLE(0);
+
+        if value.contains(ColorWriteChannels::RED) {
+            result.0 |= D3D11_COLOR_WRITE_ENABLE_RED.0;
+        }
+        if value.contains(ColorWriteChannels::GREEN) {
+            result.0 |= D3D11_COLOR_WRITE_ENABLE_GREEN.0;
         }
+        if value.contains(ColorWriteChannels::BLUE) {
+            result.0 |= D3D11_COLOR_WRITE_ENABLE_BLUE.0;
+        }
+        if value.contains(ColorWriteChannels::ALPHA) {
+            result.0 |= D3D11_COLOR_WRITE_ENABLE_ALPHA.0;
+        }
+
+        result
     }
 }
 
@@ -287,7 +298,7 @@ impl From<TextureFilter > for D3D11_FILTER {
             TextureFilter::PointMipLinear => D3D11_FILTER_MIN_MAG_POINT_MIP_LINEAR,
             TextureFilter::MinLinearMagPointMipLinear => D3D11_FILTER_MIN_LINEAR_MAG_POINT_MIP_LINEAR,
             TextureFilter::MinLinearMagPointMipPoint => D3D11_FILTER_MIN_LINEAR_MAG_MIP_POINT,
-            TextureFilter::MinPointMagLinearMipLinear => D3D11_FILTER_MIN_POINT_MAG_LINEAR_MIP_POINT,
+            TextureFilter::MinPointMagLinearMipLinear => D3D11_FILTER_MIN_POINT_MAG_MIP_LINEAR,
             TextureFilter::MinPointMagLinearMipPoint => D3D11_FILTER_MIN_POINT_MAG_LINEAR_MIP_POINT,
         }
     }
@@ -313,6 +324,33 @@ impl From<SurfaceFormat > for DXGI_FORMAT {
     fn from(value: SurfaceFormat) -> Self {
         match value {
             SurfaceFormat::Color => DXGI_FORMAT_R8G8B8A8_UNORM,
+            SurfaceFormat::Bgr565 => DXGI_FORMAT_B5G6R5_UNORM,
+            SurfaceFormat::Bgra5551 => DXGI_FORMAT_B5G5R5A1_UNORM,
+            SurfaceFormat::Bgra4444 => DXGI_FORMAT_B4G4R4A4_UNORM,
+            SurfaceFormat::Dxt1 => DXGI_FORMAT_BC1_UNORM,
+            SurfaceFormat::Dxt3 => DXGI_FORMAT_BC2_UNORM,
+            SurfaceFormat::Dxt5 => DXGI_FORMAT_BC3_UNORM,
+            SurfaceFormat::NormalizedByte2 => DXGI_FORMAT_R8G8_SNORM,
+            SurfaceFormat::NormalizedByte4 => DXGI_FORMAT_R8G8B8A8_SNORM,
+            SurfaceFormat::Rgba1010102 => DXGI_FORMAT_R10G10B10A2_UNORM,
+            SurfaceFormat::Rg32 => DXGI_FORMAT_R16G16_UNORM,
+            SurfaceFormat::Rgba64 => DXGI_FORMAT_R16G16B16A16_UNORM,
+            SurfaceFormat::Alpha8 => DXGI_FORMAT_A8_UNORM,
+            SurfaceFormat::Single => DXGI_FORMAT_R32_FLOAT,
+            SurfaceFormat::Vector2 => DXGI_FORMAT_R32G32_FLOAT,
+            SurfaceFormat::Vector4 => DXGI_FORMAT_R32G32B32A32_FLOAT,
+            SurfaceFormat::HalfSingle => DXGI_FORMAT_R16_FLOAT,
+            SurfaceFormat::HalfVector2 => DXGI_FORMAT_R16G16_FLOAT,
+            SurfaceFormat::HalfVector4 => DXGI_FORMAT_R16G16B16A16_FLOAT,
+            SurfaceFormat::HdrBlendable => DXGI_FORMAT_R16G16B16A16_FLOAT,
+            SurfaceFormat::Bgr32 => DXGI_FORMAT_B8G8R8X8_UNORM,
+            SurfaceFormat::Bgra32 => DXGI_FORMAT_B8G8R8A8_UNORM,
+            SurfaceFormat::ColorSRgb => DXGI_FORMAT_R8G8B8A8_UNORM_SRGB,
+            SurfaceFormat::Bgr32SRgb => DXGI_FORMAT_B8G8R8X8_UNORM_SRGB,
+            SurfaceFormat::Bgra32SRgb => DXGI_FORMAT_B8G8R8A8_UNORM_SRGB,
+            SurfaceFormat::Dxt1SRgb => DXGI_FORMAT_BC1_UNORM_SRGB,
+            SurfaceFormat::Dxt3SRgb => DXGI_FORMAT_BC2_UNORM_SRGB,
+            SurfaceFormat::Dxt5SRgb => DXGI_FORMAT_BC3_UNORM_SRGB,
             SurfaceFormat::Unknown => DXGI_FORMAT_UNKNOWN,
         }
     }
@@ -388,6 +426,32 @@ impl From<DXGI_FORMAT> for SurfaceFormat{
     fn from(value: DXGI_FORMAT) -> Self {
         match value {
             DXGI_FORMAT_R8G8B8A8_UNORM => SurfaceFormat::Color,
+            DXGI_FORMAT_B5G6R5_UNORM => SurfaceFormat::Bgr565,
+            DXGI_FORMAT_B5G5R5A1_UNORM => SurfaceFormat::Bgra5551,
+            DXGI_FORMAT_B4G4R4A4_UNORM => SurfaceFormat::Bgra4444,
+            DXGI_FORMAT_BC1_UNORM => SurfaceFormat::Dxt1,
+            DXGI_FORMAT_BC2_UNORM => SurfaceFormat::Dxt3,
+            DXGI_FORMAT_BC3_UNORM => SurfaceFormat::Dxt5,
+            DXGI_FORMAT_R8G8_SNORM => SurfaceFormat::NormalizedByte2,
+            DXGI_FORMAT_R8G8B8A8_SNORM => SurfaceFormat::NormalizedByte4,
+            DXGI_FORMAT_R10G10B10A2_UNORM => SurfaceFormat::Rgba1010102,
+            DXGI_FORMAT_R16G16_UNORM => SurfaceFormat::Rg32,
+            DXGI_FORMAT_R16G16B16A16_UNORM => SurfaceFormat::Rgba64,
+            DXGI_FORMAT_A8_UNORM => SurfaceFormat::Alpha8,
+            DXGI_FORMAT_R32_FLOAT => SurfaceFormat::Single,
+            DXGI_FORMAT_R32G32_FLOAT => SurfaceFormat::Vector2,
+            DXGI_FORMAT_R32G32B32A32_FLOAT => SurfaceFormat::Vector4,
+            DXGI_FORMAT_R16_FLOAT => SurfaceFormat::HalfSingle,
+            DXGI_FORMAT_R16G16_FLOAT => SurfaceFormat::HalfVector2,
+            DXGI_FORMAT_R16G16B16A16_FLOAT => SurfaceFormat::HalfVector4,
+            DXGI_FORMAT_B8G8R8X8_UNORM => SurfaceFormat::Bgr32,
+            DXGI_FORMAT_B8G8R8A8_UNORM => SurfaceFormat::Bgra32,
+            DXGI_FORMAT_R8G8B8A8_UNORM_SRGB => SurfaceFormat::ColorSRgb,
+            DXGI_FORMAT_B8G8R8X8_UNORM_SRGB => SurfaceFormat::Bgr32SRgb,
+            DXGI_FORMAT_B8G8R8A8_UNORM_SRGB => SurfaceFormat::Bgra32SRgb,
+            DXGI_FORMAT_BC1_UNORM_SRGB => SurfaceFormat::Dxt1SRgb,
+            DXGI_FORMAT_BC2_UNORM_SRGB => SurfaceFormat::Dxt3SRgb,
+            DXGI_FORMAT_BC3_UNORM_SRGB => SurfaceFormat::Dxt5SRgb,
             _ => SurfaceFormat::Unknown,
         }
     }